@@ -216,10 +216,10 @@ fn run_scenario(scenario: &Scenario) -> (f64, u64) {
                 x += vx;
                 y += vy;
 
-                if x > 1.0 || x < -1.0 {
+                if !(-1.0..=1.0).contains(&x) {
                     vx = -vx * 0.97;
                 }
-                if y > 1.0 || y < -1.0 {
+                if !(-1.0..=1.0).contains(&y) {
                     vy = -vy * 0.97;
                 }
             }
@@ -0,0 +1,430 @@
+use bevy::math::{IVec2, Vec2, Vec3};
+use bevy::prelude::Resource;
+use std::collections::HashMap;
+
+/// What a collider is made of, so footsteps and friction can vary per surface.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SurfaceType {
+    #[default]
+    Neutral,
+    Grass,
+    Stone,
+    Wood,
+    Metal,
+}
+
+/// Axis-aligned box collider for static world geometry.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StaticCollider {
+    pub center: Vec3,
+    pub half_extents: Vec3,
+    pub surface: SurfaceType,
+    /// Unit normal of a ramp's top surface, or `None` for a flat-topped box. A ramp is the
+    /// part of the box below the plane through its center with this normal.
+    pub slope_normal: Option<Vec3>,
+}
+
+impl StaticCollider {
+    /// Normal of the top surface: straight up unless the collider is a ramp.
+    pub fn top_normal(&self) -> Vec3 {
+        self.slope_normal.unwrap_or(Vec3::Y)
+    }
+
+    /// Height of the top surface above `(x, z)`. Points outside the footprint use its
+    /// nearest edge, and a ramp's plane is clamped to the box.
+    ///
+    /// ```
+    /// use bevy::math::Vec3;
+    /// use haemwend::collision::{StaticCollider, SurfaceType};
+    ///
+    /// // Rises 1 m over 2 m toward +X.
+    /// let ramp = StaticCollider {
+    ///     center: Vec3::new(0.0, 0.5, 0.0),
+    ///     half_extents: Vec3::new(1.0, 0.5, 1.0),
+    ///     surface: SurfaceType::Stone,
+    ///     slope_normal: Some(Vec3::new(-1.0, 2.0, 0.0).normalize()),
+    /// };
+    /// assert!((ramp.top_at(0.5, 0.0) - 0.75).abs() < 1e-5);
+    /// assert!((ramp.top_at(-3.0, 0.0) - 0.0).abs() < 1e-5);
+    /// assert!((ramp.top_at(3.0, 0.0) - 1.0).abs() < 1e-5);
+    /// ```
+    pub fn top_at(&self, x: f32, z: f32) -> f32 {
+        let top = self.center.y + self.half_extents.y;
+        let Some(normal) = self.slope_normal else {
+            return top;
+        };
+        let dx = (x - self.center.x).clamp(-self.half_extents.x, self.half_extents.x);
+        let dz = (z - self.center.z).clamp(-self.half_extents.z, self.half_extents.z);
+        let height = self.center.y - (normal.x * dx + normal.z * dz) / normal.y.max(1e-3);
+        height.clamp(self.center.y - self.half_extents.y, top)
+    }
+}
+
+/// Closest intersection reported by [`WorldCollisionGrid::raycast`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RayHit {
+    pub distance: f32,
+    pub point: Vec3,
+    pub normal: Vec3,
+    pub collider: StaticCollider,
+}
+
+/// Uniform XZ hash grid over static colliders. A collider is stored in every cell its
+/// footprint touches, so queries only need to visit the cells around the query point.
+///
+/// ```
+/// use bevy::math::Vec3;
+/// use haemwend::collision::{StaticCollider, SurfaceType, WorldCollisionGrid};
+///
+/// let crate_box = StaticCollider {
+///     center: Vec3::new(2.0, 0.5, 2.0),
+///     half_extents: Vec3::splat(0.5),
+///     surface: SurfaceType::Wood,
+///     slope_normal: None,
+/// };
+/// let grid = WorldCollisionGrid::from_colliders(vec![crate_box], 4.0);
+///
+/// let mut nearby = Vec::new();
+/// grid.query_nearby(Vec3::ZERO, 3.0, |collider| nearby.push(collider.center));
+/// assert_eq!(nearby, vec![Vec3::new(2.0, 0.5, 2.0)]);
+/// ```
+#[derive(Resource, Debug)]
+pub struct WorldCollisionGrid {
+    pub cell_size: f32,
+    pub cells: HashMap<IVec2, Vec<StaticCollider>>,
+}
+
+impl Default for WorldCollisionGrid {
+    fn default() -> Self {
+        Self {
+            cell_size: 4.0,
+            cells: HashMap::new(),
+        }
+    }
+}
+
+impl WorldCollisionGrid {
+    pub fn from_colliders(colliders: Vec<StaticCollider>, cell_size: f32) -> Self {
+        let mut grid = Self {
+            cell_size: cell_size.max(0.25),
+            cells: HashMap::new(),
+        };
+
+        for collider in colliders {
+            let min_x =
+                ((collider.center.x - collider.half_extents.x) / grid.cell_size).floor() as i32;
+            let max_x =
+                ((collider.center.x + collider.half_extents.x) / grid.cell_size).floor() as i32;
+            let min_z =
+                ((collider.center.z - collider.half_extents.z) / grid.cell_size).floor() as i32;
+            let max_z =
+                ((collider.center.z + collider.half_extents.z) / grid.cell_size).floor() as i32;
+
+            for x in min_x..=max_x {
+                for z in min_z..=max_z {
+                    grid.cells
+                        .entry(IVec2::new(x, z))
+                        .or_default()
+                        .push(collider);
+                }
+            }
+        }
+
+        grid
+    }
+
+    /// Visits every collider stored in the cells overlapping the square of `radius` around
+    /// `center` on the XZ plane. Colliders spanning several cells can be visited more than once.
+    ///
+    /// ```
+    /// use bevy::math::Vec3;
+    /// use haemwend::collision::{StaticCollider, SurfaceType, WorldCollisionGrid};
+    ///
+    /// let far_wall = StaticCollider {
+    ///     center: Vec3::new(40.0, 1.5, 0.0),
+    ///     half_extents: Vec3::splat(1.5),
+    ///     surface: SurfaceType::Stone,
+    ///     slope_normal: None,
+    /// };
+    /// let grid = WorldCollisionGrid::from_colliders(vec![far_wall], 4.0);
+    ///
+    /// let mut hits = 0;
+    /// grid.query_nearby(Vec3::ZERO, 2.0, |_| hits += 1);
+    /// assert_eq!(hits, 0);
+    /// ```
+    pub fn query_nearby(&self, center: Vec3, radius: f32, mut visit: impl FnMut(StaticCollider)) {
+        if self.cells.is_empty() {
+            return;
+        }
+
+        let min_x = ((center.x - radius) / self.cell_size).floor() as i32;
+        let max_x = ((center.x + radius) / self.cell_size).floor() as i32;
+        let min_z = ((center.z - radius) / self.cell_size).floor() as i32;
+        let max_z = ((center.z + radius) / self.cell_size).floor() as i32;
+
+        for x in min_x..=max_x {
+            for z in min_z..=max_z {
+                if let Some(cell_colliders) = self.cells.get(&IVec2::new(x, z)) {
+                    for collider in cell_colliders {
+                        visit(*collider);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Casts a ray against all colliders and returns the closest hit within `max_distance`.
+    /// `direction` does not need to be normalized, while `max_distance` must be finite: an
+    /// infinite or NaN range hits nothing. Rays starting inside a box ignore it, and a ramp
+    /// only counts below its sloped top.
+    ///
+    /// Walks the grid cells the ray crosses in order and stops at the first cell that holds
+    /// a hit, so long rays only visit the cells along their path.
+    ///
+    /// ```
+    /// use bevy::math::Vec3;
+    /// use haemwend::collision::{StaticCollider, SurfaceType, WorldCollisionGrid};
+    ///
+    /// let wall = StaticCollider {
+    ///     center: Vec3::new(0.0, 1.5, -10.0),
+    ///     half_extents: Vec3::splat(1.5),
+    ///     surface: SurfaceType::Stone,
+    ///     slope_normal: None,
+    /// };
+    /// let grid = WorldCollisionGrid::from_colliders(vec![wall], 4.0);
+    ///
+    /// let hit = grid.raycast(Vec3::new(0.0, 1.0, 0.0), -Vec3::Z, 50.0).unwrap();
+    /// assert!((hit.distance - 8.5).abs() < 1e-4);
+    /// assert_eq!(hit.normal, Vec3::Z);
+    /// assert!(grid.raycast(Vec3::new(0.0, 1.0, 0.0), Vec3::Z, 50.0).is_none());
+    /// ```
+    pub fn raycast(&self, origin: Vec3, direction: Vec3, max_distance: f32) -> Option<RayHit> {
+        let direction = direction.normalize_or_zero();
+        // A non-finite range would walk cells forever once the ray leaves the colliders.
+        if direction == Vec3::ZERO || !max_distance.is_finite() || max_distance <= 0.0 {
+            return None;
+        }
+
+        if self.cells.is_empty() {
+            return None;
+        }
+
+        let cell_of = |value: f32| (value / self.cell_size).floor() as i32;
+        let mut cell = IVec2::new(cell_of(origin.x), cell_of(origin.z));
+        let step = IVec2::new(
+            direction.x.signum() as i32 * (direction.x.abs() > 1e-8) as i32,
+            direction.z.signum() as i32 * (direction.z.abs() > 1e-8) as i32,
+        );
+        // Ray distance to the next cell boundary on each axis, and between boundaries.
+        let first_boundary = |origin: f32, direction: f32, cell: i32, step: i32| {
+            if step == 0 {
+                return f32::INFINITY;
+            }
+            let boundary = (cell + (step > 0) as i32) as f32 * self.cell_size;
+            (boundary - origin) / direction
+        };
+        let mut t_next = Vec2::new(
+            first_boundary(origin.x, direction.x, cell.x, step.x),
+            first_boundary(origin.z, direction.z, cell.y, step.y),
+        );
+        let t_delta = Vec2::new(
+            self.cell_size / direction.x.abs(),
+            self.cell_size / direction.z.abs(),
+        );
+
+        let mut best: Option<RayHit> = None;
+        loop {
+            for collider in self.cells.get(&cell).into_iter().flatten() {
+                let Some((distance, normal)) = ray_aabb_hit(origin, direction, collider) else {
+                    continue;
+                };
+                if distance > max_distance || best.is_some_and(|hit| hit.distance <= distance) {
+                    continue;
+                }
+                best = Some(RayHit {
+                    distance,
+                    point: origin + direction * distance,
+                    normal,
+                    collider: *collider,
+                });
+            }
+
+            let cell_exit = t_next.x.min(t_next.y);
+            if best.is_some_and(|hit| hit.distance <= cell_exit) || cell_exit > max_distance {
+                return best;
+            }
+            if t_next.x < t_next.y {
+                cell.x += step.x;
+                t_next.x += t_delta.x;
+            } else {
+                cell.y += step.y;
+                t_next.y += t_delta.y;
+            }
+        }
+    }
+}
+
+/// Slab test returning the entry distance and face normal, with a ramp's box further cut by
+/// its sloped top. Misses when the ray starts inside the collider, so a camera inside
+/// geometry does not report a hit at distance zero.
+fn ray_aabb_hit(origin: Vec3, direction: Vec3, collider: &StaticCollider) -> Option<(f32, Vec3)> {
+    let min = collider.center - collider.half_extents;
+    let max = collider.center + collider.half_extents;
+    let mut t_enter = f32::NEG_INFINITY;
+    let mut t_exit = f32::INFINITY;
+    let mut enter_axis = 0;
+
+    for axis in 0..3 {
+        let (o, d) = (origin[axis], direction[axis]);
+        if d.abs() < 1e-8 {
+            if o < min[axis] || o > max[axis] {
+                return None;
+            }
+            continue;
+        }
+
+        let inv = 1.0 / d;
+        let mut t0 = (min[axis] - o) * inv;
+        let mut t1 = (max[axis] - o) * inv;
+        if t0 > t1 {
+            std::mem::swap(&mut t0, &mut t1);
+        }
+        if t0 > t_enter {
+            t_enter = t0;
+            enter_axis = axis;
+        }
+        t_exit = t_exit.min(t1);
+        if t_enter > t_exit {
+            return None;
+        }
+    }
+
+    let mut normal = Vec3::ZERO;
+    normal[enter_axis] = -direction[enter_axis].signum();
+
+    if let Some(slope_normal) = collider.slope_normal {
+        // The solid part is where `slope_normal · (p - center) <= 0`.
+        let height = slope_normal.dot(origin - collider.center);
+        let rate = slope_normal.dot(direction);
+        if rate.abs() < 1e-8 {
+            if height > 0.0 {
+                return None;
+            }
+        } else if rate < 0.0 {
+            let t_plane = -height / rate;
+            if t_plane > t_enter {
+                t_enter = t_plane;
+                normal = slope_normal;
+            }
+        } else {
+            t_exit = t_exit.min(-height / rate);
+        }
+        if t_enter > t_exit {
+            return None;
+        }
+    }
+
+    if t_enter < 0.0 {
+        return None;
+    }
+    Some((t_enter, normal))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    fn xz_distance_to_aabb(point: Vec3, collider: &StaticCollider) -> f32 {
+        let dx = ((point.x - collider.center.x).abs() - collider.half_extents.x).max(0.0);
+        let dz = ((point.z - collider.center.z).abs() - collider.half_extents.z).max(0.0);
+        (dx * dx + dz * dz).sqrt()
+    }
+
+    fn collider_strategy() -> impl Strategy<Value = StaticCollider> {
+        (
+            (-60.0_f32..60.0, 0.0_f32..4.0, -60.0_f32..60.0),
+            (0.1_f32..6.0, 0.1_f32..3.0, 0.1_f32..6.0),
+        )
+            .prop_map(|((x, y, z), (hx, hy, hz))| StaticCollider {
+                center: Vec3::new(x, y, z),
+                half_extents: Vec3::new(hx, hy, hz),
+                surface: SurfaceType::Neutral,
+                slope_normal: None,
+            })
+    }
+
+    #[test]
+    fn raycast_rejects_non_finite_ranges() {
+        let wall = StaticCollider {
+            center: Vec3::new(0.0, 1.5, -10.0),
+            half_extents: Vec3::splat(1.5),
+            surface: SurfaceType::Stone,
+            slope_normal: None,
+        };
+        let grid = WorldCollisionGrid::from_colliders(vec![wall], 4.0);
+        let origin = Vec3::new(0.0, 1.0, 0.0);
+        for max_distance in [f32::INFINITY, f32::NAN] {
+            assert!(grid.raycast(origin, -Vec3::Z, max_distance).is_none());
+            assert!(grid.raycast(origin, Vec3::Z, max_distance).is_none());
+            assert!(grid.raycast(origin, Vec3::Y, max_distance).is_none());
+        }
+        assert!(grid.raycast(origin, -Vec3::Z, f32::MAX).is_some());
+    }
+
+    proptest! {
+        #[test]
+        fn query_nearby_never_misses_colliders_within_radius(
+            colliders in prop::collection::vec(collider_strategy(), 0..200),
+            cell_size in prop::sample::select(vec![0.5_f32, 1.0, 4.0, 9.0]),
+            (x, y, z) in (-70.0_f32..70.0, -2.0_f32..6.0, -70.0_f32..70.0),
+            radius in 0.0_f32..10.0,
+        ) {
+            let center = Vec3::new(x, y, z);
+            let grid = WorldCollisionGrid::from_colliders(colliders.clone(), cell_size);
+
+            let mut visited = Vec::new();
+            grid.query_nearby(center, radius, |collider| visited.push(collider));
+
+            for expected in colliders
+                .iter()
+                .filter(|collider| xz_distance_to_aabb(center, collider) <= radius)
+            {
+                prop_assert!(
+                    visited.iter().any(|collider| collider.center == expected.center
+                        && collider.half_extents == expected.half_extents),
+                    "query at {center} r={radius} missed {expected:?}"
+                );
+            }
+        }
+
+        #[test]
+        fn raycast_matches_testing_every_collider(
+            colliders in prop::collection::vec(collider_strategy(), 0..200),
+            cell_size in prop::sample::select(vec![0.5_f32, 1.0, 4.0, 9.0]),
+            (x, y, z) in (-70.0_f32..70.0, -2.0_f32..6.0, -70.0_f32..70.0),
+            (dx, dy, dz) in (-1.0_f32..1.0, -1.0_f32..1.0, -1.0_f32..1.0),
+            max_distance in 0.0_f32..150.0,
+        ) {
+            let origin = Vec3::new(x, y, z);
+            let direction = Vec3::new(dx, dy, dz).normalize_or_zero();
+            prop_assume!(direction != Vec3::ZERO);
+            let grid = WorldCollisionGrid::from_colliders(colliders.clone(), cell_size);
+
+            let expected = colliders
+                .iter()
+                .filter_map(|collider| ray_aabb_hit(origin, direction, collider))
+                .map(|(distance, _)| distance)
+                .filter(|&distance| distance <= max_distance)
+                .reduce(f32::min);
+            let actual = grid
+                .raycast(origin, direction, max_distance)
+                .map(|hit| hit.distance);
+            match (expected, actual) {
+                (Some(expected), Some(actual)) => prop_assert!((expected - actual).abs() < 1e-4),
+                (None, None) => {}
+                _ => prop_assert!(false, "brute force {expected:?}, grid {actual:?}"),
+            }
+        }
+    }
+}
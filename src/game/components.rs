@@ -1,7 +1,11 @@
+use super::gameplay_physics::intersects_vertical_capsule_aabb;
+use super::settings::{GameSettings, LOOK_SENSITIVITY_DEFAULT, OverlayMetric};
 use super::types::GameAction;
 use bevy::prelude::*;
 use std::collections::HashMap;
 
+pub(super) use haemwend::collision::{StaticCollider, SurfaceType, WorldCollisionGrid};
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(super) enum MenuScreen {
     Main,
@@ -9,6 +13,8 @@ pub(super) enum MenuScreen {
     Debug,
     Keybinds,
     ExitConfirm,
+    /// Shown when the window's close button is pressed mid-run.
+    QuitConfirm,
 }
 
 #[derive(Resource, Debug)]
@@ -20,6 +26,14 @@ pub(super) struct MenuState {
     pub(super) dirty: bool,
 }
 
+impl MenuState {
+    /// Whether the open menu stops the simulation; practice mode (`pause_on_menu` off)
+    /// keeps it running and only takes the input away from gameplay.
+    pub(super) fn pauses_game(&self, settings: &GameSettings) -> bool {
+        self.open && settings.pause_on_menu
+    }
+}
+
 impl Default for MenuState {
     fn default() -> Self {
         Self {
@@ -36,6 +50,9 @@ impl Default for MenuState {
 pub(super) struct MouseLookCaptureState {
     pub(super) active: bool,
     pub(super) restore_position: Option<Vec2>,
+    /// Seconds left after a capture change during which mouse look ignores motion and a
+    /// released cursor stays hidden, hiding the lock/warp jump.
+    pub(super) transition_secs: f32,
 }
 
 #[derive(Component)]
@@ -53,16 +70,104 @@ pub(super) struct ThirdPersonCameraRig {
     pub(super) pitch: f32,
     pub(super) look_sensitivity: f32,
     pub(super) zoom_sensitivity: f32,
+    /// Current orbit distance, eased toward `orbit_distance` or `aim_distance`
+    /// depending on whether the player is aim-moving.
     pub(super) distance: f32,
+    pub(super) orbit_distance: f32,
+    pub(super) aim_distance: f32,
     pub(super) min_distance: f32,
     pub(super) max_distance: f32,
     pub(super) height: f32,
     pub(super) focus_height: f32,
+    /// Current sprint FOV boost and roll, eased toward their targets.
+    pub(super) fov_boost: f32,
+    pub(super) tilt: f32,
+    /// Lagged player position the camera orbits; `None` until the first frame.
+    pub(super) follow_target: Option<Vec3>,
+    /// Shoulder the camera sits over (1.0 right, -1.0 left) and the eased sideways offset.
+    pub(super) shoulder_side: f32,
+    pub(super) shoulder_offset: f32,
+    /// Eased extra height keeping the camera above the ground under it (follow-ground mode).
+    pub(super) ground_lift: f32,
+    /// Distance from the look target obstructions hold the camera at: snaps in on a hit and
+    /// eases back out once the way clears. Infinite while nothing is in the way.
+    pub(super) collision_distance: f32,
+    /// Easing back behind the player after Recenter Camera, until reached or the mouse orbits.
+    pub(super) recentering: bool,
 }
 
 #[derive(Component)]
 pub(super) struct PerformanceOverlayText;
 
+#[derive(Component)]
+pub(super) struct ComfortVignette;
+
+#[derive(Component)]
+pub(super) struct MeasurementReadoutText;
+
+#[derive(Component)]
+pub(super) struct JumpReadoutText;
+
+/// Arc of the jump in progress and the result of the last finished one.
+#[derive(Resource, Debug, Default)]
+pub(super) struct JumpTracker {
+    pub(super) start: Option<Vec3>,
+    pub(super) peak_y: f32,
+    /// (max height, horizontal distance) of the last landed jump.
+    pub(super) last_jump: Option<(f32, f32)>,
+}
+
+/// Photo mode: free-fly camera with FOV/roll control and all UI hidden. The camera and
+/// UI visibility are captured on entry and restored on exit.
+#[derive(Resource, Debug, Default)]
+pub(super) struct PhotoModeState {
+    pub(super) active: bool,
+    pub(super) yaw: f32,
+    pub(super) pitch: f32,
+    pub(super) roll: f32,
+    pub(super) fov: f32,
+    pub(super) saved_camera: Option<(Transform, Projection)>,
+    pub(super) saved_ui_visibility: HashMap<Entity, Visibility>,
+}
+
+impl PhotoModeState {
+    /// Starts the free-fly view from where `transform` and `projection` currently look.
+    pub(super) fn aim_from(&mut self, transform: &Transform, projection: &Projection) {
+        let (yaw, pitch, roll) = transform.rotation.to_euler(EulerRot::YXZ);
+        self.yaw = yaw;
+        self.pitch = pitch;
+        self.roll = roll;
+        self.fov = match projection {
+            Projection::Perspective(perspective) => perspective.fov,
+            _ => std::f32::consts::FRAC_PI_4,
+        };
+    }
+}
+
+/// One frame of free-fly camera input: look deltas in radians (yaw, pitch) and movement
+/// axes (forward, right, up). Read from the keyboard in photo mode, scripted by `--benchmark`.
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct FlyCameraInput {
+    pub(super) look: Vec2,
+    pub(super) movement: Vec3,
+    pub(super) fast: bool,
+}
+
+/// `--benchmark` run: a scripted photo-mode flythrough whose real frame times are summed
+/// up and logged before the app exits.
+#[derive(Resource, Debug, Default)]
+pub(super) struct BenchmarkRun {
+    pub(super) scenario_id: String,
+    pub(super) elapsed_secs: f32,
+    pub(super) frame_times: Vec<f32>,
+}
+
+/// Points picked with the measurement tool; a third click starts a new measurement.
+#[derive(Resource, Debug, Default)]
+pub(super) struct MeasurementState {
+    pub(super) points: Vec<Vec3>,
+}
+
 #[derive(Component)]
 pub(super) struct PlayerBlobShadow;
 
@@ -75,12 +180,174 @@ pub(super) struct SkyboxCube;
 #[derive(Component)]
 pub(super) struct GroundPlane;
 
-#[derive(Component, Clone, Copy)]
+/// Debug freeze for movement and animation; rendering and the camera keep running.
+#[derive(Resource, Debug, Default)]
+pub(super) struct SimPaused {
+    pub(super) paused: bool,
+    /// Frames to advance by one fixed step while paused.
+    pub(super) pending_steps: u32,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(super) struct ControllerContact {
+    pub(super) point: Vec3,
+    pub(super) normal: Vec3,
+}
+
+/// Player controller contacts of the last simulated frame, for the contact debug view.
+#[derive(Resource, Debug, Default)]
+pub(super) struct ControllerContacts {
+    pub(super) points: Vec<ControllerContact>,
+}
+
+/// World-space box around one of the player's visual parts, refreshed every frame while
+/// `DebugSettings::limb_hitboxes` is on so hit detection can target body parts.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct LimbHitbox {
+    pub(super) part: Entity,
+    pub(super) center: Vec3,
+    pub(super) half_extents: Vec3,
+}
+
+#[derive(Resource, Debug, Default)]
+pub(super) struct LimbHitboxes {
+    pub(super) boxes: Vec<LimbHitbox>,
+}
+
+impl LimbHitboxes {
+    /// Limbs overlapped by a vertical capsule, e.g. a melee swing or a thick projectile.
+    pub(super) fn hit_by_capsule(
+        &self,
+        center: Vec3,
+        radius: f32,
+        half_height: f32,
+    ) -> impl Iterator<Item = &LimbHitbox> {
+        self.boxes.iter().filter(move |hitbox| {
+            intersects_vertical_capsule_aabb(
+                center,
+                radius,
+                half_height,
+                hitbox.center,
+                hitbox.half_extents,
+            )
+        })
+    }
+}
+
+/// Open CSV file of a diagnostics recording started with `DebugSettings::record_diagnostics`.
+#[derive(Resource, Debug, Default)]
+pub(super) struct DiagnosticsRecorder {
+    pub(super) writer: Option<std::io::BufWriter<std::fs::File>>,
+    pub(super) path: String,
+    pub(super) elapsed_secs: f32,
+    pub(super) since_flush_secs: f32,
+}
+
+/// Step budget of a `--headless` run; `None` runs until the process is stopped.
+#[derive(Resource, Debug, Default)]
+pub(super) struct HeadlessRun {
+    pub(super) steps_left: Option<u32>,
+    pub(super) steps_done: u32,
+}
+
+/// Id of the last scenario that loaded; persisted as `PersistedConfig::last_scenario_id`.
+#[derive(Resource, Debug, Default)]
+pub(super) struct LastScenario(pub(super) Option<String>);
+
+/// Start menu button of the last played scenario, kept highlighted while not hovered.
+#[derive(Component)]
+pub(super) struct LastPlayedScenarioButton;
+
+/// Message to put on screen once the UI exists, e.g. a `--scenario` id that wasn't found.
+#[derive(Resource, Debug, Default)]
+pub(super) struct PendingNotice(pub(super) Option<String>);
+
+/// On-screen notice that despawns itself after `remaining_secs`.
+#[derive(Component)]
+pub(super) struct TransientNotice {
+    pub(super) remaining_secs: f32,
+}
+
+/// A frame that took longer than `DebugSettings::hitch_threshold_ms`.
+#[derive(Debug, Clone)]
+pub(super) struct HitchReport {
+    pub(super) frame_time_ms: f64,
+    /// Seconds since startup.
+    pub(super) at_secs: f32,
+    pub(super) scenario: Option<String>,
+    pub(super) player_position: Option<Vec3>,
+}
+
+/// Most recent hitches, newest last, shown in the Debug menu.
+#[derive(Resource, Debug, Default)]
+pub(super) struct HitchLog {
+    pub(super) recent: std::collections::VecDeque<HitchReport>,
+}
+
+/// Rolling window of real frame times (ms), oldest first, for the FPS graph.
+#[derive(Resource, Debug, Default)]
+pub(super) struct FrameTimeHistory {
+    pub(super) samples: std::collections::VecDeque<f32>,
+}
+
+#[derive(Component)]
+pub(super) struct FpsGraph;
+
+/// Bar `0` of the FPS graph is the oldest sample.
+#[derive(Component)]
+pub(super) struct FpsGraphBar(pub(super) usize);
+
+/// Native-resolution camera that draws the UI (and the upscaled scene when scaled).
+#[derive(Component)]
+pub(super) struct UiOverlayCamera;
+
+/// Full-window image showing the 3D camera's scaled render target.
+#[derive(Component)]
+pub(super) struct RenderScaleOutput;
+
+#[derive(Component, Clone)]
 pub(super) struct FogAlphaMaterialState {
+    pub(super) source: Handle<StandardMaterial>,
     pub(super) base_alpha: f32,
     pub(super) current_alpha_factor: f32,
-    pub(super) original_alpha_mode: AlphaMode,
-    pub(super) original_fog_enabled: bool,
+}
+
+/// Alpha-fog material variants keyed by (source material, alpha level, ground).
+/// Sharing variants keeps props with the same mesh batched into one instanced draw.
+#[derive(Resource, Debug, Default)]
+pub(super) struct FogAlphaMaterialCache {
+    pub(super) variants: HashMap<(AssetId<StandardMaterial>, u8, bool), Handle<StandardMaterial>>,
+}
+
+/// A foot touched down while walking or running.
+#[derive(Message, Debug, Clone, Copy)]
+pub(super) struct FootstepMessage {
+    pub(super) position: Vec3,
+    pub(super) surface: SurfaceType,
+}
+
+/// The controller touched ground after being airborne.
+#[derive(Message, Debug, Clone, Copy)]
+pub(super) struct LandedMessage {
+    pub(super) position: Vec3,
+    pub(super) surface: SurfaceType,
+    /// Downward speed just before touching down, in m/s.
+    pub(super) impact_speed: f32,
+}
+
+/// Short-lived puff spawned by `spawn_dust_particles`; shrinks away over its lifetime.
+#[derive(Component)]
+pub(super) struct DustParticle {
+    pub(super) velocity: Vec3,
+    pub(super) age: f32,
+    pub(super) lifetime: f32,
+    pub(super) size: f32,
+}
+
+/// Mesh and per-surface materials shared by every dust puff, created on first use.
+pub(super) struct DustAssets {
+    pub(super) mesh: Handle<Mesh>,
+    pub(super) materials: HashMap<SurfaceType, Handle<StandardMaterial>>,
 }
 
 #[derive(Component)]
@@ -92,18 +359,72 @@ pub(super) struct InGameEntity;
 #[derive(Component)]
 pub(super) struct StairSteepnessLabel;
 
+/// Inert practice target. Tracks whether the player is inside its trigger radius so
+/// interaction features have something to react to.
+#[derive(Component, Debug, Clone, Copy)]
+pub(super) struct TargetDummy {
+    pub(super) trigger_radius: f32,
+    pub(super) player_in_range: bool,
+}
+
+/// Walks the entity between ground waypoints using the same slide logic as the player.
+/// Waypoints are ground positions on the XZ plane (`Vec2::y` is world Z).
+#[derive(Component, Clone)]
+pub(super) struct Patrol {
+    pub(super) waypoints: Vec<Vec2>,
+    pub(super) speed: f32,
+    pub(super) next_waypoint: usize,
+    pub(super) collider: PlayerCollider,
+}
+
 #[derive(Component)]
 pub(super) struct PlayerVisualPart;
 
 #[derive(Component)]
 pub(super) struct StartMenuRoot;
 
+/// Per-scenario collider breakdown line in the start menu, hidden unless enabled.
+#[derive(Component)]
+pub(super) struct ScenarioStatsText;
+
+#[derive(Component)]
+pub(super) struct ScenarioStatsToggleLabel;
+
 #[derive(Component)]
 pub(super) struct StartMenuCamera;
 
+/// Everything belonging to the start menu's scenario preview.
+#[derive(Component)]
+pub(super) struct ScenarioPreview;
+
+#[derive(Component)]
+pub(super) struct ScenarioPreviewCamera {
+    pub(super) radius: f32,
+    pub(super) height: f32,
+    pub(super) yaw: f32,
+    pub(super) since_render_secs: f32,
+}
+
 #[derive(Component)]
 pub(super) struct ProceduralHumanVisualRoot;
 
+#[derive(Component)]
+pub(super) struct PlayerLodProxy;
+
+#[derive(Component)]
+pub(super) struct PropLod {
+    pub(super) near_material: Handle<StandardMaterial>,
+    pub(super) far_material: Handle<StandardMaterial>,
+    pub(super) far: bool,
+}
+
+/// Lit and flat-shaded material of a player part, switched by the human shading setting.
+#[derive(Component, Clone)]
+pub(super) struct PlayerShading {
+    pub(super) lit: Handle<StandardMaterial>,
+    pub(super) flat: Handle<StandardMaterial>,
+}
+
 #[derive(Component)]
 pub(super) struct ProceduralHumanAnimState {
     pub(super) phase: f32,
@@ -112,6 +433,42 @@ pub(super) struct ProceduralHumanAnimState {
     pub(super) visual_center_y: f32,
     pub(super) ground_ik_weight: f32,
     pub(super) was_grounded: bool,
+    /// Simulation time driving the idle motion, so it freezes with the simulation.
+    pub(super) idle_time: f32,
+    /// Surface under each foot at its last plant (left, right), for footstep events.
+    pub(super) foot_surfaces: [SurfaceType; 2],
+    /// Whether each foot was in stance last frame, so a touchdown fires one footstep.
+    pub(super) feet_planted: [bool; 2],
+    /// Seconds of standing still left before the next idle fidget plays.
+    pub(super) fidget_wait_secs: f32,
+    pub(super) fidget: Option<IdleFidget>,
+    /// Leg IK results per foot (left, right) for the animation debug view.
+    pub(super) foot_ik: [FootIkDebug; 2],
+}
+
+/// One foot's leg IK result of the last frame, recorded while `show_animation_debug` is on.
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct FootIkDebug {
+    /// Ankle target the IK aimed for, and where the clamped leg actually put the ankle.
+    pub(super) attempted: Vec3,
+    pub(super) achieved: Vec3,
+    pub(super) reachable: bool,
+    /// Frames this foot's target was out of reach since the debug view was turned on.
+    pub(super) unreachable_frames: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum IdleFidgetKind {
+    LookAround,
+    WeightShift,
+}
+
+/// A short idle gesture in progress; `side` is -1.0 or 1.0.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct IdleFidget {
+    pub(super) kind: IdleFidgetKind,
+    pub(super) side: f32,
+    pub(super) elapsed: f32,
 }
 
 #[derive(Component, Clone, Copy, PartialEq, Eq)]
@@ -159,7 +516,10 @@ pub(super) struct StartMenuButton(pub(super) StartMenuButtonAction);
 
 #[derive(Clone, Copy)]
 pub(super) enum StartMenuButtonAction {
+    Continue(usize),
+    QuickStart,
     StartScenario(usize),
+    ToggleScenarioStats,
     ExitGame,
 }
 
@@ -172,18 +532,77 @@ pub(super) enum MenuButtonAction {
     OpenExitConfirm,
     BackMain,
     ExitNow,
+    QuitGame,
     CycleDisplayMode,
     CycleResolution,
-    ToggleMsaa,
+    CycleAntiAliasing,
+    CycleRenderScale,
     ToggleShadowMode,
+    CycleHumanShading,
+    CycleBodyPreset,
+    ToggleHeadTracking,
+    ToggleSimplePlayerModel,
+    ToggleGroundTexture,
+    ToggleBakeWorldShadows,
+    ToggleDustParticles,
+    ToggleCameraRelativeMovement,
+    ToggleAimMode,
+    ToggleSprintMode,
+    ToggleChaosMode,
+    ToggleLimbHitboxes,
+    CycleQuickStartScenario,
+    TogglePauseOnMenu,
+    ToggleTurnAcceleration,
+    ToggleReduceMotion,
+    ToggleComfortVignette,
+    CycleGameFeel,
+    CycleFov,
+    CycleLookSensitivity,
+    ToggleInvertY,
+    CycleCameraFocusHeight,
+    CycleCameraHeight,
+    CycleCameraShoulderOffset,
+    ToggleCameraFollowGround,
+    TogglePlayerCameraFade,
+    ToggleMenuScenarioPreview,
+    TogglePauseMenuDim,
+    ToggleChargeJump,
+    CycleJumpApexHang,
+    ToggleWallJump,
+    ToggleAlwaysCaptureMouse,
+    ToggleScrollOrbit,
     TogglePerformanceOverlay,
+    ToggleFpsGraph,
+    ToggleInputPanel,
+    CycleOverlayCorner,
+    ToggleOverlayMetric(OverlayMetric),
     ToggleBakedShadows,
     ToggleFog,
     ToggleCollisionShapes,
+    ToggleContactPoints,
+    ToggleJumpReadout,
     ToggleAnimationDebug,
     ToggleWireframe,
     ToggleWorldAxes,
+    CycleWorldAxesAnchor,
+    ToggleGroundGrid,
+    ToggleSunDirection,
+    ToggleMeasurementTool,
+    CycleTimeScale,
+    ResetTimeScale,
+    ToggleDeltaCap,
+    ToggleDiagnosticsRecording,
+    CycleDiagnosticsDuration,
+    ToggleDiagnosticsEntityCount,
+    CycleHitchThreshold,
+    ClearHitchLog,
     StartRebind(GameAction),
+    KeepPrimaryKey(GameAction),
+    RestoreDefaultKeys(GameAction),
+    ApplyClassicWasd,
+    ExportKeybinds,
+    ImportKeybinds,
+    CycleLanguage,
     ClearKeybindFilter,
 }
 
@@ -196,84 +615,7 @@ pub(super) struct PlayerCollider {
 #[derive(Component, Clone, Copy)]
 pub(super) struct WorldCollider {
     pub(super) half_extents: Vec3,
-}
-
-#[derive(Clone, Copy, Debug)]
-pub(super) struct StaticCollider {
-    pub(super) center: Vec3,
-    pub(super) half_extents: Vec3,
-}
-
-#[derive(Resource, Debug)]
-pub(super) struct WorldCollisionGrid {
-    pub(super) cell_size: f32,
-    pub(super) cells: HashMap<IVec2, Vec<StaticCollider>>,
-}
-
-impl Default for WorldCollisionGrid {
-    fn default() -> Self {
-        Self {
-            cell_size: 4.0,
-            cells: HashMap::new(),
-        }
-    }
-}
-
-impl WorldCollisionGrid {
-    pub(super) fn from_colliders(colliders: Vec<StaticCollider>, cell_size: f32) -> Self {
-        let mut grid = Self {
-            cell_size: cell_size.max(0.25),
-            cells: HashMap::new(),
-        };
-
-        for collider in colliders {
-            let min_x =
-                ((collider.center.x - collider.half_extents.x) / grid.cell_size).floor() as i32;
-            let max_x =
-                ((collider.center.x + collider.half_extents.x) / grid.cell_size).floor() as i32;
-            let min_z =
-                ((collider.center.z - collider.half_extents.z) / grid.cell_size).floor() as i32;
-            let max_z =
-                ((collider.center.z + collider.half_extents.z) / grid.cell_size).floor() as i32;
-
-            for x in min_x..=max_x {
-                for z in min_z..=max_z {
-                    grid.cells
-                        .entry(IVec2::new(x, z))
-                        .or_default()
-                        .push(collider);
-                }
-            }
-        }
-
-        grid
-    }
-
-    pub(super) fn query_nearby(
-        &self,
-        center: Vec3,
-        radius: f32,
-        mut visit: impl FnMut(StaticCollider),
-    ) {
-        if self.cells.is_empty() {
-            return;
-        }
-
-        let min_x = ((center.x - radius) / self.cell_size).floor() as i32;
-        let max_x = ((center.x + radius) / self.cell_size).floor() as i32;
-        let min_z = ((center.z - radius) / self.cell_size).floor() as i32;
-        let max_z = ((center.z + radius) / self.cell_size).floor() as i32;
-
-        for x in min_x..=max_x {
-            for z in min_z..=max_z {
-                if let Some(cell_colliders) = self.cells.get(&IVec2::new(x, z)) {
-                    for collider in cell_colliders {
-                        visit(*collider);
-                    }
-                }
-            }
-        }
-    }
+    pub(super) surface: SurfaceType,
 }
 
 #[derive(Component, Default)]
@@ -281,6 +623,21 @@ pub(super) struct PlayerKinematics {
     pub(super) horizontal_velocity: Vec2,
     pub(super) vertical_velocity: f32,
     pub(super) grounded: bool,
+    pub(super) auto_walk: bool,
+    /// Aim-move: the body follows the camera yaw. Mirrors RMB, or latches when `toggle_aim` is on.
+    pub(super) aiming: bool,
+    /// Sprint latched on by the Sprint key when `sprint_toggle` is on.
+    pub(super) sprint_latched: bool,
+    /// Signed seconds the keyboard turn has been held (sign = direction).
+    pub(super) turn_hold_secs: f32,
+    /// Seconds left in which a jump is still allowed after leaving the ground.
+    pub(super) coyote_timer: f32,
+    /// Seconds a recent Jump press stays queued for the next grounded frame.
+    pub(super) jump_buffer_timer: f32,
+    /// Charged-jump progress in 0..=1 while Jump is held on the ground.
+    pub(super) jump_charge: Option<f32>,
+    /// Normal of the last wall hit while airborne and the seconds since that hit.
+    pub(super) last_wall_contact: Option<(Vec2, f32)>,
 }
 
 impl Default for Player {
@@ -300,13 +657,23 @@ impl Default for ThirdPersonCameraRig {
         Self {
             yaw: 0.0,
             pitch: -0.35,
-            look_sensitivity: 0.0025,
+            look_sensitivity: LOOK_SENSITIVITY_DEFAULT,
             zoom_sensitivity: 0.35,
             distance: 8.0,
+            orbit_distance: 8.0,
+            aim_distance: 5.0,
             min_distance: 2.5,
             max_distance: 20.0,
             height: 2.0,
             focus_height: 1.1,
+            fov_boost: 0.0,
+            tilt: 0.0,
+            follow_target: None,
+            shoulder_side: 1.0,
+            shoulder_offset: 0.0,
+            ground_lift: 0.0,
+            collision_distance: f32::INFINITY,
+            recentering: false,
         }
     }
 }
@@ -320,6 +687,12 @@ impl ProceduralHumanAnimState {
             visual_center_y: position.y,
             ground_ik_weight: 1.0,
             was_grounded: true,
+            idle_time: 0.0,
+            foot_surfaces: [SurfaceType::Neutral; 2],
+            feet_planted: [true; 2],
+            fidget_wait_secs: 6.0,
+            fidget: None,
+            foot_ik: [FootIkDebug::default(); 2],
         }
     }
 }
@@ -1,14 +1,89 @@
 use super::*;
+use bevy::asset::RenderAssetUsages;
+use bevy::camera::primitives::Aabb;
 use bevy::gizmos::config::{DefaultGizmoConfigGroup, GizmoConfigStore};
+use bevy::mesh::{Indices, PrimitiveTopology};
+use bevy::render::view::screenshot::{Screenshot, save_to_disk};
+use haemwend::blender_model_editor::{GRID_EXTENT_METERS, GRID_MAJOR_STEP_METERS};
+use std::collections::HashMap;
+use std::io::Write;
 
 const CONTROLLER_MAX_SLIDES: usize = 4;
 const CONTROLLER_SKIN: f32 = 0.02;
 const CONTROLLER_STEP_HEIGHT: f32 = 0.38;
 const CONTROLLER_STEP_DROP: f32 = 0.25;
+/// Steepest ramp the controller walks on; steeper faces are slid down instead.
+const MAX_SLOPE_DEG: f32 = 45.0;
 const HORIZONTAL_GROUND_ACCEL: f32 = 26.0;
 const HORIZONTAL_AIR_ACCEL: f32 = 4.8;
 const HORIZONTAL_GROUND_FRICTION: f32 = 14.0;
 const HORIZONTAL_AIR_DRAG: f32 = 0.9;
+const CAMERA_RELATIVE_TURN_RATE: f32 = 10.0;
+/// Capsule half-height while crouched; standing uses the body preset's.
+const CROUCH_HALF_HEIGHT: f32 = 0.6;
+/// Walk speed multiplier while crouched; sprint has no effect.
+const CROUCH_SPEED_FACTOR: f32 = 0.45;
+/// Metres per second the capsule half-height changes when crouching or standing up.
+const CROUCH_HEIGHT_RATE: f32 = 3.0;
+const MEASUREMENT_MAX_DISTANCE: f32 = 250.0;
+const PATROL_ARRIVE_DISTANCE: f32 = 0.1;
+const PHOTO_MODE_FLY_SPEED: f32 = 6.0;
+const PHOTO_MODE_FAST_MULTIPLIER: f32 = 4.0;
+const PHOTO_MODE_ROLL_SPEED: f32 = 0.8;
+const PHOTO_MODE_SCREENSHOT_DIR: &str = "screenshots";
+/// Benchmark flythrough: frames skipped while the scene settles, recorded length, and the
+/// scripted turn (one full circle over the run) and downward tilt of the camera.
+const BENCHMARK_WARMUP_SECS: f32 = 2.0;
+const BENCHMARK_SECS: f32 = 30.0;
+const BENCHMARK_TURN_RATE: f32 = std::f32::consts::TAU / BENCHMARK_SECS;
+const BENCHMARK_PITCH: f32 = -0.3;
+const DIAGNOSTICS_CSV_DIR: &str = "diagnostics";
+const DIAGNOSTICS_FLUSH_SECS: f32 = 1.0;
+const HITCH_LOG_LEN: usize = 8;
+const SIM_STEP_DT: f32 = 1.0 / 60.0;
+const TURN_ACCEL_START_FACTOR: f32 = 0.3;
+const TURN_ACCEL_PEAK_FACTOR: f32 = 1.5;
+/// Idle fidgets: random gap between gestures while standing still, and gesture length.
+const IDLE_FIDGET_INTERVAL_SECS: std::ops::Range<f32> = 5.0..11.0;
+const IDLE_FIDGET_DURATION_SECS: f32 = 2.2;
+const WALL_JUMP_WINDOW_SECS: f32 = 0.2;
+/// Grace after running off an edge during which Jump still works.
+const COYOTE_TIME_SECS: f32 = 0.1;
+/// How long a Jump press is remembered so it fires on landing.
+const JUMP_BUFFER_SECS: f32 = 0.1;
+/// Gait below which foot plants are shuffles rather than steps and raise no dust.
+const FOOTSTEP_MIN_GAIT: f32 = 0.2;
+/// FPS graph: samples shown, the frame time that fills a bar, and its colour thresholds.
+pub(super) const FPS_GRAPH_SAMPLES: usize = 120;
+pub(super) const FPS_GRAPH_HEIGHT_PX: f32 = 48.0;
+const FPS_GRAPH_FULL_SCALE_MS: f32 = 50.0;
+const FPS_GRAPH_OK_MS: f32 = 1000.0 / 60.0;
+const FPS_GRAPH_SLOW_MS: f32 = 1000.0 / 30.0;
+const LANDING_DUST_MIN_SPEED: f32 = 2.0;
+const MOUSE_CAPTURE_TRANSITION_SECS: f32 = 0.08;
+const CAMERA_FEEL_RESPONSE: f32 = 6.0;
+/// Easing rate of the switch between the orbit and aim-move zoom distances.
+const CAMERA_ZOOM_BLEND_RATE: f32 = 8.0;
+const CAMERA_RECENTER_RATE: f32 = 12.0;
+/// Yaw per scroll line for horizontal-scroll orbiting, and pixels counted as one line.
+const SCROLL_ORBIT_RADIANS_PER_LINE: f32 = 0.1;
+const SCROLL_PIXELS_PER_LINE: f32 = 16.0;
+/// Player opacity once the camera is right on top of it, and the distance where that's hit.
+const PLAYER_FADE_MIN_ALPHA: f32 = 0.2;
+const PLAYER_FADE_NEAR_DISTANCE: f32 = 0.6;
+const CAMERA_COLLISION_RADIUS: f32 = 0.18;
+const CAMERA_COLLISION_SKIN: f32 = 0.08;
+/// Easing rate of the camera moving back out once an obstruction clears.
+const CAMERA_COLLISION_RESTORE_RATE: f32 = 4.0;
+/// Follow-ground camera: clearance kept above the ground under the camera, how far above
+/// the camera a surface may be and still count as ground, and the lift easing rates.
+const CAMERA_GROUND_CLEARANCE: f32 = 0.6;
+const CAMERA_GROUND_PROBE_ABOVE: f32 = 1.5;
+const CAMERA_GROUND_RISE_RATE: f32 = 8.0;
+const CAMERA_GROUND_FALL_RATE: f32 = 2.5;
+
+/// Share of the camera direction the head follows when it tracks the camera without LMB.
+const HEAD_TRACK_ALWAYS_WEIGHT: f32 = 0.6;
 
 pub(super) fn configure_debug_gizmo_depth(
     debug: Res<DebugSettings>,
@@ -23,71 +98,175 @@ pub(super) fn configure_debug_gizmo_depth(
     }
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub(super) fn player_move(
     keys: Res<ButtonInput<KeyCode>>,
     mouse_buttons: Res<ButtonInput<MouseButton>>,
     time: Res<Time>,
+    sim: Res<SimPaused>,
+    debug: Res<DebugSettings>,
     menu: Res<MenuState>,
+    photo_mode: Res<PhotoModeState>,
     keybinds: Res<GameKeybinds>,
+    settings: Res<GameSettings>,
     camera_query: Query<&ThirdPersonCameraRig, With<Camera3d>>,
     mut player_query: Query<(
         &mut Transform,
         &Player,
-        &PlayerCollider,
+        &mut PlayerCollider,
         &mut PlayerKinematics,
     )>,
     world_collision_grid: Res<WorldCollisionGrid>,
+    mut contacts: ResMut<ControllerContacts>,
+    mut jump_tracker: ResMut<JumpTracker>,
+    mut landings: MessageWriter<LandedMessage>,
+    patrol_colliders: Query<(&Transform, &WorldCollider), (With<Patrol>, Without<Player>)>,
 ) {
+    let Ok((mut transform, player, mut player_collider, mut kinematics)) =
+        player_query.single_mut()
+    else {
+        return;
+    };
+
     if menu.open {
+        kinematics.aiming = false;
+    }
+    if menu.pauses_game(&settings) {
+        kinematics.auto_walk = false;
         return;
     }
 
-    let Ok(camera_rig) = camera_query.single() else {
+    if photo_mode.active {
         return;
+    }
+
+    // In practice mode the menu owns the input, so gameplay sees nothing pressed.
+    let no_keys = ButtonInput::<KeyCode>::default();
+    let no_buttons = ButtonInput::<MouseButton>::default();
+    let (keys, mouse_buttons) = if menu.open {
+        (&no_keys, &no_buttons)
+    } else {
+        (&*keys, &*mouse_buttons)
     };
 
-    let Ok((mut transform, player, player_collider, mut kinematics)) = player_query.single_mut()
-    else {
+    let dt = sim_delta_secs(&time, &sim, &debug);
+    if dt <= 0.0 {
+        return;
+    }
+
+    let Ok(camera_rig) = camera_query.single() else {
         return;
     };
 
-    let rmb_held = mouse_buttons.pressed(MouseButton::Right);
-    if rmb_held {
+    if keybinds.action_just_pressed(keys, mouse_buttons, GameAction::AutoWalk) {
+        kinematics.auto_walk = !kinematics.auto_walk;
+    } else if [
+        GameAction::MoveForward,
+        GameAction::MoveBackward,
+        GameAction::StrafeLeft,
+        GameAction::StrafeRight,
+    ]
+    .into_iter()
+    .any(|action| keybinds.action_just_pressed(keys, mouse_buttons, action))
+    {
+        kinematics.auto_walk = false;
+    }
+
+    if settings.toggle_aim {
+        if mouse_buttons.just_pressed(MouseButton::Right) {
+            kinematics.aiming = !kinematics.aiming;
+        }
+    } else {
+        kinematics.aiming = mouse_buttons.pressed(MouseButton::Right);
+    }
+    let aiming = kinematics.aiming;
+    if aiming {
         transform.rotation = Quat::from_rotation_y(camera_rig.yaw);
     }
 
-    let dt = time.delta_secs();
-    if !rmb_held {
-        let turn_axis = (keybinds.action_pressed(&keys, GameAction::TurnRight) as i8
-            - keybinds.action_pressed(&keys, GameAction::TurnLeft) as i8)
+    // Camera-relative mode: WASD follows the camera yaw, turn keys strafe and the
+    // character turns toward its movement direction instead.
+    let camera_relative = settings.camera_relative_movement && !aiming;
+
+    if !aiming && !camera_relative {
+        let turn_axis = (keybinds.action_pressed(keys, mouse_buttons, GameAction::TurnRight) as i8
+            - keybinds.action_pressed(keys, mouse_buttons, GameAction::TurnLeft) as i8)
             as f32;
+        if turn_axis == 0.0 || turn_axis.signum() != kinematics.turn_hold_secs.signum() {
+            kinematics.turn_hold_secs = 0.0;
+        }
+        kinematics.turn_hold_secs += turn_axis * dt;
+
         if turn_axis != 0.0 {
-            transform.rotate_y(-turn_axis * player.turn_speed * dt);
+            let turn_rate = if settings.turn_acceleration_enabled {
+                let ramp = (kinematics.turn_hold_secs.abs()
+                    / settings.turn_acceleration_ramp_secs.max(1e-3))
+                .min(1.0);
+                player.turn_speed
+                    * TURN_ACCEL_START_FACTOR.lerp(TURN_ACCEL_PEAK_FACTOR, smoothstep01(ramp))
+            } else {
+                player.turn_speed
+            };
+            transform.rotate_y(-turn_axis * turn_rate * dt);
         }
+    } else {
+        kinematics.turn_hold_secs = 0.0;
     }
 
-    let forward = transform.rotation * -Vec3::Z;
-    let right = transform.rotation * Vec3::X;
+    let movement_basis = if camera_relative {
+        Quat::from_rotation_y(camera_rig.yaw)
+    } else {
+        transform.rotation
+    };
+    let forward = movement_basis * -Vec3::Z;
+    let right = movement_basis * Vec3::X;
+
+    let axes = movement_axes(
+        &keybinds,
+        keys,
+        mouse_buttons,
+        kinematics.auto_walk,
+        aiming || camera_relative,
+    );
+    let movement = (forward * axes.y + right * axes.x).normalize_or_zero();
+    let has_input = movement.length_squared() > 1e-6;
 
-    let forward_axis = (keybinds.action_pressed(&keys, GameAction::MoveForward) as i8
-        - keybinds.action_pressed(&keys, GameAction::MoveBackward) as i8)
-        as f32;
+    if camera_relative && has_input {
+        let target_yaw = (-movement.x).atan2(-movement.z);
+        let (current_yaw, _, _) = transform.rotation.to_euler(EulerRot::YXZ);
+        let max_turn = CAMERA_RELATIVE_TURN_RATE * dt;
+        let turn = shortest_angle_delta(current_yaw, target_yaw).clamp(-max_turn, max_turn);
+        transform.rotation = Quat::from_rotation_y(current_yaw + turn);
+    }
 
-    let strafe_axis = if rmb_held {
-        let strafe_right = keybinds.action_pressed(&keys, GameAction::StrafeRight)
-            || keybinds.action_pressed(&keys, GameAction::TurnRight);
-        let strafe_left = keybinds.action_pressed(&keys, GameAction::StrafeLeft)
-            || keybinds.action_pressed(&keys, GameAction::TurnLeft);
-        (strafe_right as i8 - strafe_left as i8) as f32
+    let sprinting = if settings.sprint_toggle {
+        if !has_input {
+            kinematics.sprint_latched = false;
+        } else if keybinds.action_just_pressed(keys, mouse_buttons, GameAction::Sprint) {
+            kinematics.sprint_latched = !kinematics.sprint_latched;
+        }
+        kinematics.sprint_latched
     } else {
-        (keybinds.action_pressed(&keys, GameAction::StrafeRight) as i8
-            - keybinds.action_pressed(&keys, GameAction::StrafeLeft) as i8) as f32
+        keybinds.action_pressed(keys, mouse_buttons, GameAction::Sprint)
     };
+    let stand_half_height = settings.body_preset.proportions().half_height();
+    let crouch_half_height = if keybinds.action_pressed(keys, mouse_buttons, GameAction::Crouch) {
+        CROUCH_HALF_HEIGHT.min(stand_half_height)
+    } else {
+        stand_half_height
+    };
+    update_crouch_height(
+        &mut transform.translation,
+        &mut player_collider,
+        crouch_half_height,
+        dt,
+        &world_collision_grid,
+    );
+    let crouched = player_collider.half_height < stand_half_height - 1e-3;
 
-    let movement = (forward * forward_axis + right * strafe_axis).normalize_or_zero();
-    let has_input = movement.length_squared() > 1e-6;
-
-    let speed = if keybinds.action_pressed(&keys, GameAction::Sprint) {
+    let speed = if crouched {
+        player.walk_speed * CROUCH_SPEED_FACTOR
+    } else if sprinting {
         player.sprint_speed
     } else {
         player.walk_speed
@@ -113,52 +292,507 @@ pub(super) fn player_move(
         kinematics.horizontal_velocity *= damping;
     }
 
+    let mut wall_jumped = false;
+    if settings.wall_jump_enabled
+        && !kinematics.grounded
+        && keybinds.action_just_pressed(keys, mouse_buttons, GameAction::Jump)
+        && let Some((wall_normal, since_contact)) = kinematics.last_wall_contact
+        && since_contact <= WALL_JUMP_WINDOW_SECS
+    {
+        kinematics.horizontal_velocity = wall_normal * settings.wall_jump_push_speed;
+        kinematics.vertical_velocity = settings.wall_jump_up_speed;
+        kinematics.last_wall_contact = None;
+        wall_jumped = true;
+    }
+
     let desired_delta = Vec3::new(
         kinematics.horizontal_velocity.x * dt,
         0.0,
         kinematics.horizontal_velocity.y * dt,
     );
 
-    let horizontal_start = transform.translation;
-    let mut next_position = transform.translation;
-    let (slid_position, blocked) = move_with_slide(
-        next_position,
-        desired_delta,
+    let jump_speed = if settings.charge_jump_enabled {
+        // No coyote time or buffering for charged jumps; drop any timers left from before.
+        kinematics.coyote_timer = 0.0;
+        kinematics.jump_buffer_timer = 0.0;
+        charge_jump(
+            keys,
+            mouse_buttons,
+            &keybinds,
+            &settings,
+            &mut kinematics,
+            dt,
+        )
+    } else {
+        kinematics.jump_charge = None;
+        let pressed =
+            !wall_jumped && keybinds.action_just_pressed(keys, mouse_buttons, GameAction::Jump);
+        buffered_jump(&mut kinematics, pressed, dt).then_some(player.jump_speed)
+    };
+
+    contacts.points.clear();
+    let start_position = transform.translation;
+    let was_grounded = kinematics.grounded;
+    let fall_speed = -kinematics.vertical_velocity;
+    let gravity = apex_hang_gravity(
+        player.gravity,
+        &kinematics,
+        settings.jump_apex_hang_factor,
+        settings.jump_apex_hang_threshold,
+    );
+    transform.translation = move_kinematic(
+        transform.translation,
         *player_collider,
+        desired_delta,
+        jump_speed,
+        &mut kinematics,
+        gravity,
+        dt,
         &world_collision_grid,
+        debug.show_contact_points.then_some(&mut contacts.points),
+    );
+    let jumped = wall_jumped || (was_grounded && jump_speed.is_some() && !kinematics.grounded);
+    if was_grounded && !jumped {
+        transform.translation = snap_to_ground(
+            transform.translation,
+            *player_collider,
+            &mut kinematics,
+            &world_collision_grid,
+            settings.ground_snap_distance,
+        );
+    }
+    if !was_grounded && kinematics.grounded {
+        let feet = transform.translation - Vec3::Y * player_collider.half_height;
+        let surface = sample_ground_height(
+            &world_collision_grid,
+            feet + Vec3::Y * 0.05,
+            player_collider.radius,
+        )
+        .map_or(SurfaceType::Neutral, |(_, surface)| surface);
+        landings.write(LandedMessage {
+            position: feet,
+            surface,
+            impact_speed: fall_speed.max(0.0),
+        });
+    }
+    track_jump(
+        &mut jump_tracker,
+        jumped,
+        start_position,
+        transform.translation,
+        kinematics.grounded,
+    );
+
+    // Patrolling entities are not in the static grid; keep the player out of them here.
+    for (patrol_transform, patrol_collider) in &patrol_colliders {
+        transform.translation = push_out_of_box_xz(
+            transform.translation,
+            *player_collider,
+            patrol_transform.translation,
+            patrol_collider.half_extents,
+        );
+    }
+}
+
+/// Follows the jump arc from the frame the impulse applies until the player is grounded.
+fn track_jump(
+    tracker: &mut JumpTracker,
+    jumped: bool,
+    start_position: Vec3,
+    position: Vec3,
+    grounded: bool,
+) {
+    if jumped {
+        tracker.start = Some(start_position);
+        tracker.peak_y = start_position.y;
+    }
+    let Some(start) = tracker.start else {
+        return;
+    };
+
+    tracker.peak_y = tracker.peak_y.max(position.y);
+    if grounded {
+        let height = tracker.peak_y - start.y;
+        let distance = Vec2::new(position.x - start.x, position.z - start.z).length();
+        tracker.last_jump = Some((height, distance));
+        tracker.start = None;
+    }
+}
+
+fn dust_color(surface: SurfaceType) -> Color {
+    match surface {
+        SurfaceType::Neutral => Color::srgba(0.62, 0.56, 0.46, 0.55),
+        SurfaceType::Grass => Color::srgba(0.45, 0.42, 0.28, 0.50),
+        SurfaceType::Stone => Color::srgba(0.70, 0.70, 0.68, 0.55),
+        SurfaceType::Wood => Color::srgba(0.66, 0.50, 0.34, 0.50),
+        SurfaceType::Metal => Color::srgba(0.60, 0.62, 0.66, 0.40),
+    }
+}
+
+/// Kicks up a few dust puffs per footstep and a ring of them on hard landings, tinted by
+/// the surface. Mesh and materials are created once and shared by every puff.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn spawn_dust_particles(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    mut footsteps: MessageReader<FootstepMessage>,
+    mut landings: MessageReader<LandedMessage>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut dust_assets: Local<Option<DustAssets>>,
+) {
+    // (position, surface, puff count, outward speed, lifetime)
+    let bursts = footsteps
+        .read()
+        .map(|step| (step.position, step.surface, 3, 0.5, 0.45))
+        .chain(
+            landings
+                .read()
+                .filter(|landing| landing.impact_speed >= LANDING_DUST_MIN_SPEED)
+                .map(|landing| {
+                    let count = (landing.impact_speed * 1.5).clamp(6.0, 14.0) as usize;
+                    let speed = (landing.impact_speed * 0.25).clamp(0.6, 2.0);
+                    (landing.position, landing.surface, count, speed, 0.8)
+                }),
+        )
+        .collect::<Vec<_>>();
+    if !settings.dust_particles || bursts.is_empty() {
+        return;
+    }
+
+    let dust_assets = dust_assets.get_or_insert_with(|| DustAssets {
+        mesh: meshes.add(Cuboid::from_length(1.0)),
+        materials: HashMap::new(),
+    });
+    for (position, surface, count, speed, lifetime) in bursts {
+        let material = dust_assets
+            .materials
+            .entry(surface)
+            .or_insert_with(|| {
+                materials.add(StandardMaterial {
+                    base_color: dust_color(surface),
+                    alpha_mode: AlphaMode::Blend,
+                    unlit: true,
+                    ..default()
+                })
+            })
+            .clone();
+        for _ in 0..count {
+            let angle = rand::random_range(0.0..std::f32::consts::TAU);
+            let outward =
+                Vec3::new(angle.cos(), 0.0, angle.sin()) * speed * rand::random_range(0.6..1.0);
+            let size = rand::random_range(0.06..0.12);
+            commands.spawn((
+                DustParticle {
+                    velocity: outward + Vec3::Y * rand::random_range(0.2..0.6),
+                    age: 0.0,
+                    lifetime,
+                    size,
+                },
+                Mesh3d(dust_assets.mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                Transform::from_translation(position + Vec3::Y * 0.04)
+                    .with_scale(Vec3::splat(size)),
+                NotShadowCaster,
+                NotShadowReceiver,
+                InGameEntity,
+            ));
+        }
+    }
+}
+
+/// Drifts dust puffs outward with drag and shrinks them to nothing before despawning.
+pub(super) fn update_dust_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    sim: Res<SimPaused>,
+    debug: Res<DebugSettings>,
+    mut particles: Query<(Entity, &mut Transform, &mut DustParticle)>,
+) {
+    let dt = sim_delta_secs(&time, &sim, &debug);
+    if dt <= 0.0 {
+        return;
+    }
+
+    let drag = (-dt * 4.0).exp();
+    for (entity, mut transform, mut particle) in &mut particles {
+        particle.age += dt;
+        if particle.age >= particle.lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+        particle.velocity *= drag;
+        transform.translation += particle.velocity * dt;
+        let remaining = 1.0 - particle.age / particle.lifetime;
+        transform.scale = Vec3::splat(particle.size * (0.4 + 0.6 * remaining) * remaining.sqrt());
+    }
+}
+
+/// Counts the steps of a headless run and exits once the budget is spent, logging where the
+/// player ended up.
+pub(super) fn count_headless_steps(
+    flow: Res<GameFlowState>,
+    mut run: ResMut<HeadlessRun>,
+    player_query: Query<&Transform, With<Player>>,
+    mut app_exit: MessageWriter<AppExit>,
+) {
+    if !flow.in_game {
+        return;
+    }
+    run.steps_done += 1;
+    let Some(steps_left) = run.steps_left.as_mut() else {
+        return;
+    };
+    *steps_left = steps_left.saturating_sub(1);
+    if *steps_left > 0 {
+        return;
+    }
+
+    match player_query.single() {
+        Ok(transform) => info!(
+            "Headless run klaar na {} stappen, speler op {:.2}",
+            run.steps_done, transform.translation
+        ),
+        Err(_) => info!("Headless run klaar na {} stappen", run.steps_done),
+    }
+    app_exit.write(AppExit::Success);
+}
+
+pub(super) fn update_jump_readout(
+    debug: Res<DebugSettings>,
+    photo_mode: Res<PhotoModeState>,
+    tracker: Res<JumpTracker>,
+    mut readout_query: Query<(&mut Text, &mut Visibility), With<JumpReadoutText>>,
+) {
+    let Ok((mut readout, mut visibility)) = readout_query.single_mut() else {
+        return;
+    };
+    if !debug.show_jump_readout {
+        visibility.set_if_neq(Visibility::Hidden);
+        return;
+    }
+    // Photo mode hides the readout itself.
+    if photo_mode.active {
+        return;
+    }
+    visibility.set_if_neq(Visibility::Inherited);
+
+    let text = match tracker.last_jump {
+        Some((height, distance)) => {
+            format!("Last jump: height {height:.2} m, distance {distance:.2} m")
+        }
+        None => "Last jump: --".to_string(),
+    };
+    if **readout != text {
+        **readout = text;
+    }
+}
+
+/// Eases the capsule toward `target_half_height` with the feet kept in place. It only grows
+/// while the taller capsule fits, so releasing Crouch under a low ceiling stays crouched.
+fn update_crouch_height(
+    position: &mut Vec3,
+    collider: &mut PlayerCollider,
+    target_half_height: f32,
+    dt: f32,
+    world_collision_grid: &WorldCollisionGrid,
+) {
+    let max_change = CROUCH_HEIGHT_RATE * dt;
+    let change = (target_half_height - collider.half_height).clamp(-max_change, max_change);
+    if change.abs() <= f32::EPSILON {
+        return;
+    }
+
+    let resized = PlayerCollider {
+        half_height: collider.half_height + change,
+        ..*collider
+    };
+    let resized_center = *position + Vec3::Y * change;
+    if change > 0.0
+        && would_collide(
+            resized_center + Vec3::Y * CONTROLLER_SKIN,
+            resized,
+            world_collision_grid,
+        )
+    {
+        return;
+    }
+    *collider = resized;
+    *position = resized_center;
+}
+
+/// Coyote time and jump buffering: a press up to `JUMP_BUFFER_SECS` before landing, or up to
+/// `COYOTE_TIME_SECS` after leaving the ground, still jumps. A coyote jump marks the capsule
+/// grounded again so `move_kinematic` takes it. Charged jumps bypass this and only start
+/// from a press while grounded.
+fn buffered_jump(kinematics: &mut PlayerKinematics, pressed: bool, dt: f32) -> bool {
+    kinematics.coyote_timer = if kinematics.grounded {
+        COYOTE_TIME_SECS
+    } else {
+        (kinematics.coyote_timer - dt).max(0.0)
+    };
+    kinematics.jump_buffer_timer = if pressed {
+        JUMP_BUFFER_SECS
+    } else {
+        (kinematics.jump_buffer_timer - dt).max(0.0)
+    };
+    if kinematics.jump_buffer_timer <= 0.0 || kinematics.coyote_timer <= 0.0 {
+        return false;
+    }
+
+    kinematics.coyote_timer = 0.0;
+    kinematics.jump_buffer_timer = 0.0;
+    kinematics.grounded = true;
+    true
+}
+
+/// Builds charge while Jump is held on the ground and returns the jump speed on release.
+fn charge_jump(
+    keys: &ButtonInput<KeyCode>,
+    mouse_buttons: &ButtonInput<MouseButton>,
+    keybinds: &GameKeybinds,
+    settings: &GameSettings,
+    kinematics: &mut PlayerKinematics,
+    dt: f32,
+) -> Option<f32> {
+    if !kinematics.grounded {
+        kinematics.jump_charge = None;
+        return None;
+    }
+
+    if keybinds.action_just_pressed(keys, mouse_buttons, GameAction::Jump) {
+        kinematics.jump_charge = Some(0.0);
+    }
+    let charge = kinematics.jump_charge?;
+    if keybinds.action_just_released(keys, mouse_buttons, GameAction::Jump) {
+        kinematics.jump_charge = None;
+        let min_speed = settings.charge_jump_min_speed.max(0.0);
+        let max_speed = settings.charge_jump_max_speed.max(min_speed);
+        return Some(min_speed.lerp(max_speed, charge));
+    }
+
+    kinematics.jump_charge = Some((charge + settings.charge_jump_rate.max(0.0) * dt).min(1.0));
+    None
+}
+
+/// Keeps a walking capsule that just lost the ground on it: when the ground under it is at
+/// most `max_drop` below its feet (the next step down a stair), it's placed on that instead
+/// of spending a few frames falling.
+pub(super) fn snap_to_ground(
+    position: Vec3,
+    collider: PlayerCollider,
+    kinematics: &mut PlayerKinematics,
+    grid: &WorldCollisionGrid,
+    max_drop: f32,
+) -> Vec3 {
+    let moving = kinematics.horizontal_velocity.length_squared() > 1e-4;
+    if kinematics.grounded || kinematics.vertical_velocity > 0.0 || !moving || max_drop <= 0.0 {
+        return position;
+    }
+    let feet = position - Vec3::Y * collider.half_height;
+    let Some((top, _)) = sample_ground_height(grid, feet + Vec3::Y * 1e-3, collider.radius) else {
+        return position;
+    };
+    if feet.y - top > max_drop {
+        return position;
+    }
+    kinematics.grounded = true;
+    kinematics.vertical_velocity = 0.0;
+    Vec3::new(position.x, top + collider.half_height, position.z)
+}
+
+/// Gravity for this step: scaled by `hang_factor` while airborne within `threshold` m/s of
+/// zero vertical speed, which stretches the jump apex without raising it much.
+pub(super) fn apex_hang_gravity(
+    gravity: f32,
+    kinematics: &PlayerKinematics,
+    hang_factor: f32,
+    threshold: f32,
+) -> f32 {
+    if !kinematics.grounded && kinematics.vertical_velocity.abs() < threshold {
+        gravity * hang_factor.clamp(0.0, 1.0)
+    } else {
+        gravity
+    }
+}
+
+/// One controller step for a vertical capsule: slide (and step up) along `desired_delta`,
+/// apply `jump_speed` if still grounded, then integrate gravity and resolve landing/ceiling
+/// hits. `kinematics` is updated with the velocity that was actually achieved and with the
+/// wall contact of this step; `contacts`, when given, collects every contact for debugging.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn move_kinematic(
+    position: Vec3,
+    collider: PlayerCollider,
+    desired_delta: Vec3,
+    jump_speed: Option<f32>,
+    kinematics: &mut PlayerKinematics,
+    gravity: f32,
+    dt: f32,
+    grid: &WorldCollisionGrid,
+    mut contacts: Option<&mut Vec<ControllerContact>>,
+) -> Vec3 {
+    let mut next_position = position;
+    let (slid_position, wall_normal) = move_with_slide(
+        next_position,
+        desired_delta,
+        collider,
+        grid,
         CONTROLLER_MAX_SLIDES,
         CONTROLLER_SKIN,
+        contacts.as_deref_mut(),
     );
     next_position.x = slid_position.x;
     next_position.z = slid_position.z;
+    let mut wall_normal = wall_normal;
 
-    if blocked && kinematics.grounded {
-        if let Some(step_position) = try_step_move(
-            transform.translation,
+    if wall_normal.is_some()
+        && kinematics.grounded
+        && let Some((step_position, step_wall_normal)) = try_step_move(
+            position,
             desired_delta,
-            *player_collider,
-            &world_collision_grid,
+            collider,
+            grid,
             CONTROLLER_STEP_HEIGHT,
             CONTROLLER_STEP_DROP,
             CONTROLLER_SKIN,
-        ) {
-            next_position = step_position;
-        }
+            contacts.as_deref_mut(),
+        )
+    {
+        next_position = step_position;
+        wall_normal = step_wall_normal;
     }
 
-    let actual_horizontal_delta = Vec2::new(
-        next_position.x - horizontal_start.x,
-        next_position.z - horizontal_start.z,
-    );
+    kinematics.last_wall_contact = match (kinematics.grounded, wall_normal) {
+        (true, _) => None,
+        (false, Some(normal)) => Some((normal, 0.0)),
+        (false, None) => kinematics
+            .last_wall_contact
+            .map(|(normal, since_contact)| (normal, since_contact + dt)),
+    };
+
+    // Grounded capsules follow a ramp up and down, and step cleanly off its ends.
+    let on_ramp = |position| ground_support(position, collider, grid).is_some_and(|(_, ramp)| ramp);
+    if kinematics.grounded
+        && let Some((top, ramp)) = ground_support(next_position, collider, grid)
+        && (ramp || on_ramp(position))
+    {
+        next_position.y = top + collider.half_height;
+    }
+
+    let actual_horizontal_delta =
+        Vec2::new(next_position.x - position.x, next_position.z - position.z);
     kinematics.horizontal_velocity = actual_horizontal_delta / dt.max(1e-5);
 
-    if keybinds.action_just_pressed(&keys, GameAction::Jump) && kinematics.grounded {
-        kinematics.vertical_velocity = player.jump_speed;
+    if let Some(jump_speed) = jump_speed
+        && kinematics.grounded
+    {
+        kinematics.vertical_velocity = jump_speed;
         kinematics.grounded = false;
     }
 
     let vertical_start = next_position;
-    kinematics.vertical_velocity += player.gravity * dt;
+    kinematics.vertical_velocity += gravity * dt;
     let proposed_vertical = Vec3::new(
         vertical_start.x,
         vertical_start.y + kinematics.vertical_velocity * dt,
@@ -166,45 +800,65 @@ pub(super) fn player_move(
     );
 
     if kinematics.vertical_velocity <= 0.0 {
-        if let Some(landing_top) = find_landing_top(
-            vertical_start,
-            proposed_vertical,
-            *player_collider,
-            &world_collision_grid,
-        ) {
-            next_position.y = landing_top + player_collider.half_height;
+        if let Some((landing_top, normal)) =
+            find_landing_top(vertical_start, proposed_vertical, collider, grid)
+        {
+            next_position.y = landing_top + collider.half_height;
             kinematics.vertical_velocity = 0.0;
-            kinematics.grounded = true;
+            kinematics.grounded = is_walkable(normal);
+            if !kinematics.grounded {
+                // Too steep to stand on: gravity along the face pushes the capsule downhill.
+                kinematics.horizontal_velocity +=
+                    Vec2::new(normal.x, normal.z) * -gravity * normal.y * dt;
+            }
+            if let Some(contacts) = contacts.as_deref_mut() {
+                contacts.push(ControllerContact {
+                    point: Vec3::new(next_position.x, landing_top, next_position.z),
+                    normal,
+                });
+            }
         } else {
             next_position.y = proposed_vertical.y;
             kinematics.grounded = false;
         }
-    } else if let Some(ceiling_bottom) = find_ceiling_bottom(
-        vertical_start,
-        proposed_vertical,
-        *player_collider,
-        &world_collision_grid,
-    ) {
-        next_position.y = ceiling_bottom - player_collider.half_height;
+    } else if let Some(ceiling_bottom) =
+        find_ceiling_bottom(vertical_start, proposed_vertical, collider, grid)
+    {
+        next_position.y = ceiling_bottom - collider.half_height;
         kinematics.vertical_velocity = 0.0;
         kinematics.grounded = false;
+        if let Some(contacts) = contacts {
+            contacts.push(ControllerContact {
+                point: Vec3::new(next_position.x, ceiling_bottom, next_position.z),
+                normal: Vec3::NEG_Y,
+            });
+        }
     } else {
         next_position.y = proposed_vertical.y;
         kinematics.grounded = false;
     }
 
-    transform.translation = next_position;
+    next_position
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub(super) fn animate_procedural_human(
     time: Res<Time>,
+    sim: Res<SimPaused>,
+    debug: Res<DebugSettings>,
     menu: Res<MenuState>,
     settings: Res<GameSettings>,
     mouse_buttons: Res<ButtonInput<MouseButton>>,
     world_collision_grid: Res<WorldCollisionGrid>,
     camera_query: Query<&ThirdPersonCameraRig, With<Camera3d>>,
+    mut footsteps: MessageWriter<FootstepMessage>,
     mut player_query: Query<
-        (&Transform, &PlayerKinematics, &mut ProceduralHumanAnimState),
+        (
+            &Transform,
+            &PlayerCollider,
+            &PlayerKinematics,
+            &mut ProceduralHumanAnimState,
+        ),
         (
             With<Player>,
             Without<ProceduralHumanVisualRoot>,
@@ -286,12 +940,17 @@ pub(super) fn animate_procedural_human(
         ),
     >,
 ) {
-    let Ok((player_transform, player_kinematics, mut anim_state)) = player_query.single_mut()
+    let Ok((player_transform, player_collider, player_kinematics, mut anim_state)) =
+        player_query.single_mut()
     else {
         return;
     };
 
-    let dt = time.delta_secs().max(1e-5);
+    let dt = sim_delta_secs(&time, &sim, &debug);
+    if dt <= 0.0 {
+        return;
+    }
+    anim_state.idle_time += dt;
     let delta = player_transform.translation - anim_state.last_position;
     let measured_speed = Vec2::new(delta.x, delta.z).length() / dt;
     anim_state.last_position = player_transform.translation;
@@ -304,7 +963,11 @@ pub(super) fn animate_procedural_human(
     let vertical_follow = 1.0 - (-dt * vertical_follow_rate).exp();
     anim_state.visual_center_y += (target_visual_y - anim_state.visual_center_y) * vertical_follow;
 
-    let target_speed = if menu.open { 0.0 } else { measured_speed };
+    let target_speed = if menu.pauses_game(&settings) {
+        0.0
+    } else {
+        measured_speed
+    };
     let smooth = 1.0 - (-dt * 10.0).exp();
     anim_state.smoothed_speed += (target_speed - anim_state.smoothed_speed) * smooth;
     let speed_factor = (anim_state.smoothed_speed / 8.0).clamp(0.0, 1.0);
@@ -328,14 +991,36 @@ pub(super) fn animate_procedural_human(
         anim_state.phase -= std::f32::consts::TAU;
     }
 
-    let stride_bob = (anim_state.phase * 2.0).sin() * (0.01 + 0.045 * speed_factor);
-    let idle_bob = (time.elapsed_secs() * 1.5).sin() * (0.006 * (1.0 - speed_factor));
+    let (fidget_head_yaw, fidget_sway) = update_idle_fidget(
+        &mut anim_state,
+        dt,
+        speed_factor < 0.05 && player_kinematics.grounded,
+    );
+
+    let (stride_bob, idle_bob) = if settings.reduce_motion {
+        (0.0, 0.0)
+    } else {
+        (
+            (anim_state.phase * 2.0).sin() * (0.01 + 0.045 * speed_factor) * settings.bob_intensity,
+            (anim_state.idle_time * 1.5).sin()
+                * (0.006 * (1.0 - speed_factor))
+                * settings.bob_intensity,
+        )
+    };
     let strafe_lean = -0.08 * strafe_sign * strafe_factor;
-    let lean_roll = (anim_state.phase).sin() * 0.06 * speed_factor + strafe_lean;
+    let lean_roll = (anim_state.phase).sin() * 0.06 * speed_factor + strafe_lean + fidget_sway;
     let jump_body_pitch = -0.10 * jump_up + 0.14 * jump_down + 0.12 * landing_ready;
     let jump_body_offset = 0.05 * jump_up - 0.02 * jump_down - 0.02 * landing_ready;
-    let mut root_local_translation =
-        Vec3::new(0.0, -0.9 + stride_bob + idle_bob + jump_body_offset, 0.0);
+    // A crouched capsule is shorter than the body; sink the root by the lost height so the
+    // head stays inside it, and let the leg IK fold the knees to keep the feet planted. The
+    // ground probes drop with it so they start below any ceiling the capsule fits under.
+    let crouch_drop = 2.0
+        * (settings.body_preset.proportions().half_height() - player_collider.half_height).max(0.0);
+    let mut root_local_translation = Vec3::new(
+        fidget_sway * 0.6,
+        -player_collider.half_height - crouch_drop + stride_bob + idle_bob + jump_body_offset,
+        0.0,
+    );
     let root_local_rotation = Quat::from_rotation_y(std::f32::consts::PI)
         * Quat::from_rotation_z(lean_roll)
         * Quat::from_rotation_x(jump_body_pitch);
@@ -348,13 +1033,22 @@ pub(super) fn animate_procedural_human(
     let mut root_world_translation =
         visual_player_translation + player_transform.rotation * root_local_translation;
 
-    let mut head_yaw_target = 0.0;
+    let mut head_yaw_target = fidget_head_yaw;
     let mut head_pitch_target = 0.0;
-    if mouse_buttons.pressed(MouseButton::Left) {
-        if let Ok(camera_rig) = camera_query.single() {
-            let (player_yaw, _, _) = player_transform.rotation.to_euler(EulerRot::YXZ);
-            head_yaw_target = shortest_angle_delta(player_yaw, camera_rig.yaw);
+    if let Ok(camera_rig) = camera_query.single() {
+        let (player_yaw, _, _) = player_transform.rotation.to_euler(EulerRot::YXZ);
+        let look_yaw = shortest_angle_delta(player_yaw, camera_rig.yaw);
+        if mouse_buttons.pressed(MouseButton::Left) {
+            head_yaw_target = look_yaw;
             head_pitch_target = -camera_rig.pitch;
+        } else if settings.head_always_tracks_camera {
+            // Subtler than the LMB look, and fading out as the camera swings round to the
+            // front so the head doesn't stay cranked over a shoulder.
+            let quarter = std::f32::consts::FRAC_PI_2;
+            let behind = ((look_yaw.abs() - quarter) / quarter).clamp(0.0, 1.0);
+            let weight = HEAD_TRACK_ALWAYS_WEIGHT * (1.0 - smoothstep01(behind));
+            head_yaw_target = fidget_head_yaw + look_yaw * weight;
+            head_pitch_target = -camera_rig.pitch * weight;
         }
     }
 
@@ -386,6 +1080,7 @@ pub(super) fn animate_procedural_human(
 
     // If one foot is supported lower (edge of stairs), lower pelvis so stance feet can reach.
     let mut pelvis_drop = 0.0_f32;
+    let mut out_of_reach = [false; 2];
     for _ in 0..2 {
         let test_root = root_world_translation - Vec3::Y * pelvis_drop;
         let mut required_drop = 0.0_f32;
@@ -397,49 +1092,51 @@ pub(super) fn animate_procedural_human(
             let nominal_local = leg_base_local
                 + Vec3::new(
                     0.0,
-                    -(hip.upper_len + hip.lower_len) + lift * (0.10 + 0.08 * gait) + jump_leg_tuck,
+                    -(hip.upper_len + hip.lower_len)
+                        + lift * (0.10 + 0.08 * gait)
+                        + jump_leg_tuck
+                        + crouch_drop,
                     stride * leg_stride_scale + jump_leg_forward,
                 );
             let mut ankle_target_world = test_root + root_world_rotation * nominal_local;
             let nominal_ankle_y = ankle_target_world.y;
             let probe = Vec3::new(
                 ankle_target_world.x,
-                test_root.y + 2.0,
+                test_root.y + 2.0 - crouch_drop,
                 ankle_target_world.z,
             );
 
-            if ground_ik_weight > 0.01 {
-                if let Some(ground_y) = sample_ground_height(&world_collision_grid, probe, 0.12) {
-                    let planted_y = ground_y + hip.ankle_height;
-                    let support_delta = planted_y - nominal_ankle_y;
-                    if support_delta < -foot_support_max_drop
-                        || support_delta > foot_support_max_rise
-                    {
-                        continue;
-                    }
-                    let stance = 1.0 - lift;
-                    let plant_strength =
-                        ((0.82 + (1.0 - gait) * 0.16) * ground_ik_weight).clamp(0.0, 0.98);
-                    ankle_target_world.y = ankle_target_world.y.max(planted_y);
-                    ankle_target_world.y = ankle_target_world.y * (1.0 - stance * plant_strength)
-                        + planted_y * (stance * plant_strength);
-
-                    let target_local =
-                        root_world_rotation.inverse() * (ankle_target_world - test_root);
-                    let to_target = target_local - leg_base_local;
-                    let dy = to_target.y;
-                    let dz = to_target.z;
-                    let leg_total = hip.upper_len + hip.lower_len;
-                    let max_reach = (leg_total - 0.015).max(0.05);
-                    if dz.abs() >= max_reach {
-                        continue;
-                    }
-
-                    let reachable_dy = -(max_reach * max_reach - dz * dz).sqrt();
-                    let needed = (reachable_dy - dy).max(0.0);
-                    required_drop =
-                        required_drop.max(needed * (1.0 - 0.35 * swing.abs()) * ground_ik_weight);
+            if ground_ik_weight > 0.01
+                && let Some((ground_y, _)) =
+                    sample_ground_height(&world_collision_grid, probe, 0.12)
+            {
+                let planted_y = ground_y + hip.ankle_height;
+                let support_delta = planted_y - nominal_ankle_y;
+                if support_delta < -foot_support_max_drop || support_delta > foot_support_max_rise {
+                    continue;
                 }
+                let stance = 1.0 - lift;
+                let plant_strength =
+                    ((0.82 + (1.0 - gait) * 0.16) * ground_ik_weight).clamp(0.0, 0.98);
+                ankle_target_world.y = ankle_target_world.y.max(planted_y);
+                ankle_target_world.y = ankle_target_world.y * (1.0 - stance * plant_strength)
+                    + planted_y * (stance * plant_strength);
+
+                let target_local = root_world_rotation.inverse() * (ankle_target_world - test_root);
+                let to_target = target_local - leg_base_local;
+                let dy = to_target.y;
+                let dz = to_target.z;
+                let leg_total = hip.upper_len + hip.lower_len;
+                let max_reach = (leg_total - 0.015).max(0.05);
+                if dz.abs() >= max_reach {
+                    out_of_reach[hip.side as usize] = true;
+                    continue;
+                }
+
+                let reachable_dy = -(max_reach * max_reach - dz * dz).sqrt();
+                let needed = (reachable_dy - dy).max(0.0);
+                required_drop =
+                    required_drop.max(needed * (1.0 - 0.35 * swing.abs()) * ground_ik_weight);
             }
         }
 
@@ -468,7 +1165,10 @@ pub(super) fn animate_procedural_human(
         let nominal_local = leg_base_local
             + Vec3::new(
                 0.0,
-                -(hip.upper_len + hip.lower_len) + lift * (0.10 + 0.08 * gait) + jump_leg_tuck,
+                -(hip.upper_len + hip.lower_len)
+                    + lift * (0.10 + 0.08 * gait)
+                    + jump_leg_tuck
+                    + crouch_drop,
                 stride * leg_stride_scale + jump_leg_forward,
             );
         let mut ankle_target_world = root_world_translation + root_world_rotation * nominal_local;
@@ -476,22 +1176,37 @@ pub(super) fn animate_procedural_human(
 
         let probe = Vec3::new(
             ankle_target_world.x,
-            root_world_translation.y + 2.0,
+            root_world_translation.y + 2.0 - crouch_drop,
             ankle_target_world.z,
         );
-        if ground_ik_weight > 0.01 {
-            if let Some(ground_y) = sample_ground_height(&world_collision_grid, probe, 0.12) {
-                let planted_y = ground_y + hip.ankle_height;
-                let support_delta = planted_y - nominal_ankle_y;
-                if support_delta >= -foot_support_max_drop && support_delta <= foot_support_max_rise
+        if ground_ik_weight > 0.01
+            && let Some((ground_y, surface)) =
+                sample_ground_height(&world_collision_grid, probe, 0.12)
+        {
+            let planted_y = ground_y + hip.ankle_height;
+            let support_delta = planted_y - nominal_ankle_y;
+            if support_delta >= -foot_support_max_drop && support_delta <= foot_support_max_rise {
+                let stance = 1.0 - lift;
+                let planted = stance > 0.5;
+                if planted {
+                    anim_state.foot_surfaces[hip.side as usize] = surface;
+                }
+                if planted
+                    && !anim_state.feet_planted[hip.side as usize]
+                    && player_kinematics.grounded
+                    && gait > FOOTSTEP_MIN_GAIT
                 {
-                    let stance = 1.0 - lift;
-                    let plant_strength =
-                        ((0.82 + (1.0 - gait) * 0.16) * ground_ik_weight).clamp(0.0, 0.98);
-                    ankle_target_world.y = ankle_target_world.y.max(planted_y);
-                    ankle_target_world.y = ankle_target_world.y * (1.0 - stance * plant_strength)
-                        + planted_y * (stance * plant_strength);
+                    footsteps.write(FootstepMessage {
+                        position: Vec3::new(ankle_target_world.x, ground_y, ankle_target_world.z),
+                        surface,
+                    });
                 }
+                anim_state.feet_planted[hip.side as usize] = planted;
+                let plant_strength =
+                    ((0.82 + (1.0 - gait) * 0.16) * ground_ik_weight).clamp(0.0, 0.98);
+                ankle_target_world.y = ankle_target_world.y.max(planted_y);
+                ankle_target_world.y = ankle_target_world.y * (1.0 - stance * plant_strength)
+                    + planted_y * (stance * plant_strength);
             }
         }
 
@@ -502,7 +1217,21 @@ pub(super) fn animate_procedural_human(
         let dz = to_target.z;
 
         let leg_total = hip.upper_len + hip.lower_len;
-        let dist = (dy * dy + dz * dz).sqrt().clamp(0.05, leg_total - 0.001);
+        let target_dist = (dy * dy + dz * dz).sqrt();
+        let dist = target_dist.clamp(0.05, leg_total - 0.001);
+        if debug.show_animation_debug {
+            let foot = &mut anim_state.foot_ik[hip.side as usize];
+            foot.attempted = ankle_target_world;
+            foot.achieved = root_world_translation
+                + root_world_rotation
+                    * (leg_base_local + Vec3::new(0.0, dy, dz).normalize_or_zero() * dist);
+            foot.reachable = !out_of_reach[hip.side as usize] && target_dist <= dist;
+            if !foot.reachable {
+                foot.unreachable_frames += 1;
+            }
+        } else {
+            anim_state.foot_ik[hip.side as usize] = FootIkDebug::default();
+        }
         let base_angle = dz.atan2(-dy);
         let cos_hip = ((hip.upper_len * hip.upper_len + dist * dist
             - hip.lower_len * hip.lower_len)
@@ -538,7 +1267,7 @@ pub(super) fn animate_procedural_human(
             -1.0
         };
         let swing = (anim_state.phase + side_phase).sin();
-        let idle = (time.elapsed_secs() * 1.8 + side_phase).sin() * 0.07 * (1.0 - speed_factor);
+        let idle = (anim_state.idle_time * 1.8 + side_phase).sin() * 0.07 * (1.0 - speed_factor);
         let pitch = swing * (0.15 + 0.72 * speed_factor) + idle + jump_arm_pitch;
         let forearm_ratio = (pivot.lower_len / pivot.upper_len.max(0.05)).clamp(0.7, 1.3);
         let elbow_bend = (0.35 + 0.30 * speed_factor + 0.20 * (1.0 - swing.abs())) * forearm_ratio;
@@ -567,6 +1296,65 @@ pub(super) fn animate_procedural_human(
     }
 }
 
+/// Advances the idle fidget timer and returns the (head yaw, body sway) offsets of the
+/// gesture in progress. Any movement cancels the gesture and restarts the wait.
+fn update_idle_fidget(
+    anim_state: &mut ProceduralHumanAnimState,
+    dt: f32,
+    still: bool,
+) -> (f32, f32) {
+    if !still {
+        if anim_state.fidget.take().is_some()
+            || anim_state.fidget_wait_secs < IDLE_FIDGET_INTERVAL_SECS.start
+        {
+            anim_state.fidget_wait_secs = rand::random_range(IDLE_FIDGET_INTERVAL_SECS);
+        }
+        return (0.0, 0.0);
+    }
+
+    if anim_state.fidget.is_none() {
+        anim_state.fidget_wait_secs -= dt;
+    }
+    if anim_state.fidget.is_none() && anim_state.fidget_wait_secs <= 0.0 {
+        anim_state.fidget = Some(IdleFidget {
+            kind: if rand::random::<bool>() {
+                IdleFidgetKind::LookAround
+            } else {
+                IdleFidgetKind::WeightShift
+            },
+            side: if rand::random::<bool>() { 1.0 } else { -1.0 },
+            elapsed: 0.0,
+        });
+        anim_state.fidget_wait_secs = rand::random_range(IDLE_FIDGET_INTERVAL_SECS);
+    }
+
+    let Some(fidget) = anim_state.fidget.as_mut() else {
+        return (0.0, 0.0);
+    };
+    fidget.elapsed += dt;
+    if fidget.elapsed >= IDLE_FIDGET_DURATION_SECS {
+        anim_state.fidget = None;
+        return (0.0, 0.0);
+    }
+
+    let envelope = (fidget.elapsed / IDLE_FIDGET_DURATION_SECS * std::f32::consts::PI).sin();
+    match fidget.kind {
+        IdleFidgetKind::LookAround => (0.55 * fidget.side * envelope, 0.0),
+        IdleFidgetKind::WeightShift => (0.0, 0.05 * fidget.side * envelope),
+    }
+}
+
+/// Collision-shape gizmo colour per surface, so tagging mistakes are visible.
+fn surface_debug_color(surface: SurfaceType) -> Color {
+    match surface {
+        SurfaceType::Neutral => Color::srgba(1.0, 0.9, 0.35, 0.95),
+        SurfaceType::Grass => Color::srgba(0.45, 0.95, 0.35, 0.95),
+        SurfaceType::Stone => Color::srgba(0.75, 0.78, 0.82, 0.95),
+        SurfaceType::Wood => Color::srgba(0.95, 0.6, 0.3, 0.95),
+        SurfaceType::Metal => Color::srgba(0.45, 0.75, 1.0, 0.95),
+    }
+}
+
 fn shortest_angle_delta(from: f32, to: f32) -> f32 {
     let mut delta =
         (to - from + std::f32::consts::PI).rem_euclid(std::f32::consts::TAU) - std::f32::consts::PI;
@@ -576,6 +1364,11 @@ fn shortest_angle_delta(from: f32, to: f32) -> f32 {
     delta
 }
 
+/// Wraps `angle` into (-PI, PI] so accumulated angles keep their float precision.
+fn wrap_angle(angle: f32) -> f32 {
+    shortest_angle_delta(0.0, angle)
+}
+
 fn smoothstep01(t: f32) -> f32 {
     t * t * (3.0 - 2.0 * t)
 }
@@ -592,12 +1385,13 @@ fn leg_motion(phase: f32, side: LimbSide, gait: f32) -> (f32, f32, f32) {
     (swing, lift, stride)
 }
 
+/// Highest collider top under `probe_world` (within `foot_radius`) and its surface.
 fn sample_ground_height(
     grid: &WorldCollisionGrid,
     probe_world: Vec3,
     foot_radius: f32,
-) -> Option<f32> {
-    let mut best_top: Option<f32> = None;
+) -> Option<(f32, SurfaceType)> {
+    let mut best_top: Option<(f32, SurfaceType)> = None;
     grid.query_nearby(probe_world, foot_radius + 0.2, |collider| {
         if !intersects_disc_aabb_xz(
             probe_world,
@@ -608,14 +1402,16 @@ fn sample_ground_height(
             return;
         }
 
-        let top = collider.center.y + collider.half_extents.y;
-        if top <= probe_world.y {
-            best_top = Some(best_top.map_or(top, |current| current.max(top)));
+        let top = collider.top_at(probe_world.x, probe_world.z);
+        if top <= probe_world.y && best_top.is_none_or(|(current, _)| top > current) {
+            best_top = Some((top, collider.surface));
         }
     });
     best_top
 }
 
+/// Slides a disc along `displacement`, returning the end position and the normal of the
+/// last wall it slid along.
 fn move_with_slide(
     start: Vec3,
     displacement: Vec3,
@@ -623,10 +1419,11 @@ fn move_with_slide(
     grid: &WorldCollisionGrid,
     max_iterations: usize,
     skin: f32,
-) -> (Vec3, bool) {
+    mut contacts: Option<&mut Vec<ControllerContact>>,
+) -> (Vec3, Option<Vec2>) {
     let mut position = start;
     let mut remaining = Vec2::new(displacement.x, displacement.z);
-    let mut blocked = false;
+    let mut contact_normal = None;
 
     for _ in 0..max_iterations {
         let remaining_len = remaining.length();
@@ -640,7 +1437,18 @@ fn move_with_slide(
 
         grid.query_nearby(position, query_radius, |static_collider| {
             let feet_y = position.y - collider.half_height;
-            let collider_top = static_collider.center.y + static_collider.half_extents.y;
+            let collider_top = static_collider.top_at(position.x, position.z);
+
+            // A face too steep to walk on holds the capsule back like a wall facing downhill.
+            if let Some(downhill) = steep_downhill(&static_collider)
+                && footprint_contains(&static_collider, position)
+                && feet_y < collider_top + skin
+                && remaining.dot(downhill) < 0.0
+            {
+                best_hit_t = 0.0;
+                best_normal = downhill;
+                return;
+            }
 
             // Treat current support surfaces as floor, not as side blockers.
             // Without this, walking off an edge can get stuck on the same step's "wall".
@@ -667,11 +1475,10 @@ fn move_with_slide(
                     static_collider.half_extents.x,
                     static_collider.half_extents.z,
                 ),
-            ) {
-                if toi < best_hit_t {
-                    best_hit_t = toi;
-                    best_normal = normal;
-                }
+            ) && toi < best_hit_t
+            {
+                best_hit_t = toi;
+                best_normal = normal;
             }
         });
 
@@ -681,10 +1488,17 @@ fn move_with_slide(
             break;
         }
 
-        blocked = true;
+        contact_normal = Some(best_normal);
         let move_t = (best_hit_t - 0.001).clamp(0.0, 1.0);
         position.x += remaining.x * move_t;
         position.z += remaining.y * move_t;
+        if let Some(contacts) = contacts.as_deref_mut() {
+            let normal = Vec3::new(best_normal.x, 0.0, best_normal.y);
+            contacts.push(ControllerContact {
+                point: position - normal * collider.radius,
+                normal,
+            });
+        }
 
         let mut leftover = remaining * (1.0 - best_hit_t.clamp(0.0, 1.0));
         let into_wall = leftover.dot(best_normal);
@@ -694,9 +1508,12 @@ fn move_with_slide(
         remaining = leftover;
     }
 
-    (position, blocked)
+    (position, contact_normal)
 }
 
+/// Retries a blocked move raised by `step_height` and snaps down onto the step, returning
+/// the snapped position and the wall normal of the raised slide.
+#[allow(clippy::too_many_arguments)]
 fn try_step_move(
     start: Vec3,
     displacement: Vec3,
@@ -705,7 +1522,8 @@ fn try_step_move(
     step_height: f32,
     max_drop: f32,
     skin: f32,
-) -> Option<Vec3> {
+    mut contacts: Option<&mut Vec<ControllerContact>>,
+) -> Option<(Vec3, Option<Vec2>)> {
     let horizontal_delta = Vec2::new(displacement.x, displacement.z);
     if horizontal_delta.length_squared() < 1e-6 {
         return None;
@@ -716,13 +1534,14 @@ fn try_step_move(
         return None;
     }
 
-    let (raised_moved, _) = move_with_slide(
+    let (raised_moved, wall_normal) = move_with_slide(
         raised,
         displacement,
         collider,
         grid,
         CONTROLLER_MAX_SLIDES,
         skin,
+        contacts.as_deref_mut(),
     );
 
     let moved_dist = Vec2::new(raised_moved.x - start.x, raised_moved.z - start.z).length();
@@ -743,7 +1562,10 @@ fn try_step_move(
             return;
         }
 
-        let top = static_collider.center.y + static_collider.half_extents.y;
+        if steep_downhill(&static_collider).is_some() {
+            return;
+        }
+        let top = static_collider.top_at(raised_moved.x, raised_moved.z);
         let center_after_snap = top + collider.half_height;
         let drop = raised_moved.y - center_after_snap;
         if drop < -skin || drop > step_height + max_drop {
@@ -764,7 +1586,13 @@ fn try_step_move(
         return None;
     }
 
-    Some(snapped)
+    if let Some(contacts) = contacts {
+        contacts.push(ControllerContact {
+            point: Vec3::new(snapped.x, top, snapped.z),
+            normal: Vec3::Y,
+        });
+    }
+    Some((snapped, wall_normal))
 }
 
 fn capsule_overlaps_aabb_vertically(
@@ -869,82 +1697,527 @@ fn sweep_disc_against_aabb_xz(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(super) fn third_person_camera(
-    mouse_motion: Res<AccumulatedMouseMotion>,
-    mouse_scroll: Res<AccumulatedMouseScroll>,
+    time: Res<Time>,
+    keys: Res<ButtonInput<KeyCode>>,
+    keybinds: Res<GameKeybinds>,
+    mouse_motion: Res<AccumulatedMouseMotion>,
+    mouse_scroll: Res<AccumulatedMouseScroll>,
     mouse_buttons: Res<ButtonInput<MouseButton>>,
     menu: Res<MenuState>,
-    world_collision_grid: Res<WorldCollisionGrid>,
-    player_query: Query<&Transform, (With<Player>, Without<Camera3d>)>,
-    mut camera_query: Query<(&mut Transform, &mut ThirdPersonCameraRig), With<Camera3d>>,
+    mut settings: ResMut<GameSettings>,
+    mouse_capture_state: Res<MouseLookCaptureState>,
+    photo_mode: Res<PhotoModeState>,
+    world_collision_grid: Option<Res<WorldCollisionGrid>>,
+    player_query: Query<(&Transform, &Player, &PlayerKinematics), Without<Camera3d>>,
+    mut camera_query: Query<
+        (&mut Transform, &mut Projection, &mut ThirdPersonCameraRig),
+        With<Camera3d>,
+    >,
 ) {
-    if menu.open {
+    if menu.pauses_game(&settings) || photo_mode.active {
         return;
     }
 
-    let Ok(player_transform) = player_query.single() else {
+    let Ok((player_transform, player, kinematics)) = player_query.single() else {
         return;
     };
 
-    let Ok((mut camera_transform, mut rig)) = camera_query.single_mut() else {
+    let Ok((mut camera_transform, mut projection, mut rig)) = camera_query.single_mut() else {
         return;
     };
-
-    let orbit_pressed =
-        mouse_buttons.pressed(MouseButton::Left) || mouse_buttons.pressed(MouseButton::Right);
-    if orbit_pressed {
+    let world_collision_grid = world_collision_grid.as_deref();
+
+    // Under a practice-mode menu the camera keeps following but takes no input.
+    let input_enabled = !menu.open;
+    let orbit_pressed = input_enabled
+        && ((settings.always_capture_mouse && mouse_capture_state.active)
+            || mouse_buttons.pressed(MouseButton::Left)
+            || mouse_buttons.pressed(MouseButton::Right)
+            || kinematics.aiming);
+    if orbit_pressed && mouse_capture_state.transition_secs <= 0.0 {
         let mouse_delta = mouse_motion.delta;
-        rig.yaw -= mouse_delta.x * rig.look_sensitivity;
-        rig.pitch -= mouse_delta.y * rig.look_sensitivity;
+        if mouse_delta != Vec2::ZERO {
+            rig.recentering = false;
+        }
+        rig.yaw = wrap_angle(rig.yaw - mouse_delta.x * rig.look_sensitivity);
+        let pitch_sign = if settings.invert_y { -1.0 } else { 1.0 };
+        rig.pitch -= pitch_sign * mouse_delta.y * rig.look_sensitivity;
         rig.pitch = rig.pitch.clamp(-1.2, 0.6);
     }
-    rig.distance = (rig.distance - mouse_scroll.delta.y * rig.zoom_sensitivity)
-        .clamp(rig.min_distance, rig.max_distance);
+    // A trackpad gesture usually carries both axes; only its dominant one counts, so a
+    // swipe doesn't also zoom and a zoom doesn't drift the yaw.
+    let scroll = if !input_enabled {
+        Vec2::ZERO
+    } else if settings.horizontal_scroll_orbit {
+        if mouse_scroll.delta.x.abs() > mouse_scroll.delta.y.abs() {
+            Vec2::new(mouse_scroll.delta.x, 0.0)
+        } else {
+            Vec2::new(0.0, mouse_scroll.delta.y)
+        }
+    } else {
+        Vec2::new(0.0, mouse_scroll.delta.y)
+    };
+    if scroll.x != 0.0 {
+        let lines = match mouse_scroll.unit {
+            MouseScrollUnit::Line => scroll.x,
+            MouseScrollUnit::Pixel => scroll.x / SCROLL_PIXELS_PER_LINE,
+        };
+        rig.yaw = wrap_angle(rig.yaw - lines * SCROLL_ORBIT_RADIANS_PER_LINE);
+        rig.recentering = false;
+    }
+    let (min_distance, max_distance) = (rig.min_distance, rig.max_distance);
+    let zoom_step = scroll.y * rig.zoom_sensitivity;
+    let mode_distance = if kinematics.aiming {
+        &mut rig.aim_distance
+    } else {
+        &mut rig.orbit_distance
+    };
+    *mode_distance = (*mode_distance - zoom_step).clamp(min_distance, max_distance);
+    let mode_distance = *mode_distance;
+
+    let dt = time.delta_secs();
+    if input_enabled
+        && keybinds.action_just_pressed(&keys, &mouse_buttons, GameAction::RecenterCamera)
+    {
+        rig.recentering = true;
+    }
+    if rig.recentering {
+        let (facing_yaw, _, _) = player_transform.rotation.to_euler(EulerRot::YXZ);
+        let default_pitch = ThirdPersonCameraRig::default().pitch;
+        let yaw_delta = shortest_angle_delta(rig.yaw, facing_yaw);
+        if yaw_delta.abs() < 1e-3 && (rig.pitch - default_pitch).abs() < 1e-3 {
+            rig.yaw = wrap_angle(facing_yaw);
+            rig.pitch = default_pitch;
+            rig.recentering = false;
+        } else {
+            let blend = 1.0 - (-CAMERA_RECENTER_RATE * dt).exp();
+            rig.yaw = wrap_angle(rig.yaw + yaw_delta * blend);
+            rig.pitch = rig.pitch.lerp(default_pitch, blend);
+        }
+    }
+    rig.distance = rig
+        .distance
+        .lerp(mode_distance, 1.0 - (-CAMERA_ZOOM_BLEND_RATE * dt).exp());
+    // Store once the wheel stops, so a scroll burst doesn't save the config every frame.
+    if zoom_step == 0.0
+        && (settings.camera_orbit_distance != rig.orbit_distance
+            || settings.camera_aim_distance != rig.aim_distance)
+    {
+        settings.camera_orbit_distance = rig.orbit_distance;
+        settings.camera_aim_distance = rig.aim_distance;
+    }
+    // Reduce motion follows rigidly and eases sprint FOV and sideways roll out entirely.
+    let motion = if settings.reduce_motion { 0.0 } else { 1.0 };
+    let target = if settings.camera_follow_lag_secs > 0.0 && motion > 0.0 {
+        let blend = 1.0 - (-dt / settings.camera_follow_lag_secs).exp();
+        rig.follow_target
+            .map_or(player_transform.translation, |lagged| {
+                lagged.lerp(player_transform.translation, blend)
+            })
+    } else {
+        player_transform.translation
+    };
+    rig.follow_target = Some(target);
+
+    let sprint_factor = ((kinematics.horizontal_velocity.length() - player.walk_speed)
+        / (player.sprint_speed - player.walk_speed).max(1e-3))
+    .clamp(0.0, 1.0);
+    let camera_right = Vec2::new(rig.yaw.cos(), -rig.yaw.sin());
+    let sideways = (kinematics.horizontal_velocity.dot(camera_right)
+        / player.sprint_speed.max(1e-3))
+    .clamp(-1.0, 1.0);
+    let feel_blend = 1.0 - (-dt * CAMERA_FEEL_RESPONSE).exp();
+    let target_fov_boost = settings.sprint_fov_boost_degrees.to_radians() * sprint_factor * motion;
+    let target_tilt = -settings.camera_tilt_degrees.to_radians() * sideways * motion;
+    rig.fov_boost += (target_fov_boost - rig.fov_boost) * feel_blend;
+    rig.tilt += (target_tilt - rig.tilt) * feel_blend;
+    if let Projection::Perspective(perspective) = &mut *projection {
+        perspective.fov = settings.fov_radians() + rig.fov_boost;
+    }
+
+    rig.focus_height = settings
+        .camera_focus_height
+        .clamp(CAMERA_FOCUS_HEIGHT_RANGE.0, CAMERA_FOCUS_HEIGHT_RANGE.1);
+    rig.height = settings
+        .camera_height
+        .clamp(CAMERA_HEIGHT_RANGE.0, CAMERA_HEIGHT_RANGE.1);
+    if input_enabled
+        && keybinds.action_just_pressed(&keys, &mouse_buttons, GameAction::SwapShoulder)
+    {
+        rig.shoulder_side = -rig.shoulder_side;
+    }
+    let target_shoulder_offset = settings.camera_shoulder_offset.max(0.0) * rig.shoulder_side;
+    rig.shoulder_offset += (target_shoulder_offset - rig.shoulder_offset) * feel_blend;
 
-    let target = player_transform.translation;
-    let look_target = target + Vec3::Y * rig.focus_height;
     let rotation = Quat::from_euler(EulerRot::YXZ, rig.yaw, rig.pitch, 0.0);
+    // Shift the pivot sideways first, stopping short of walls, so the orbit collision
+    // below starts from a point that is known to be free.
+    let pivot = target + Vec3::Y * rig.focus_height;
+    let shoulder = Quat::from_rotation_y(rig.yaw) * Vec3::X * rig.shoulder_offset;
+    let shoulder_hit = world_collision_grid.map_or(1.0, |grid| {
+        camera_hit_fraction(pivot, pivot + shoulder, grid)
+    });
+    let shoulder_shift = if shoulder_hit < 1.0 {
+        shoulder.normalize_or_zero()
+            * (shoulder.length() * shoulder_hit - CAMERA_COLLISION_SKIN).max(0.0)
+    } else {
+        shoulder
+    };
+    let look_target = pivot + shoulder_shift;
     let orbit_offset = rotation * Vec3::new(0.0, 0.0, rig.distance);
-    let desired_position = target + orbit_offset + Vec3::Y * rig.height;
-
-    camera_transform.translation =
-        resolve_camera_collision(look_target, desired_position, &world_collision_grid);
+    let mut desired_position = target + orbit_offset + Vec3::Y * rig.height + shoulder_shift;
+
+    // Rise quickly over ground coming up under the camera and settle back slowly, so
+    // passing over a crate edge doesn't bob the view.
+    let target_lift = match world_collision_grid {
+        Some(grid) if settings.camera_follow_ground => sample_ground_height(
+            grid,
+            desired_position + Vec3::Y * CAMERA_GROUND_PROBE_ABOVE,
+            CAMERA_COLLISION_RADIUS,
+        )
+        .map_or(0.0, |(ground_y, _)| {
+            (ground_y + CAMERA_GROUND_CLEARANCE - desired_position.y).max(0.0)
+        }),
+        _ => 0.0,
+    };
+    let lift_rate = if target_lift > rig.ground_lift {
+        CAMERA_GROUND_RISE_RATE
+    } else {
+        CAMERA_GROUND_FALL_RATE
+    };
+    rig.ground_lift += (target_lift - rig.ground_lift) * (1.0 - (-dt * lift_rate).exp());
+    desired_position.y += rig.ground_lift;
+
+    // Pull in at once so the view never clips, then ease back out as the way clears.
+    let segment = desired_position - look_target;
+    let full_distance = segment.length();
+    let clear_distance = world_collision_grid.map_or(full_distance, |grid| {
+        camera_clear_distance(look_target, desired_position, grid)
+    });
+    rig.collision_distance =
+        eased_collision_distance(rig.collision_distance, clear_distance, full_distance, dt);
+    camera_transform.translation = if rig.collision_distance < full_distance {
+        look_target + segment / full_distance * rig.collision_distance
+    } else {
+        desired_position
+    };
     camera_transform.look_at(look_target, Vec3::Y);
+    camera_transform.rotate_local_z(rig.tilt);
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub(super) fn toggle_photo_mode(
+    mut commands: Commands,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    keybinds: Res<GameKeybinds>,
+    flow: Res<GameFlowState>,
+    menu: Res<MenuState>,
+    mut photo_mode: ResMut<PhotoModeState>,
+    mut camera_query: Query<(&mut Transform, &mut Projection), With<Camera3d>>,
+    mut ui_roots: Query<
+        (Entity, &mut Visibility),
+        (With<Node>, Without<ChildOf>, Without<RenderScaleOutput>),
+    >,
+) {
+    let wants_exit = photo_mode.active
+        && (keys.just_pressed(KeyCode::Escape)
+            || keybinds.action_just_pressed(&keys, &mouse_buttons, GameAction::PhotoMode)
+            || !flow.in_game
+            || menu.open);
+    let wants_enter = !photo_mode.active
+        && flow.in_game
+        && !menu.open
+        && keybinds.action_just_pressed(&keys, &mouse_buttons, GameAction::PhotoMode);
+
+    if wants_enter {
+        let Ok((transform, projection)) = camera_query.single() else {
+            return;
+        };
+        photo_mode.aim_from(transform, projection);
+        photo_mode.saved_camera = Some((*transform, projection.clone()));
+        photo_mode.saved_ui_visibility = ui_roots
+            .iter()
+            .map(|(entity, visibility)| (entity, *visibility))
+            .collect();
+        photo_mode.active = true;
+    } else if wants_exit {
+        if let (Some((saved_transform, saved_projection)), Ok((mut transform, mut projection))) =
+            (photo_mode.saved_camera.take(), camera_query.single_mut())
+        {
+            *transform = saved_transform;
+            *projection = saved_projection;
+        }
+        for (entity, mut visibility) in &mut ui_roots {
+            let restored = photo_mode
+                .saved_ui_visibility
+                .get(&entity)
+                .copied()
+                .unwrap_or(Visibility::Inherited);
+            visibility.set_if_neq(restored);
+        }
+        photo_mode.saved_ui_visibility.clear();
+        photo_mode.active = false;
+    }
+
+    if !photo_mode.active {
+        return;
+    }
+
+    // Other systems toggle HUD visibility every frame, so keep forcing it off.
+    for (_, mut visibility) in &mut ui_roots {
+        visibility.set_if_neq(Visibility::Hidden);
+    }
+
+    if keybinds.action_just_pressed(&keys, &mouse_buttons, GameAction::PhotoScreenshot) {
+        let millis = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|elapsed| elapsed.as_millis())
+            .unwrap_or_default();
+        if let Err(err) = fs::create_dir_all(PHOTO_MODE_SCREENSHOT_DIR) {
+            warn!(
+                "Kon screenshotmap {} niet maken: {err}",
+                PHOTO_MODE_SCREENSHOT_DIR
+            );
+        } else {
+            let path = format!("{PHOTO_MODE_SCREENSHOT_DIR}/photo_{millis}.png");
+            commands
+                .spawn(Screenshot::primary_window())
+                .observe(save_to_disk(path));
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn photo_mode_camera(
+    keys: Res<ButtonInput<KeyCode>>,
+    keybinds: Res<GameKeybinds>,
+    time: Res<Time>,
+    mouse_motion: Res<AccumulatedMouseMotion>,
+    mouse_scroll: Res<AccumulatedMouseScroll>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mut photo_mode: ResMut<PhotoModeState>,
+    mut camera_query: Query<
+        (&mut Transform, &mut Projection, &ThirdPersonCameraRig),
+        With<Camera3d>,
+    >,
+) {
+    if !photo_mode.active {
+        return;
+    }
+
+    let Ok((mut transform, mut projection, rig)) = camera_query.single_mut() else {
+        return;
+    };
+
+    let dt = time.delta_secs();
+    let look =
+        if mouse_buttons.pressed(MouseButton::Left) || mouse_buttons.pressed(MouseButton::Right) {
+            -mouse_motion.delta * rig.look_sensitivity
+        } else {
+            Vec2::ZERO
+        };
+
+    let roll_axis = (keybinds.action_pressed(&keys, &mouse_buttons, GameAction::PhotoRollRight)
+        as i8
+        - keybinds.action_pressed(&keys, &mouse_buttons, GameAction::PhotoRollLeft) as i8)
+        as f32;
+    photo_mode.roll += roll_axis * PHOTO_MODE_ROLL_SPEED * dt;
+    if keybinds.action_just_pressed(&keys, &mouse_buttons, GameAction::PhotoResetRoll) {
+        photo_mode.roll = 0.0;
+    }
+
+    photo_mode.fov = (photo_mode.fov - mouse_scroll.delta.y * 0.05).clamp(0.2, 2.0);
+    if let Projection::Perspective(perspective) = projection.as_mut() {
+        perspective.fov = photo_mode.fov;
+    }
+
+    let forward_axis = (keybinds.action_pressed(&keys, &mouse_buttons, GameAction::MoveForward)
+        as i8
+        - keybinds.action_pressed(&keys, &mouse_buttons, GameAction::MoveBackward) as i8)
+        as f32;
+    let strafe_right = keybinds.action_pressed(&keys, &mouse_buttons, GameAction::StrafeRight)
+        || keybinds.action_pressed(&keys, &mouse_buttons, GameAction::TurnRight);
+    let strafe_left = keybinds.action_pressed(&keys, &mouse_buttons, GameAction::StrafeLeft)
+        || keybinds.action_pressed(&keys, &mouse_buttons, GameAction::TurnLeft);
+    let strafe_axis = (strafe_right as i8 - strafe_left as i8) as f32;
+    let vertical_axis = (keybinds.action_pressed(&keys, &mouse_buttons, GameAction::Jump) as i8
+        - keybinds.action_pressed(&keys, &mouse_buttons, GameAction::PhotoFlyDown) as i8)
+        as f32;
+
+    let input = FlyCameraInput {
+        look,
+        movement: Vec3::new(forward_axis, strafe_axis, vertical_axis),
+        fast: keybinds.action_pressed(&keys, &mouse_buttons, GameAction::Sprint),
+    };
+    fly_camera(&mut transform, &mut photo_mode, input, dt);
+}
+
+/// Turns and moves the photo-mode camera by one frame of `input`.
+fn fly_camera(
+    transform: &mut Transform,
+    photo_mode: &mut PhotoModeState,
+    input: FlyCameraInput,
+    dt: f32,
+) {
+    photo_mode.yaw += input.look.x;
+    photo_mode.pitch = (photo_mode.pitch + input.look.y).clamp(-1.5, 1.5);
+    transform.rotation = Quat::from_euler(
+        EulerRot::YXZ,
+        photo_mode.yaw,
+        photo_mode.pitch,
+        photo_mode.roll,
+    );
+
+    let movement = (transform.forward() * input.movement.x
+        + transform.right() * input.movement.y
+        + Vec3::Y * input.movement.z)
+        .normalize_or_zero();
+    let speed = if input.fast {
+        PHOTO_MODE_FLY_SPEED * PHOTO_MODE_FAST_MULTIPLIER
+    } else {
+        PHOTO_MODE_FLY_SPEED
+    };
+    transform.translation += movement * speed * dt;
+}
+
+/// `--benchmark`: once the scenario is loaded, flies the photo-mode camera in a slow circle
+/// for `BENCHMARK_SECS`, then logs the frame-time summary and exits.
+pub(super) fn run_benchmark_flythrough(
+    real_time: Res<Time<Real>>,
+    flow: Res<GameFlowState>,
+    mut benchmark: ResMut<BenchmarkRun>,
+    mut photo_mode: ResMut<PhotoModeState>,
+    mut camera_query: Query<(&mut Transform, &Projection), With<Camera3d>>,
+    mut app_exit: MessageWriter<AppExit>,
+) {
+    if !flow.in_game {
+        return;
+    }
+    let Ok((mut transform, projection)) = camera_query.single_mut() else {
+        return;
+    };
+    if !photo_mode.active {
+        photo_mode.aim_from(&transform, projection);
+        photo_mode.pitch = BENCHMARK_PITCH;
+        photo_mode.active = true;
+    }
+
+    let dt = real_time.delta_secs();
+    benchmark.elapsed_secs += dt;
+    if benchmark.elapsed_secs > BENCHMARK_WARMUP_SECS {
+        benchmark.frame_times.push(dt);
+    }
+    let input = FlyCameraInput {
+        look: Vec2::new(BENCHMARK_TURN_RATE * dt, 0.0),
+        movement: Vec3::X,
+        fast: false,
+    };
+    fly_camera(&mut transform, &mut photo_mode, input, dt);
+
+    if benchmark.elapsed_secs < BENCHMARK_WARMUP_SECS + BENCHMARK_SECS {
+        return;
+    }
+    let summary = benchmark_summary(&benchmark.frame_times);
+    info!(
+        "Benchmark '{}': {} frames in {:.1}s, gemiddeld {:.1} FPS, 1% laagste {:.1} FPS, traagste frame {:.1} ms",
+        benchmark.scenario_id,
+        benchmark.frame_times.len(),
+        BENCHMARK_SECS,
+        summary.average_fps,
+        summary.low_1_percent_fps,
+        summary.worst_frame_ms
+    );
+    app_exit.write(AppExit::Success);
+}
+
+/// FPS summary of a run's frame times (seconds): the mean, the mean of the slowest 1%,
+/// and the single slowest frame.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct BenchmarkSummary {
+    pub(super) average_fps: f32,
+    pub(super) low_1_percent_fps: f32,
+    pub(super) worst_frame_ms: f32,
+}
+
+pub(super) fn benchmark_summary(frame_times: &[f32]) -> BenchmarkSummary {
+    let fps = |times: &[f32]| {
+        let total: f32 = times.iter().sum();
+        if total > 0.0 {
+            times.len() as f32 / total
+        } else {
+            0.0
+        }
+    };
+    let mut slowest = frame_times.to_vec();
+    slowest.sort_by(|a, b| b.total_cmp(a));
+    let low_count = slowest.len().div_ceil(100);
+    BenchmarkSummary {
+        average_fps: fps(frame_times),
+        low_1_percent_fps: fps(&slowest[..low_count]),
+        worst_frame_ms: slowest.first().map_or(0.0, |worst| worst * 1000.0),
+    }
+}
+
+/// Next `ThirdPersonCameraRig::collision_distance`: snaps in to `clear` and eases back out
+/// toward it, going back to infinite once the full distance is reached.
+fn eased_collision_distance(held: f32, clear: f32, full: f32, dt: f32) -> f32 {
+    let next = if clear < held {
+        clear
+    } else {
+        held.lerp(clear, 1.0 - (-CAMERA_COLLISION_RESTORE_RATE * dt).exp())
+    };
+    if next >= full - 1e-3 {
+        f32::INFINITY
+    } else {
+        next
+    }
 }
 
-fn resolve_camera_collision(origin: Vec3, desired: Vec3, grid: &WorldCollisionGrid) -> Vec3 {
-    let camera_radius = 0.18_f32;
-    let camera_skin = 0.08_f32;
+/// How far from `origin` toward `desired` the camera can sit, keeping `CAMERA_COLLISION_SKIN`
+/// off the first obstruction; the full distance when nothing is in the way.
+fn camera_clear_distance(origin: Vec3, desired: Vec3, grid: &WorldCollisionGrid) -> f32 {
     let min_from_origin = 0.35_f32;
 
-    let segment = desired - origin;
-    let distance = segment.length();
+    let distance = origin.distance(desired);
     if distance <= 1e-5 {
-        return desired;
+        return distance;
+    }
+
+    let hit_t = camera_hit_fraction(origin, desired, grid);
+    if hit_t >= 1.0 {
+        return distance;
+    }
+
+    (distance * hit_t - CAMERA_COLLISION_SKIN)
+        .max(min_from_origin)
+        .min(distance)
+}
+
+/// Fraction of `origin..desired` the camera sphere travels before touching a collider.
+fn camera_hit_fraction(origin: Vec3, desired: Vec3, grid: &WorldCollisionGrid) -> f32 {
+    let distance = origin.distance(desired);
+    if distance <= 1e-5 {
+        return 1.0;
     }
 
-    let dir = segment / distance;
     let mut hit_t = 1.0_f32;
     let query_center = (origin + desired) * 0.5;
-    let query_radius = distance * 0.5 + camera_radius + 0.5;
+    let query_radius = distance * 0.5 + CAMERA_COLLISION_RADIUS + 0.5;
 
     grid.query_nearby(query_center, query_radius, |collider| {
-        let expanded_half = collider.half_extents + Vec3::splat(camera_radius);
+        let expanded_half = collider.half_extents + Vec3::splat(CAMERA_COLLISION_RADIUS);
         let box_min = collider.center - expanded_half;
         let box_max = collider.center + expanded_half;
-        if let Some(t) = segment_aabb_toi(origin, desired, box_min, box_max) {
-            if t < hit_t {
-                hit_t = t;
-            }
+        if let Some(t) = segment_aabb_toi(origin, desired, box_min, box_max)
+            && t < hit_t
+        {
+            hit_t = t;
         }
     });
-
-    if hit_t >= 1.0 {
-        return desired;
-    }
-
-    let safe_distance = (distance * hit_t - camera_skin).max(min_from_origin);
-    origin + dir * safe_distance
+    hit_t
 }
 
 fn segment_aabb_toi(start: Vec3, end: Vec3, box_min: Vec3, box_max: Vec3) -> Option<f32> {
@@ -1009,6 +2282,170 @@ pub(super) fn billboard_stair_labels(
     }
 }
 
+pub(super) fn update_player_lod(
+    settings: Res<GameSettings>,
+    camera_query: Query<&Transform, (With<Camera3d>, Without<Player>)>,
+    player_query: Query<&Transform, (With<Player>, Without<Camera3d>)>,
+    mut visual_roots: Query<
+        &mut Visibility,
+        (With<ProceduralHumanVisualRoot>, Without<PlayerLodProxy>),
+    >,
+    mut proxies: Query<&mut Visibility, (With<PlayerLodProxy>, Without<ProceduralHumanVisualRoot>)>,
+) {
+    let Ok(camera_transform) = camera_query.single() else {
+        return;
+    };
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+
+    let lod_distance = settings.player_lod_distance.max(0.0);
+    let far = camera_transform
+        .translation
+        .distance_squared(player_transform.translation)
+        > lod_distance * lod_distance;
+    let (detailed, proxy) = if far {
+        (Visibility::Hidden, Visibility::Inherited)
+    } else {
+        (Visibility::Inherited, Visibility::Hidden)
+    };
+
+    for mut visibility in &mut visual_roots {
+        visibility.set_if_neq(detailed);
+    }
+    for mut visibility in &mut proxies {
+        visibility.set_if_neq(proxy);
+    }
+}
+
+pub(super) fn update_player_shading(
+    settings: Res<GameSettings>,
+    mut parts: Query<(
+        Ref<PlayerShading>,
+        &mut MeshMaterial3d<StandardMaterial>,
+        Option<&mut FogAlphaMaterialState>,
+    )>,
+) {
+    for (shading, mut material, fog_state) in &mut parts {
+        if !settings.is_changed() && !shading.is_added() {
+            continue;
+        }
+        let target = match settings.human_shading {
+            HumanShadingSetting::Lit => &shading.lit,
+            HumanShadingSetting::Flat => &shading.flat,
+        };
+        // Alpha fog derives its material from the source handle, so swap that instead.
+        match fog_state {
+            Some(mut state) => {
+                if state.source != *target {
+                    state.source = target.clone();
+                }
+            }
+            None => {
+                if material.0 != *target {
+                    material.0 = target.clone();
+                }
+            }
+        }
+    }
+}
+
+/// Fades the player's materials when the camera closes in on it. The player owns its
+/// materials, so they are edited in place; `applied_alpha` skips re-uploads while steady.
+#[allow(clippy::type_complexity)]
+pub(super) fn fade_player_near_camera(
+    settings: Res<GameSettings>,
+    camera_query: Query<&Transform, With<Camera3d>>,
+    player_query: Query<(&Transform, &PlayerCollider), (With<Player>, Without<Camera3d>)>,
+    parts: Query<Ref<PlayerShading>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut applied_alpha: Local<Option<f32>>,
+) {
+    let alpha = match (camera_query.single(), player_query.single()) {
+        (Ok(camera_transform), Ok((player_transform, collider)))
+            if settings.fade_player_near_camera =>
+        {
+            // Measure to the nearest point of the body's axis, not just its centre.
+            let half_axis = (collider.half_height - collider.radius).max(0.0);
+            let offset = camera_transform.translation - player_transform.translation;
+            let axis_point = Vec3::Y * offset.y.clamp(-half_axis, half_axis);
+            let distance = (offset - axis_point).length() - collider.radius;
+            let fade_range = (settings.player_fade_distance - PLAYER_FADE_NEAR_DISTANCE).max(1e-3);
+            let t = ((distance - PLAYER_FADE_NEAR_DISTANCE) / fade_range).clamp(0.0, 1.0);
+            PLAYER_FADE_MIN_ALPHA + (1.0 - PLAYER_FADE_MIN_ALPHA) * smoothstep01(t)
+        }
+        _ => 1.0,
+    };
+    // A respawned player starts opaque, so new parts force a re-apply.
+    let alpha = (alpha * 50.0).round() / 50.0;
+    if *applied_alpha == Some(alpha) && !parts.iter().any(|shading| shading.is_added()) {
+        return;
+    }
+    *applied_alpha = Some(alpha);
+
+    for shading in &parts {
+        for handle in [&shading.lit, &shading.flat] {
+            let Some(material) = materials.get_mut(handle) else {
+                continue;
+            };
+            material.base_color.set_alpha(alpha);
+            material.alpha_mode = if alpha < 1.0 {
+                AlphaMode::Blend
+            } else {
+                AlphaMode::Opaque
+            };
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub(super) fn update_prop_lod(
+    settings: Res<GameSettings>,
+    camera_query: Query<&Transform, With<Camera3d>>,
+    mut props: Query<
+        (
+            &Transform,
+            &mut PropLod,
+            &mut MeshMaterial3d<StandardMaterial>,
+            Option<&mut FogAlphaMaterialState>,
+        ),
+        Without<Camera3d>,
+    >,
+) {
+    let Ok(camera_transform) = camera_query.single() else {
+        return;
+    };
+
+    let far_distance = settings.prop_lod_distance.max(0.0);
+    // Hysteresis band so props right at the threshold don't flicker between materials.
+    let near_distance = far_distance * 0.9;
+
+    for (transform, mut lod, mut material, fog_state) in &mut props {
+        let distance = camera_transform.translation.distance(transform.translation);
+        let far = if lod.far {
+            distance > near_distance
+        } else {
+            distance > far_distance
+        };
+        if far == lod.far {
+            continue;
+        }
+
+        lod.far = far;
+        let target = if far {
+            lod.far_material.clone()
+        } else {
+            lod.near_material.clone()
+        };
+        // Alpha fog derives its material from the source handle, so swap that instead.
+        match fog_state {
+            Some(mut state) => state.source = target,
+            None => material.0 = target,
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
 pub(super) fn update_player_blob_shadow(
     settings: Res<GameSettings>,
     player_query: Query<(&Transform, &PlayerCollider), (With<Player>, Without<PlayerBlobShadow>)>,
@@ -1056,32 +2493,626 @@ pub(super) fn update_player_blob_shadow(
     shadow_transform.translation = Vec3::new(player_pos.x, support_top + 0.015, player_pos.z);
     shadow_transform.scale = Vec3::new(radius, 1.0, radius);
 
-    if let Some(material) = materials.get_mut(&shadow_material.0) {
-        material.base_color = material.base_color.with_alpha(0.16 + 0.42 * fade);
+    if let Some(material) = materials.get_mut(&shadow_material.0) {
+        material.base_color = material.base_color.with_alpha(0.16 + 0.42 * fade);
+    }
+}
+
+pub(super) fn update_target_dummy_triggers(
+    player_query: Query<&Transform, With<Player>>,
+    mut dummy_query: Query<(&Transform, &mut TargetDummy), Without<Player>>,
+) {
+    let Ok(player_transform) = player_query.single() else {
+        return;
+    };
+
+    for (dummy_transform, mut dummy) in &mut dummy_query {
+        let offset = dummy_transform.translation - player_transform.translation;
+        let distance = Vec2::new(offset.x, offset.z).length();
+        let in_range = distance <= dummy.trigger_radius;
+        if in_range == dummy.player_in_range {
+            continue;
+        }
+
+        dummy.player_in_range = in_range;
+        if in_range {
+            info!("Speler binnen bereik van doelpop ({distance:.1} m)");
+        } else {
+            info!("Speler buiten bereik van doelpop");
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn update_patrols(
+    time: Res<Time>,
+    sim: Res<SimPaused>,
+    debug: Res<DebugSettings>,
+    menu: Res<MenuState>,
+    settings: Res<GameSettings>,
+    world_collision_grid: Res<WorldCollisionGrid>,
+    player_query: Query<(&Player, &Transform, &PlayerCollider)>,
+    mut patrol_query: Query<
+        (
+            &mut Transform,
+            &mut Patrol,
+            &mut PlayerKinematics,
+            &WorldCollider,
+        ),
+        Without<Player>,
+    >,
+) {
+    if menu.pauses_game(&settings) {
+        return;
+    }
+    let Ok((player, player_transform, player_collider)) = player_query.single() else {
+        return;
+    };
+
+    let dt = sim_delta_secs(&time, &sim, &debug);
+    if dt <= 0.0 {
+        return;
+    }
+    for (mut transform, mut patrol, mut kinematics, world_collider) in &mut patrol_query {
+        if patrol.waypoints.is_empty() {
+            continue;
+        }
+        patrol.next_waypoint %= patrol.waypoints.len();
+
+        let target = patrol.waypoints[patrol.next_waypoint];
+        let to_target = Vec3::new(
+            target.x - transform.translation.x,
+            0.0,
+            target.y - transform.translation.z,
+        );
+        let distance = to_target.length();
+        if distance < PATROL_ARRIVE_DISTANCE {
+            patrol.next_waypoint = (patrol.next_waypoint + 1) % patrol.waypoints.len();
+            continue;
+        }
+
+        let direction = to_target / distance;
+        let desired_delta = direction * (patrol.speed * dt).min(distance);
+        let next_position = move_kinematic(
+            transform.translation,
+            patrol.collider,
+            desired_delta,
+            None,
+            &mut kinematics,
+            player.gravity,
+            dt,
+            &world_collision_grid,
+            None,
+        );
+        transform.rotation = Quat::from_rotation_y((-direction.x).atan2(-direction.z));
+
+        // Wait for the player to step aside instead of walking through them.
+        if intersects_vertical_capsule_aabb(
+            player_transform.translation,
+            player_collider.radius,
+            player_collider.half_height,
+            next_position,
+            world_collider.half_extents,
+        ) {
+            transform.translation.y = next_position.y;
+            kinematics.horizontal_velocity = Vec2::ZERO;
+            continue;
+        }
+        transform.translation = next_position;
+
+        // Skip waypoints that are unreachable instead of pushing into a wall forever.
+        if kinematics.horizontal_velocity.length_squared() < 1e-6 {
+            patrol.next_waypoint = (patrol.next_waypoint + 1) % patrol.waypoints.len();
+        }
+    }
+}
+
+/// Simulation dt for this frame: real dt (capped) normally, zero while paused and one
+/// fixed step per requested frame step, all scaled by the debug time scale.
+pub(super) fn sim_delta_secs(time: &Time, sim: &SimPaused, debug: &DebugSettings) -> f32 {
+    let dt = if !sim.paused {
+        capped_delta_secs(time.delta_secs(), debug)
+    } else if sim.pending_steps > 0 {
+        SIM_STEP_DT
+    } else {
+        0.0
+    };
+    dt * debug
+        .time_scale
+        .clamp(TIME_SCALE_RANGE.0, TIME_SCALE_RANGE.1)
+}
+
+fn capped_delta_secs(dt: f32, debug: &DebugSettings) -> f32 {
+    if debug.cap_delta_time {
+        dt.min(debug.max_delta_secs.max(SIM_STEP_DT))
+    } else {
+        dt
+    }
+}
+
+pub(super) fn handle_sim_pause_input(
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    keybinds: Res<GameKeybinds>,
+    menu: Res<MenuState>,
+    mut sim: ResMut<SimPaused>,
+) {
+    if menu.open {
+        return;
+    }
+
+    if keybinds.action_just_pressed(&keys, &mouse_buttons, GameAction::PauseSimulation) {
+        sim.paused = !sim.paused;
+        sim.pending_steps = 0;
+    }
+    if sim.paused && keybinds.action_just_pressed(&keys, &mouse_buttons, GameAction::StepSimulation)
+    {
+        sim.pending_steps += 1;
+    }
+}
+
+/// Strafe (x) and forward (y) input in -1..=1 as `player_move` resolves it. With
+/// `turn_keys_strafe` (aim-move, camera-relative) the turn keys strafe as well.
+pub(super) fn movement_axes(
+    keybinds: &GameKeybinds,
+    keys: &ButtonInput<KeyCode>,
+    mouse_buttons: &ButtonInput<MouseButton>,
+    auto_walk: bool,
+    turn_keys_strafe: bool,
+) -> Vec2 {
+    let pressed = |action| keybinds.action_pressed(keys, mouse_buttons, action);
+    let forward_axis = if auto_walk {
+        1.0
+    } else {
+        (pressed(GameAction::MoveForward) as i8 - pressed(GameAction::MoveBackward) as i8) as f32
+    };
+    let strafe_right =
+        pressed(GameAction::StrafeRight) || (turn_keys_strafe && pressed(GameAction::TurnRight));
+    let strafe_left =
+        pressed(GameAction::StrafeLeft) || (turn_keys_strafe && pressed(GameAction::TurnLeft));
+    Vec2::new(
+        (strafe_right as i8 - strafe_left as i8) as f32,
+        forward_axis,
+    )
+}
+
+/// Debug hotkey for comparing shaded and wireframe rendering without opening the menu.
+pub(super) fn toggle_wireframe_hotkey(
+    keys: Res<ButtonInput<KeyCode>>,
+    menu: Res<MenuState>,
+    mut debug: ResMut<DebugSettings>,
+) {
+    if !menu.open && keys.just_pressed(KeyCode::F4) {
+        debug.show_wireframe = !debug.show_wireframe;
+    }
+}
+
+/// Runs after the simulated systems so each requested step lasts exactly one frame.
+pub(super) fn consume_sim_step(mut sim: ResMut<SimPaused>) {
+    if sim.pending_steps > 0 {
+        sim.pending_steps -= 1;
+    }
+}
+
+pub(super) fn update_comfort_vignette(
+    settings: Res<GameSettings>,
+    player_query: Query<(&PlayerKinematics, Option<&ProceduralHumanAnimState>), With<Player>>,
+    mut vignette_query: Query<&mut ImageNode, With<ComfortVignette>>,
+) {
+    let enabled = settings.comfort_vignette || settings.reduce_motion;
+    // The simple player model has no animation state; use its raw speed instead.
+    let speed = player_query
+        .single()
+        .map(|(kinematics, anim_state)| {
+            anim_state.map_or(kinematics.horizontal_velocity.length(), |anim_state| {
+                anim_state.smoothed_speed
+            })
+        })
+        .unwrap_or(0.0);
+    let alpha = if enabled {
+        smoothstep01(((speed - 3.0) / 6.0).clamp(0.0, 1.0)) * 0.7
+    } else {
+        0.0
+    };
+
+    for mut image in &mut vignette_query {
+        if (image.color.alpha() - alpha).abs() > 1e-3 {
+            image.color.set_alpha(alpha);
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(super) fn update_measurement_tool(
+    debug: Res<DebugSettings>,
+    menu: Res<MenuState>,
+    photo_mode: Res<PhotoModeState>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    keybinds: Res<GameKeybinds>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<Camera3d>>,
+    world_collision_grid: Res<WorldCollisionGrid>,
+    mut measurement: ResMut<MeasurementState>,
+    mut readout_query: Query<(&mut Text, &mut Visibility), With<MeasurementReadoutText>>,
+    mut gizmos: Gizmos,
+) {
+    let Ok((mut readout, mut readout_visibility)) = readout_query.single_mut() else {
+        return;
+    };
+
+    if !debug.measurement_tool {
+        measurement.points.clear();
+        readout_visibility.set_if_neq(Visibility::Hidden);
+        return;
+    }
+    // Photo mode hides the readout itself; keep the points for when it exits.
+    if photo_mode.active {
+        return;
+    }
+    readout_visibility.set_if_neq(Visibility::Inherited);
+
+    if !menu.open && keybinds.action_just_pressed(&keys, &mouse_buttons, GameAction::Measure) {
+        let hit = window.cursor_position().and_then(|cursor| {
+            let (camera, camera_transform) = camera_query.single().ok()?;
+            let ray = camera.viewport_to_world(camera_transform, cursor).ok()?;
+            world_collision_grid.raycast(ray.origin, *ray.direction, MEASUREMENT_MAX_DISTANCE)
+        });
+        if let Some(hit) = hit {
+            if measurement.points.len() >= 2 {
+                measurement.points.clear();
+            }
+            measurement.points.push(hit.point);
+        }
+    }
+
+    let marker_color = Color::srgb(1.0, 0.35, 0.85);
+    for point in &measurement.points {
+        draw_marker_cross(&mut gizmos, *point, 0.2, marker_color);
+    }
+
+    let text = match measurement.points.as_slice() {
+        [a, b] => {
+            gizmos.line(*a, *b, marker_color);
+            let delta = *b - *a;
+            format!(
+                "Meting: {:.2} m (dx {:.2}, dy {:.2}, dz {:.2})",
+                delta.length(),
+                delta.x,
+                delta.y,
+                delta.z
+            )
+        }
+        [a] => format!(
+            "Meting: punt 1 op ({:.2}, {:.2}, {:.2}), klik {} voor punt 2",
+            a.x,
+            a.y,
+            a.z,
+            keybinds.display_keys(GameAction::Measure)
+        ),
+        _ => format!(
+            "Meting: klik {} op een oppervlak",
+            keybinds.display_keys(GameAction::Measure)
+        ),
+    };
+    if **readout != text {
+        **readout = text;
+    }
+}
+
+pub(super) fn update_performance_overlay(
+    time: Res<Time>,
+    diagnostics: Res<DiagnosticsStore>,
+    debug: Res<DebugSettings>,
+    sim: Res<SimPaused>,
+    entities: Query<()>,
+    mut text_query: Query<(Entity, &mut Text), With<PerformanceOverlayText>>,
+    mut playtime: Local<(Option<Entity>, f32)>,
+) {
+    let fps = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|fps| fps.smoothed())
+        .unwrap_or(0.0);
+
+    let frame_time_ms = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .and_then(|frame_time| frame_time.smoothed())
+        .unwrap_or(0.0);
+
+    for (entity, mut text) in &mut text_query {
+        // The overlay is respawned per scenario, so a new entity restarts the playtime.
+        if playtime.0 != Some(entity) {
+            *playtime = (Some(entity), 0.0);
+        }
+        playtime.1 += time.delta_secs();
+
+        let metrics = debug.overlay_metrics;
+        let mut lines = Vec::new();
+        if metrics.fps {
+            lines.push(format!("FPS: {fps:>6.1}"));
+        }
+        if metrics.frame_time {
+            lines.push(format!("Frame time: {frame_time_ms:>6.2} ms"));
+        }
+        if metrics.entity_count {
+            lines.push(format!("Entities: {}", entities.iter().count()));
+        }
+        if metrics.playtime {
+            let secs = playtime.1 as u32;
+            lines.push(format!("Playtime: {}:{:02}", secs / 60, secs % 60));
+        }
+        if sim.paused {
+            lines.push("Simulation paused".to_string());
+        }
+        **text = lines.join("\n");
+    }
+}
+
+/// Records the real frame time and redraws the FPS graph bars from the rolling window.
+pub(super) fn update_fps_graph(
+    time: Res<Time<Real>>,
+    debug: Res<DebugSettings>,
+    mut history: ResMut<FrameTimeHistory>,
+    mut graphs: Query<&mut Visibility, With<FpsGraph>>,
+    mut bars: Query<(&FpsGraphBar, &mut Node, &mut BackgroundColor)>,
+) {
+    if history.samples.len() == FPS_GRAPH_SAMPLES {
+        history.samples.pop_front();
+    }
+    history.samples.push_back(time.delta_secs() * 1000.0);
+
+    for mut visibility in &mut graphs {
+        *visibility = if debug.show_fps_graph {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+    }
+    if !debug.show_fps_graph {
+        return;
+    }
+
+    // Right-align the window so the newest sample is always the last bar.
+    let missing = FPS_GRAPH_SAMPLES - history.samples.len();
+    for (bar, mut node, mut color) in &mut bars {
+        let Some(&frame_ms) = bar
+            .0
+            .checked_sub(missing)
+            .and_then(|index| history.samples.get(index))
+        else {
+            node.height = px(0);
+            continue;
+        };
+        let fill = (frame_ms / FPS_GRAPH_FULL_SCALE_MS).clamp(0.02, 1.0);
+        node.height = px(fill * FPS_GRAPH_HEIGHT_PX);
+        color.0 = if frame_ms <= FPS_GRAPH_OK_MS {
+            Color::srgb(0.35, 0.85, 0.40)
+        } else if frame_ms <= FPS_GRAPH_SLOW_MS {
+            Color::srgb(0.95, 0.80, 0.25)
+        } else {
+            Color::srgb(0.95, 0.30, 0.25)
+        };
+    }
+}
+
+/// Writes one CSV row per frame while a diagnostics recording runs, flushing every second
+/// and when the recording stops or reaches its configured duration.
+pub(super) fn record_diagnostics_csv(
+    time: Res<Time>,
+    diagnostics: Res<DiagnosticsStore>,
+    mut debug: ResMut<DebugSettings>,
+    mut menu: ResMut<MenuState>,
+    entities: Query<()>,
+    mut recorder: ResMut<DiagnosticsRecorder>,
+) {
+    if !debug.record_diagnostics {
+        finish_diagnostics_recording(&mut recorder);
+        return;
+    }
+
+    if recorder.writer.is_none() {
+        match start_diagnostics_recording(debug.diagnostics_entity_count) {
+            Ok((writer, path)) => {
+                info!("Diagnostiek opnemen naar {path}");
+                *recorder = DiagnosticsRecorder {
+                    writer: Some(writer),
+                    path,
+                    elapsed_secs: 0.0,
+                    since_flush_secs: 0.0,
+                };
+            }
+            Err(err) => {
+                error!("Kon diagnostiekbestand niet openen: {err}");
+                debug.record_diagnostics = false;
+                menu.dirty = true;
+                return;
+            }
+        }
+    }
+
+    let fps = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FPS)
+        .and_then(|fps| fps.value())
+        .unwrap_or(0.0);
+    let frame_time_ms = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .and_then(|frame_time| frame_time.value())
+        .unwrap_or(0.0);
+    let mut row = format!("{:.4},{fps:.2},{frame_time_ms:.3}", recorder.elapsed_secs);
+    if debug.diagnostics_entity_count {
+        row.push_str(&format!(",{}", entities.iter().count()));
+    }
+
+    let dt = time.delta_secs();
+    recorder.elapsed_secs += dt;
+    recorder.since_flush_secs += dt;
+    let flush = recorder.since_flush_secs >= DIAGNOSTICS_FLUSH_SECS;
+    if flush {
+        recorder.since_flush_secs = 0.0;
+    }
+    let Some(writer) = recorder.writer.as_mut() else {
+        return;
+    };
+    let result =
+        writeln!(writer, "{row}").and_then(|()| if flush { writer.flush() } else { Ok(()) });
+    if let Err(err) = result {
+        error!("Kon niet naar {} schrijven: {err}", recorder.path);
+        debug.record_diagnostics = false;
+    } else if recorder.elapsed_secs >= debug.diagnostics_record_secs {
+        debug.record_diagnostics = false;
+    }
+
+    if !debug.record_diagnostics {
+        finish_diagnostics_recording(&mut recorder);
+        menu.dirty = true;
+    }
+}
+
+/// Logs every frame slower than the hitch threshold once, with the scenario and player
+/// position, and keeps the last few in `HitchLog`.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn detect_hitches(
+    time: Res<Time>,
+    diagnostics: Res<DiagnosticsStore>,
+    debug: Res<DebugSettings>,
+    flow: Res<GameFlowState>,
+    scenarios: Res<ScenarioCatalog>,
+    player_query: Query<&Transform, With<Player>>,
+    mut menu: ResMut<MenuState>,
+    mut hitches: ResMut<HitchLog>,
+    mut last_measured: Local<Option<std::time::Instant>>,
+) {
+    let threshold_ms = debug.hitch_threshold_ms as f64;
+    let Some(measurement) = diagnostics
+        .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
+        .and_then(|frame_time| frame_time.measurement())
+    else {
+        return;
+    };
+    if threshold_ms <= 0.0
+        || measurement.value < threshold_ms
+        || *last_measured == Some(measurement.time)
+    {
+        return;
+    }
+    *last_measured = Some(measurement.time);
+
+    let report = HitchReport {
+        frame_time_ms: measurement.value,
+        at_secs: time.elapsed_secs(),
+        scenario: flow
+            .current_scenario
+            .and_then(|index| scenarios.scenarios.get(index))
+            .map(|scenario| scenario.id.clone()),
+        player_position: player_query
+            .single()
+            .ok()
+            .map(|transform| transform.translation),
+    };
+    warn!(
+        "Hapering: frame van {:.1} ms (scenario {}, speler op {})",
+        report.frame_time_ms,
+        report.scenario.as_deref().unwrap_or("-"),
+        report
+            .player_position
+            .map_or("-".to_string(), |position| format!("{position:.1}")),
+    );
+    if hitches.recent.len() >= HITCH_LOG_LEN {
+        hitches.recent.pop_front();
+    }
+    hitches.recent.push_back(report);
+    if menu.open && menu.screen == MenuScreen::Debug {
+        menu.dirty = true;
+    }
+}
+
+fn start_diagnostics_recording(
+    entity_count: bool,
+) -> std::io::Result<(std::io::BufWriter<fs::File>, String)> {
+    let millis = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis())
+        .unwrap_or_default();
+    fs::create_dir_all(DIAGNOSTICS_CSV_DIR)?;
+    let path = format!("{DIAGNOSTICS_CSV_DIR}/frames_{millis}.csv");
+    let mut writer = std::io::BufWriter::new(fs::File::create(&path)?);
+    writeln!(
+        writer,
+        "time_secs,fps,frame_time_ms{}",
+        if entity_count { ",entities" } else { "" }
+    )?;
+    Ok((writer, path))
+}
+
+fn finish_diagnostics_recording(recorder: &mut DiagnosticsRecorder) {
+    let Some(mut writer) = recorder.writer.take() else {
+        return;
+    };
+    match writer.flush() {
+        Ok(()) => info!(
+            "Diagnostiek opgeslagen in {} ({:.1} s)",
+            recorder.path, recorder.elapsed_secs
+        ),
+        Err(err) => error!("Kon {} niet afronden: {err}", recorder.path),
     }
 }
 
-pub(super) fn update_performance_overlay(
-    diagnostics: Res<DiagnosticsStore>,
-    mut text_query: Query<&mut Text, With<PerformanceOverlayText>>,
+/// Moves the performance overlay into its configured corner.
+pub(super) fn place_performance_overlay(
+    debug: Res<DebugSettings>,
+    mut overlay_query: Query<(&mut Node, Ref<PerformanceOverlayText>)>,
 ) {
-    let fps = diagnostics
-        .get(&FrameTimeDiagnosticsPlugin::FPS)
-        .and_then(|fps| fps.smoothed())
-        .unwrap_or(0.0);
+    for (mut node, overlay) in &mut overlay_query {
+        if debug.is_changed() || overlay.is_added() {
+            debug.overlay_corner.place(&mut node, px(12));
+        }
+    }
+}
 
-    let frame_time_ms = diagnostics
-        .get(&FrameTimeDiagnosticsPlugin::FRAME_TIME)
-        .and_then(|frame_time| frame_time.smoothed())
-        .unwrap_or(0.0);
+/// World-space AABB of a mesh's local `aabb` under `transform`, as (center, half extents).
+pub(super) fn world_aabb(transform: &GlobalTransform, aabb: &Aabb) -> (Vec3, Vec3) {
+    let affine = transform.affine();
+    let center = affine.transform_point3(aabb.center.into());
+    let axes = affine.matrix3;
+    let half = aabb.half_extents;
+    let half_extents = Vec3::new(
+        axes.row(0).abs().dot(half),
+        axes.row(1).abs().dot(half),
+        axes.row(2).abs().dot(half),
+    );
+    (center, half_extents)
+}
 
-    for mut text in &mut text_query {
-        **text = format!("FPS: {fps:>6.1}\nFrame time: {frame_time_ms:>6.2} ms");
+type LimbHitboxFilter = (With<PlayerVisualPart>, Without<PlayerLodProxy>);
+
+/// Rebuilds `LimbHitboxes` from the player's visual parts. Runs after transform propagation
+/// so the boxes match this frame's pose; the LOD proxy is skipped since it only stands in
+/// for the limbs visually.
+pub(super) fn update_limb_hitboxes(
+    debug: Res<DebugSettings>,
+    mut hitboxes: ResMut<LimbHitboxes>,
+    parts: Query<(Entity, &GlobalTransform, &Aabb), LimbHitboxFilter>,
+) {
+    hitboxes.boxes.clear();
+    if !debug.limb_hitboxes {
+        return;
     }
+    hitboxes
+        .boxes
+        .extend(parts.iter().map(|(part, transform, aabb)| {
+            let (center, half_extents) = world_aabb(transform, aabb);
+            LimbHitbox {
+                part,
+                center,
+                half_extents,
+            }
+        }));
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(super) fn draw_debug_geometry(
     debug: Res<DebugSettings>,
+    photo_mode: Res<PhotoModeState>,
     player_query: Query<(&Transform, &PlayerCollider), With<Player>>,
     world_query: Query<(&Transform, &WorldCollider), Without<Player>>,
     leg_hips: Query<(&HumanLegHip, &GlobalTransform, &Children)>,
@@ -1089,29 +3120,52 @@ pub(super) fn draw_debug_geometry(
     arm_pivots: Query<(&HumanArmPivot, &GlobalTransform, &Children)>,
     arm_elbows: Query<&GlobalTransform, With<HumanArmElbow>>,
     heads: Query<&GlobalTransform, With<HumanHead>>,
+    suns: Query<&GlobalTransform, (With<DirectionalLight>, Without<ScenarioPreview>)>,
+    contacts: Res<ControllerContacts>,
+    limb_hitboxes: Res<LimbHitboxes>,
+    patrols: Query<(&Transform, &Patrol), Without<Player>>,
+    anim_states: Query<&ProceduralHumanAnimState, With<Player>>,
     mut gizmos: Gizmos,
 ) {
-    if !debug.show_collision_shapes && !debug.show_world_axes && !debug.show_animation_debug {
+    if photo_mode.active {
+        return;
+    }
+    if !debug.show_collision_shapes
+        && !debug.show_contact_points
+        && !debug.show_world_axes
+        && !debug.show_animation_debug
+        && !debug.show_ground_grid
+        && !debug.show_sun_direction
+    {
         return;
     }
 
+    if debug.show_sun_direction {
+        let color = Color::srgba(1.0, 0.85, 0.2, 0.95);
+        for sun in &suns {
+            draw_marker_cross(&mut gizmos, sun.translation(), 0.6, color);
+            if let Ok((transform, _)) = player_query.single() {
+                let start = transform.translation + Vec3::Y * 4.0;
+                gizmos.arrow(start, start + sun.forward() * 2.5, color);
+            }
+        }
+    }
+
+    if debug.show_ground_grid
+        && let Ok((transform, _)) = player_query.single()
+    {
+        draw_ground_grid(&mut gizmos, transform.translation);
+    }
+
     if debug.show_world_axes {
-        let len = 4.0;
-        gizmos.line(
-            Vec3::ZERO,
-            Vec3::new(len, 0.0, 0.0),
-            Color::srgb(0.9, 0.22, 0.22),
-        );
-        gizmos.line(
-            Vec3::ZERO,
-            Vec3::new(0.0, len, 0.0),
-            Color::srgb(0.22, 0.9, 0.22),
-        );
-        gizmos.line(
-            Vec3::ZERO,
-            Vec3::new(0.0, 0.0, len),
-            Color::srgb(0.22, 0.52, 0.95),
-        );
+        if debug.world_axes_anchor.at_origin() {
+            draw_axes_lines(&mut gizmos, Vec3::ZERO, Quat::IDENTITY, 4.0);
+        }
+        if debug.world_axes_anchor.at_player()
+            && let Ok((transform, _)) = player_query.single()
+        {
+            draw_axes_lines(&mut gizmos, transform.translation, transform.rotation, 1.5);
+        }
     }
 
     if debug.show_collision_shapes {
@@ -1120,7 +3174,7 @@ pub(super) fn draw_debug_geometry(
                 &mut gizmos,
                 transform.translation,
                 collider.half_extents,
-                Color::srgba(1.0, 0.9, 0.35, 0.95),
+                surface_debug_color(collider.surface),
             );
         }
 
@@ -1133,6 +3187,35 @@ pub(super) fn draw_debug_geometry(
                 Color::srgba(0.25, 1.0, 1.0, 0.95),
             );
         }
+
+        // Limbs a patrol's capsule touches light up, as a preview of body-part hits.
+        let touched: Vec<Entity> = patrols
+            .iter()
+            .flat_map(|(transform, patrol)| {
+                limb_hitboxes.hit_by_capsule(
+                    transform.translation,
+                    patrol.collider.radius,
+                    patrol.collider.half_height,
+                )
+            })
+            .map(|hitbox| hitbox.part)
+            .collect();
+        for hitbox in &limb_hitboxes.boxes {
+            let color = if touched.contains(&hitbox.part) {
+                Color::srgba(1.0, 0.2, 0.15, 0.95)
+            } else {
+                Color::srgba(1.0, 0.45, 0.85, 0.9)
+            };
+            draw_aabb_lines(&mut gizmos, hitbox.center, hitbox.half_extents, color);
+        }
+    }
+
+    if debug.show_contact_points {
+        for contact in &contacts.points {
+            let color = Color::srgba(1.0, 0.25, 0.2, 0.95);
+            draw_marker_cross(&mut gizmos, contact.point, 0.05, color);
+            gizmos.arrow(contact.point, contact.point + contact.normal * 0.4, color);
+        }
     }
 
     if debug.show_animation_debug {
@@ -1211,9 +3294,84 @@ pub(super) fn draw_debug_geometry(
                 Color::srgb(0.25, 0.55, 0.95),
             );
         }
+
+        // Attempted ankle targets; an unreachable one is linked to where the ankle ended up.
+        for anim_state in &anim_states {
+            for foot in &anim_state.foot_ik {
+                if foot.reachable {
+                    draw_marker_cross(
+                        &mut gizmos,
+                        foot.attempted,
+                        0.03,
+                        Color::srgba(0.35, 1.0, 0.35, 0.9),
+                    );
+                } else {
+                    let color = Color::srgba(1.0, 0.2, 0.15, 0.95);
+                    draw_marker_cross(&mut gizmos, foot.attempted, 0.06, color);
+                    gizmos.line(foot.achieved, foot.attempted, color);
+                }
+            }
+        }
+    }
+}
+
+/// Grid snapped to whole meters around `focus`; each line fades out from the point
+/// closest to the focus so the grid reads as a disc rather than a hard square.
+fn draw_ground_grid(gizmos: &mut Gizmos, focus: Vec3) {
+    let extent = GRID_EXTENT_METERS as f32;
+    let y = 0.02;
+    let origin_x = focus.x.round() as i32;
+    let origin_z = focus.z.round() as i32;
+
+    for i in -GRID_EXTENT_METERS..=GRID_EXTENT_METERS {
+        let is_major_x = (origin_x + i) % GRID_MAJOR_STEP_METERS == 0;
+        let is_major_z = (origin_z + i) % GRID_MAJOR_STEP_METERS == 0;
+
+        let x = (origin_x + i) as f32;
+        let lateral = (x - focus.x).abs();
+        let near = Vec3::new(x, y, focus.z);
+        let color = ground_grid_color(is_major_x, lateral / extent);
+        let faded = color.with_alpha(0.0);
+        gizmos.line_gradient(near, near - Vec3::Z * extent, color, faded);
+        gizmos.line_gradient(near, near + Vec3::Z * extent, color, faded);
+
+        let z = (origin_z + i) as f32;
+        let lateral = (z - focus.z).abs();
+        let near = Vec3::new(focus.x, y, z);
+        let color = ground_grid_color(is_major_z, lateral / extent);
+        let faded = color.with_alpha(0.0);
+        gizmos.line_gradient(near, near - Vec3::X * extent, color, faded);
+        gizmos.line_gradient(near, near + Vec3::X * extent, color, faded);
+    }
+}
+
+fn ground_grid_color(is_major: bool, normalized_offset: f32) -> Color {
+    let fade = (1.0 - normalized_offset).clamp(0.0, 1.0);
+    if is_major {
+        Color::srgba(0.85, 0.85, 0.85, 0.6 * fade)
+    } else {
+        Color::srgba(0.55, 0.55, 0.55, 0.35 * fade)
     }
 }
 
+fn draw_axes_lines(gizmos: &mut Gizmos, origin: Vec3, rotation: Quat, len: f32) {
+    gizmos.line(
+        origin,
+        origin + rotation * Vec3::new(len, 0.0, 0.0),
+        Color::srgb(0.9, 0.22, 0.22),
+    );
+    gizmos.line(
+        origin,
+        origin + rotation * Vec3::new(0.0, len, 0.0),
+        Color::srgb(0.22, 0.9, 0.22),
+    );
+    gizmos.line(
+        origin,
+        origin + rotation * Vec3::new(0.0, 0.0, len),
+        Color::srgb(0.22, 0.52, 0.95),
+    );
+}
+
 fn draw_marker_cross(gizmos: &mut Gizmos, center: Vec3, half: f32, color: Color) {
     gizmos.line(
         center + Vec3::new(-half, 0.0, 0.0),
@@ -1327,22 +3485,31 @@ fn draw_ring(gizmos: &mut Gizmos, center: Vec3, radius: f32, color: Color, segme
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(super) fn sync_mouse_capture_with_focus(
+    time: Res<Time>,
     flow: Res<GameFlowState>,
     menu: Res<MenuState>,
+    settings: Res<GameSettings>,
     mouse_buttons: Res<ButtonInput<MouseButton>>,
     mut mouse_capture_state: ResMut<MouseLookCaptureState>,
     window_query: Single<(&mut Window, &mut CursorOptions), With<PrimaryWindow>>,
+    player_query: Query<&PlayerKinematics, With<Player>>,
 ) {
     let (mut window, mut cursor_options) = window_query.into_inner();
-    let look_held =
-        mouse_buttons.pressed(MouseButton::Left) || mouse_buttons.pressed(MouseButton::Right);
+    mouse_capture_state.transition_secs =
+        (mouse_capture_state.transition_secs - time.delta_secs()).max(0.0);
+    let look_held = settings.always_capture_mouse
+        || mouse_buttons.pressed(MouseButton::Left)
+        || mouse_buttons.pressed(MouseButton::Right)
+        || player_query.iter().any(|kinematics| kinematics.aiming);
 
     if window.focused && flow.in_game && !menu.open {
         if look_held {
             if !mouse_capture_state.active {
                 mouse_capture_state.restore_position = window.cursor_position();
                 mouse_capture_state.active = true;
+                mouse_capture_state.transition_secs = MOUSE_CAPTURE_TRANSITION_SECS;
             }
             cursor_options.visible = false;
             cursor_options.grab_mode = CursorGrabMode::Locked;
@@ -1352,13 +3519,15 @@ pub(super) fn sync_mouse_capture_with_focus(
                     window.set_cursor_position(Some(position));
                 }
                 mouse_capture_state.active = false;
+                mouse_capture_state.transition_secs = MOUSE_CAPTURE_TRANSITION_SECS;
             }
-            cursor_options.visible = true;
+            cursor_options.visible = mouse_capture_state.transition_secs <= 0.0;
             cursor_options.grab_mode = CursorGrabMode::Confined;
         }
     } else {
         mouse_capture_state.active = false;
         mouse_capture_state.restore_position = None;
+        mouse_capture_state.transition_secs = 0.0;
         cursor_options.visible = true;
         cursor_options.grab_mode = CursorGrabMode::None;
     }
@@ -1383,28 +3552,67 @@ pub(super) fn menu_button_hover_color() -> BackgroundColor {
     BackgroundColor(Color::srgb(0.23, 0.27, 0.35))
 }
 
+pub(super) fn menu_button_selected_color() -> BackgroundColor {
+    BackgroundColor(Color::srgb(0.18, 0.30, 0.42))
+}
+
 pub(super) fn menu_button_pressed_color() -> BackgroundColor {
     BackgroundColor(Color::srgb(0.19, 0.43, 0.25))
 }
 
-pub(super) fn spawn_baked_shadow(
+pub(super) fn spawn_baked_shadows(
     commands: &mut Commands,
-    shadow_mesh: &Handle<Mesh>,
+    meshes: &mut Assets<Mesh>,
     shadow_material: &Handle<StandardMaterial>,
-    center: Vec3,
-    size: Vec2,
+    quads: &[(Vec3, Vec2)],
 ) {
+    if quads.is_empty() {
+        return;
+    }
+
+    // One merged mesh keeps all static shadows in a single draw call.
     commands.spawn((
         BakedShadow,
         InGameEntity,
-        Mesh3d(shadow_mesh.clone()),
+        Mesh3d(meshes.add(build_baked_shadow_mesh(quads))),
         MeshMaterial3d(shadow_material.clone()),
-        Transform::from_translation(center).with_scale(Vec3::new(size.x, 1.0, size.y)),
+        Transform::IDENTITY,
         NotShadowCaster,
         NotShadowReceiver,
     ));
 }
 
+fn build_baked_shadow_mesh(quads: &[(Vec3, Vec2)]) -> Mesh {
+    let mut positions: Vec<[f32; 3]> = Vec::with_capacity(quads.len() * 4);
+    let mut normals: Vec<[f32; 3]> = Vec::with_capacity(quads.len() * 4);
+    let mut uvs: Vec<[f32; 2]> = Vec::with_capacity(quads.len() * 4);
+    let mut indices: Vec<u32> = Vec::with_capacity(quads.len() * 6);
+
+    for (center, size) in quads {
+        let half = *size * 0.5;
+        let base = positions.len() as u32;
+        positions.extend_from_slice(&[
+            [center.x - half.x, center.y, center.z - half.y],
+            [center.x - half.x, center.y, center.z + half.y],
+            [center.x + half.x, center.y, center.z + half.y],
+            [center.x + half.x, center.y, center.z - half.y],
+        ]);
+        normals.extend_from_slice(&[[0.0, 1.0, 0.0]; 4]);
+        uvs.extend_from_slice(&[[0.0, 0.0], [0.0, 1.0], [1.0, 1.0], [1.0, 0.0]]);
+        indices.extend_from_slice(&[base, base + 1, base + 2, base, base + 2, base + 3]);
+    }
+
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_indices(Indices::U32(indices));
+    mesh
+}
+
 pub(super) fn would_collide(
     player_center: Vec3,
     player_collider: PlayerCollider,
@@ -1422,21 +3630,67 @@ pub(super) fn would_collide(
             player_collider.half_height,
             collider.center,
             collider.half_extents,
-        );
+        ) && (collider.slope_normal.is_none()
+            || player_center.y - player_collider.half_height
+                < collider.top_at(player_center.x, player_center.z) - 1e-3);
     });
     hit
 }
 
+/// Whether a surface with `normal` is flat enough to stand on.
+fn is_walkable(normal: Vec3) -> bool {
+    normal.y >= MAX_SLOPE_DEG.to_radians().cos()
+}
+
+/// XZ direction down a ramp that is too steep to walk on; `None` for walkable tops.
+fn steep_downhill(collider: &StaticCollider) -> Option<Vec2> {
+    let normal = collider.slope_normal?;
+    (!is_walkable(normal)).then(|| Vec2::new(normal.x, normal.z).normalize_or_zero())
+}
+
+fn footprint_contains(collider: &StaticCollider, point: Vec3) -> bool {
+    (point.x - collider.center.x).abs() <= collider.half_extents.x
+        && (point.z - collider.center.z).abs() <= collider.half_extents.z
+}
+
+/// Highest top under the capsule's center within step range of its feet, and whether it
+/// belongs to a walkable ramp.
+fn ground_support(
+    position: Vec3,
+    collider: PlayerCollider,
+    grid: &WorldCollisionGrid,
+) -> Option<(f32, bool)> {
+    let feet_y = position.y - collider.half_height;
+    let mut best: Option<(f32, bool)> = None;
+    grid.query_nearby(position, collider.radius, |static_collider| {
+        if !footprint_contains(&static_collider, position) {
+            return;
+        }
+        let top = static_collider.top_at(position.x, position.z);
+        if top > feet_y + CONTROLLER_STEP_HEIGHT
+            || top < feet_y - CONTROLLER_STEP_DROP
+            || best.is_some_and(|(best_top, _)| best_top >= top)
+        {
+            return;
+        }
+        let walkable_ramp = static_collider.slope_normal.is_some_and(is_walkable);
+        best = Some((top, walkable_ramp));
+    });
+    best
+}
+
+/// Highest top the capsule's feet cross on the way from `previous_center` down to
+/// `proposed_center`, with that surface's normal.
 pub(super) fn find_landing_top(
     previous_center: Vec3,
     proposed_center: Vec3,
     player_collider: PlayerCollider,
     world_collision_grid: &WorldCollisionGrid,
-) -> Option<f32> {
+) -> Option<(f32, Vec3)> {
     let previous_bottom = previous_center.y - player_collider.half_height;
     let proposed_bottom = proposed_center.y - player_collider.half_height;
     let epsilon = 0.0001;
-    let mut top_hit: Option<f32> = None;
+    let mut top_hit: Option<(f32, Vec3)> = None;
 
     world_collision_grid.query_nearby(proposed_center, player_collider.radius + 0.1, |collider| {
         if !intersects_disc_aabb_xz(
@@ -1448,12 +3702,12 @@ pub(super) fn find_landing_top(
             return;
         }
 
-        let collider_top = collider.center.y + collider.half_extents.y;
+        let collider_top = collider.top_at(proposed_center.x, proposed_center.z);
         let crossed_top =
             previous_bottom >= collider_top - epsilon && proposed_bottom <= collider_top + epsilon;
 
-        if crossed_top {
-            top_hit = Some(top_hit.map_or(collider_top, |best| best.max(collider_top)));
+        if crossed_top && top_hit.is_none_or(|(best, _)| collider_top > best) {
+            top_hit = Some((collider_top, collider.top_normal()));
         }
     });
 
@@ -1493,6 +3747,42 @@ pub(super) fn find_ceiling_bottom(
     bottom_hit
 }
 
+/// Moves a vertical capsule out of a box along XZ when the two overlap.
+fn push_out_of_box_xz(
+    position: Vec3,
+    collider: PlayerCollider,
+    box_center: Vec3,
+    box_half_extents: Vec3,
+) -> Vec3 {
+    if !intersects_vertical_capsule_aabb(
+        position,
+        collider.radius,
+        collider.half_height,
+        box_center,
+        box_half_extents,
+    ) {
+        return position;
+    }
+
+    let offset = Vec2::new(position.x - box_center.x, position.z - box_center.z);
+    let half = Vec2::new(box_half_extents.x, box_half_extents.z);
+    let closest = offset.clamp(-half, half);
+    let outside = offset - closest;
+    let push = if outside.length_squared() > 1e-8 {
+        outside.normalize() * (collider.radius - outside.length())
+    } else {
+        // Center inside the box footprint: leave along the shallowest axis.
+        let depth = half + Vec2::splat(collider.radius) - offset.abs();
+        if depth.x < depth.y {
+            Vec2::new(depth.x * offset.x.signum(), 0.0)
+        } else {
+            Vec2::new(0.0, depth.y * offset.y.signum())
+        }
+    };
+
+    position + Vec3::new(push.x, 0.0, push.y)
+}
+
 pub(super) fn intersects_disc_aabb_xz(
     disc_center: Vec3,
     disc_radius: f32,
@@ -1536,3 +3826,686 @@ pub(super) fn intersects_vertical_capsule_aabb(
     outside_x * outside_x + outside_y * outside_y + outside_z * outside_z
         < capsule_radius * capsule_radius
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn benchmark_summary_averages_over_time_and_isolates_the_slow_tail() {
+        let mut frame_times = vec![0.01; 198];
+        frame_times.extend([0.05, 0.1]);
+        let summary = benchmark_summary(&frame_times);
+        assert!((summary.average_fps - 200.0 / 2.13).abs() < 1e-3);
+        assert!((summary.low_1_percent_fps - 2.0 / 0.15).abs() < 1e-3);
+        assert!((summary.worst_frame_ms - 100.0).abs() < 1e-3);
+        assert_eq!(benchmark_summary(&[]).average_fps, 0.0);
+    }
+
+    #[test]
+    fn world_aabb_follows_translation_and_quarter_turns() {
+        let aabb = Aabb::from_min_max(Vec3::new(-0.1, -0.5, -0.2), Vec3::new(0.1, 0.5, 0.2));
+        let transform = GlobalTransform::from(
+            Transform::from_xyz(1.0, 2.0, 3.0)
+                .with_rotation(Quat::from_rotation_z(std::f32::consts::FRAC_PI_2)),
+        );
+        let (center, half_extents) = world_aabb(&transform, &aabb);
+        assert!(center.abs_diff_eq(Vec3::new(1.0, 2.0, 3.0), 1e-5));
+        assert!(half_extents.abs_diff_eq(Vec3::new(0.5, 0.1, 0.2), 1e-5));
+    }
+
+    #[test]
+    fn movement_axes_match_the_inline_player_move_input() {
+        const ACTIONS: [GameAction; 6] = [
+            GameAction::MoveForward,
+            GameAction::MoveBackward,
+            GameAction::StrafeLeft,
+            GameAction::StrafeRight,
+            GameAction::TurnLeft,
+            GameAction::TurnRight,
+        ];
+        let keybinds = GameKeybinds::default();
+        let mouse_buttons = ButtonInput::<MouseButton>::default();
+        for held in 0..1u32 << ACTIONS.len() {
+            let mut keys = ButtonInput::<KeyCode>::default();
+            for (bit, action) in ACTIONS.into_iter().enumerate() {
+                if held & (1 << bit) != 0
+                    && let InputBinding::Key(key) = keybinds.keys_for(action)[0].input
+                {
+                    keys.press(key);
+                }
+            }
+            let down = |bit: usize| held & (1 << bit) != 0;
+            for auto_walk in [false, true] {
+                for turn_keys_strafe in [false, true] {
+                    // What player_move computed before the axes moved into movement_axes.
+                    let forward_axis = if auto_walk {
+                        1.0
+                    } else {
+                        (down(0) as i8 - down(1) as i8) as f32
+                    };
+                    let strafe_axis = if turn_keys_strafe {
+                        ((down(3) || down(5)) as i8 - (down(2) || down(4)) as i8) as f32
+                    } else {
+                        (down(3) as i8 - down(2) as i8) as f32
+                    };
+                    assert_eq!(
+                        movement_axes(
+                            &keybinds,
+                            &keys,
+                            &mouse_buttons,
+                            auto_walk,
+                            turn_keys_strafe
+                        ),
+                        Vec2::new(strafe_axis, forward_axis),
+                        "held {held:06b}, auto-walk {auto_walk}, turn keys strafe {turn_keys_strafe}"
+                    );
+                }
+            }
+        }
+    }
+
+    const COLLIDER: PlayerCollider = PlayerCollider {
+        radius: 0.35,
+        half_height: 0.9,
+    };
+
+    fn floor_with_step() -> WorldCollisionGrid {
+        WorldCollisionGrid::from_colliders(
+            vec![
+                StaticCollider {
+                    center: Vec3::new(0.0, -0.5, 0.0),
+                    half_extents: Vec3::new(10.0, 0.5, 10.0),
+                    surface: SurfaceType::Grass,
+                    slope_normal: None,
+                },
+                StaticCollider {
+                    center: Vec3::new(0.0, 0.15, -1.0),
+                    half_extents: Vec3::new(2.0, 0.15, 0.5),
+                    surface: SurfaceType::Stone,
+                    slope_normal: None,
+                },
+            ],
+            4.0,
+        )
+    }
+
+    fn grounded() -> PlayerKinematics {
+        PlayerKinematics {
+            grounded: true,
+            ..default()
+        }
+    }
+
+    #[test]
+    fn falling_capsule_lands_on_the_floor() {
+        let grid = floor_with_step();
+        let mut kinematics = PlayerKinematics::default();
+        let mut position = Vec3::new(0.0, 3.0, 3.0);
+        for _ in 0..120 {
+            position = move_kinematic(
+                position,
+                COLLIDER,
+                Vec3::ZERO,
+                None,
+                &mut kinematics,
+                -20.0,
+                1.0 / 60.0,
+                &grid,
+                None,
+            );
+        }
+
+        assert!(kinematics.grounded);
+        assert_eq!(kinematics.vertical_velocity, 0.0);
+        assert!((position.y - COLLIDER.half_height).abs() < 1e-4);
+    }
+
+    #[test]
+    fn walking_into_a_low_ledge_steps_up() {
+        let grid = floor_with_step();
+        let mut kinematics = grounded();
+        let start = Vec3::new(0.0, COLLIDER.half_height, -0.1);
+        let position = move_kinematic(
+            start,
+            COLLIDER,
+            Vec3::new(0.0, 0.0, -0.2),
+            None,
+            &mut kinematics,
+            -20.0,
+            1.0 / 60.0,
+            &grid,
+            None,
+        );
+
+        assert!(position.z < start.z - 0.1);
+        assert!((position.y - (0.3 + COLLIDER.half_height)).abs() < 0.05);
+    }
+
+    #[test]
+    fn jumping_into_a_ledge_still_steps_up_first() {
+        let grid = floor_with_step();
+        let mut kinematics = grounded();
+        let start = Vec3::new(0.0, COLLIDER.half_height, -0.1);
+        let position = move_kinematic(
+            start,
+            COLLIDER,
+            Vec3::new(0.0, 0.0, -0.2),
+            Some(7.5),
+            &mut kinematics,
+            -20.0,
+            1.0 / 60.0,
+            &grid,
+            None,
+        );
+
+        assert!(position.z < start.z - 0.1);
+        assert!(position.y > 0.3 + COLLIDER.half_height);
+        assert!(!kinematics.grounded);
+        assert!(kinematics.vertical_velocity > 0.0);
+    }
+
+    #[test]
+    fn crouch_keeps_the_feet_planted_and_stays_down_under_a_ceiling() {
+        let grid = WorldCollisionGrid::from_colliders(
+            vec![
+                StaticCollider {
+                    center: Vec3::new(0.0, -0.5, 0.0),
+                    half_extents: Vec3::new(10.0, 0.5, 10.0),
+                    surface: SurfaceType::Grass,
+                    slope_normal: None,
+                },
+                StaticCollider {
+                    center: Vec3::new(0.0, 1.5, 3.0),
+                    half_extents: Vec3::new(1.0, 0.1, 1.0),
+                    surface: SurfaceType::Stone,
+                    slope_normal: None,
+                },
+            ],
+            4.0,
+        );
+        let mut collider = COLLIDER;
+        let mut position = Vec3::new(0.0, COLLIDER.half_height, 3.0);
+        for _ in 0..30 {
+            update_crouch_height(&mut position, &mut collider, CROUCH_HALF_HEIGHT, 0.1, &grid);
+        }
+        assert_eq!(collider.half_height, CROUCH_HALF_HEIGHT);
+        assert!((position.y - CROUCH_HALF_HEIGHT).abs() < 1e-4);
+
+        // The slab's underside at 1.4 m leaves room to rise only part of the way.
+        for _ in 0..30 {
+            update_crouch_height(
+                &mut position,
+                &mut collider,
+                COLLIDER.half_height,
+                0.1,
+                &grid,
+            );
+        }
+        assert!(collider.half_height < COLLIDER.half_height);
+        assert!((position.y - collider.half_height).abs() < 1e-4);
+
+        position.z = -3.0;
+        for _ in 0..30 {
+            update_crouch_height(
+                &mut position,
+                &mut collider,
+                COLLIDER.half_height,
+                0.1,
+                &grid,
+            );
+        }
+        assert_eq!(collider.half_height, COLLIDER.half_height);
+    }
+
+    #[test]
+    fn sliding_into_a_ledge_reports_its_normal() {
+        let grid = floor_with_step();
+        let (position, wall_normal) = move_with_slide(
+            Vec3::new(0.0, COLLIDER.half_height, -0.05),
+            Vec3::new(0.1, 0.0, -0.2),
+            COLLIDER,
+            &grid,
+            CONTROLLER_MAX_SLIDES,
+            CONTROLLER_SKIN,
+            None,
+        );
+
+        assert_eq!(wall_normal, Some(Vec2::Y));
+        assert!(position.x > 0.05);
+
+        let (_, open_normal) = move_with_slide(
+            Vec3::new(0.0, COLLIDER.half_height, 3.0),
+            Vec3::new(0.0, 0.0, 0.2),
+            COLLIDER,
+            &grid,
+            CONTROLLER_MAX_SLIDES,
+            CONTROLLER_SKIN,
+            None,
+        );
+        assert_eq!(open_normal, None);
+    }
+
+    #[test]
+    fn overlapping_capsule_is_pushed_out_of_a_box() {
+        let box_half = Vec3::new(0.32, 0.9, 0.32);
+        let pushed = push_out_of_box_xz(
+            Vec3::new(0.5, COLLIDER.half_height, 0.0),
+            COLLIDER,
+            Vec3::new(0.0, 0.9, 0.0),
+            box_half,
+        );
+
+        assert!((pushed.x - (box_half.x + COLLIDER.radius)).abs() < 1e-5);
+        assert_eq!(pushed.z, 0.0);
+
+        let untouched = Vec3::new(2.0, COLLIDER.half_height, 0.0);
+        assert_eq!(
+            push_out_of_box_xz(untouched, COLLIDER, Vec3::new(0.0, 0.9, 0.0), box_half),
+            untouched
+        );
+    }
+
+    #[test]
+    fn capped_hitch_does_not_tunnel_through_the_floor() {
+        let grid = floor_with_step();
+        let debug = DebugSettings::default();
+        let dt = capped_delta_secs(3.0, &debug);
+        assert_eq!(dt, debug.max_delta_secs);
+
+        let mut kinematics = PlayerKinematics {
+            vertical_velocity: -30.0,
+            ..default()
+        };
+        let start = Vec3::new(0.0, COLLIDER.half_height + 0.5, 3.0);
+        let position = move_kinematic(
+            start,
+            COLLIDER,
+            Vec3::new(9.5 * dt, 0.0, 0.0),
+            None,
+            &mut kinematics,
+            -20.0,
+            dt,
+            &grid,
+            None,
+        );
+
+        assert!(kinematics.grounded);
+        assert!((position.y - COLLIDER.half_height).abs() < 1e-4);
+        assert!((position.x - start.x).abs() <= 9.5 * debug.max_delta_secs + 1e-4);
+    }
+
+    #[test]
+    fn jumps_are_buffered_and_forgiven_just_after_leaving_the_ground() {
+        let dt = 1.0 / 60.0;
+        let mut kinematics = grounded();
+        assert!(!buffered_jump(&mut kinematics, false, dt));
+
+        // Ran off an edge a few frames ago: still inside the coyote window.
+        kinematics.grounded = false;
+        for _ in 0..3 {
+            assert!(!buffered_jump(&mut kinematics, false, dt));
+        }
+        assert!(buffered_jump(&mut kinematics, true, dt));
+        assert!(kinematics.grounded);
+
+        // The jump used the window up, so a second press in the air does nothing.
+        kinematics.grounded = false;
+        assert!(!buffered_jump(&mut kinematics, true, dt));
+
+        // That press is remembered and fires on the next grounded frame, but not later.
+        kinematics.grounded = true;
+        assert!(buffered_jump(&mut kinematics, false, dt));
+        kinematics.grounded = false;
+        assert!(!buffered_jump(&mut kinematics, true, 0.2));
+        kinematics.grounded = true;
+        assert!(!buffered_jump(&mut kinematics, false, 0.2));
+    }
+
+    /// Floor plus a ramp rising toward +X from x = 0 to x = 4.
+    fn floor_with_ramp(angle_deg: f32) -> (WorldCollisionGrid, StaticCollider) {
+        let angle = angle_deg.to_radians();
+        let half_rise = 2.0 * angle.tan();
+        let ramp = StaticCollider {
+            center: Vec3::new(2.0, half_rise, 0.0),
+            half_extents: Vec3::new(2.0, half_rise, 1.5),
+            surface: SurfaceType::Stone,
+            slope_normal: Some(Vec3::new(-angle.sin(), angle.cos(), 0.0)),
+        };
+        let floor = StaticCollider {
+            center: Vec3::new(0.0, -0.5, 0.0),
+            half_extents: Vec3::new(10.0, 0.5, 10.0),
+            surface: SurfaceType::Grass,
+            slope_normal: None,
+        };
+        (
+            WorldCollisionGrid::from_colliders(vec![floor, ramp], 4.0),
+            ramp,
+        )
+    }
+
+    #[test]
+    fn walking_up_and_down_a_30_degree_ramp_stays_on_its_surface() {
+        let (grid, ramp) = floor_with_ramp(30.0);
+        let dt = 1.0 / 60.0;
+        let mut kinematics = grounded();
+        let mut position = Vec3::new(-1.0, COLLIDER.half_height, 0.0);
+        let mut walk = |position: &mut Vec3, step_x: f32| {
+            *position = move_kinematic(
+                *position,
+                COLLIDER,
+                Vec3::new(step_x, 0.0, 0.0),
+                None,
+                &mut kinematics,
+                -20.0,
+                dt,
+                &grid,
+                None,
+            );
+            assert!(kinematics.grounded, "lost the ground at {position}");
+            let surface = ramp.top_at(position.x, position.z).max(0.0);
+            assert!((position.y - COLLIDER.half_height - surface).abs() < 1e-3);
+        };
+
+        for _ in 0..40 {
+            walk(&mut position, 0.09);
+        }
+        assert!(position.x > 2.5);
+        assert!(position.y > COLLIDER.half_height + 1.4);
+
+        for _ in 0..50 {
+            walk(&mut position, -0.09);
+        }
+        assert!(position.x < -0.5);
+        assert!((position.y - COLLIDER.half_height).abs() < 1e-3);
+    }
+
+    #[test]
+    fn steep_ramps_cannot_be_climbed_and_slide_the_player_down() {
+        let (grid, ramp) = floor_with_ramp(60.0);
+        let dt = 1.0 / 60.0;
+        let mut kinematics = grounded();
+        let mut position = Vec3::new(-1.0, COLLIDER.half_height, 0.0);
+        for _ in 0..60 {
+            position = move_kinematic(
+                position,
+                COLLIDER,
+                Vec3::new(0.09, 0.0, 0.0),
+                None,
+                &mut kinematics,
+                -20.0,
+                dt,
+                &grid,
+                None,
+            );
+        }
+        assert!(position.x < 0.2, "{position}");
+        assert!((position.y - COLLIDER.half_height).abs() < 1e-3);
+
+        let mut kinematics = PlayerKinematics::default();
+        let mut position = Vec3::new(2.0, ramp.top_at(2.0, 0.0) + COLLIDER.half_height, 0.0);
+        for _ in 0..120 {
+            let slide = kinematics.horizontal_velocity * dt;
+            position = move_kinematic(
+                position,
+                COLLIDER,
+                Vec3::new(slide.x, 0.0, slide.y),
+                None,
+                &mut kinematics,
+                -20.0,
+                dt,
+                &grid,
+                None,
+            );
+        }
+        assert!(position.x < 0.5, "{position}");
+        assert!(position.y < COLLIDER.half_height + 0.9);
+    }
+
+    #[test]
+    fn jump_is_ignored_while_airborne() {
+        let grid = floor_with_step();
+        let mut kinematics = PlayerKinematics::default();
+        move_kinematic(
+            Vec3::new(0.0, 3.0, 3.0),
+            COLLIDER,
+            Vec3::ZERO,
+            Some(7.5),
+            &mut kinematics,
+            -20.0,
+            1.0 / 60.0,
+            &grid,
+            None,
+        );
+
+        assert!(kinematics.vertical_velocity < 0.0);
+    }
+
+    #[test]
+    fn apex_hang_time_lengthens_the_jump_but_barely_raises_it() {
+        let grid = floor_with_step();
+        let jump = |hang_factor: f32| {
+            let mut kinematics = grounded();
+            let mut position = Vec3::new(0.0, COLLIDER.half_height, 3.0);
+            let (mut airtime, mut peak) = (0.0, position.y);
+            for step in 0..600 {
+                let gravity = apex_hang_gravity(-20.0, &kinematics, hang_factor, 1.5);
+                position = move_kinematic(
+                    position,
+                    COLLIDER,
+                    Vec3::ZERO,
+                    (step == 0).then_some(7.5),
+                    &mut kinematics,
+                    gravity,
+                    1.0 / 240.0,
+                    &grid,
+                    None,
+                );
+                if kinematics.grounded {
+                    break;
+                }
+                airtime += 1.0 / 240.0;
+                peak = peak.max(position.y);
+            }
+            (airtime, peak - COLLIDER.half_height)
+        };
+
+        let (plain_airtime, plain_height) = jump(1.0);
+        let (hang_airtime, hang_height) = jump(0.5);
+        assert!(hang_airtime > plain_airtime + 0.1);
+        assert!((hang_height - plain_height).abs() < plain_height * 0.1);
+    }
+
+    #[test]
+    fn ground_snap_keeps_the_player_grounded_down_every_stair_lane() {
+        for (rise, run) in STAIR_PROFILES {
+            let mut colliders = vec![StaticCollider {
+                center: Vec3::new(0.0, -0.5, 0.0),
+                half_extents: Vec3::new(20.0, 0.5, 20.0),
+                surface: SurfaceType::Grass,
+                slope_normal: None,
+            }];
+            colliders.extend(
+                stair_treads(0.0, 0.0, rise, run).map(|(center, half_extents)| StaticCollider {
+                    center,
+                    half_extents,
+                    surface: SurfaceType::Stone,
+                    slope_normal: None,
+                }),
+            );
+            let grid = WorldCollisionGrid::from_colliders(colliders, 4.0);
+            let (top_center, top_half) = stair_treads(0.0, 0.0, rise, run).last().unwrap();
+
+            let descend = |snap_distance: f32| {
+                let mut kinematics = grounded();
+                let mut position = Vec3::new(
+                    0.0,
+                    top_center.y + top_half.y + COLLIDER.half_height,
+                    top_center.z,
+                );
+                let mut airborne_frames = 0;
+                for _ in 0..180 {
+                    position = move_kinematic(
+                        position,
+                        COLLIDER,
+                        Vec3::new(0.0, 0.0, -5.5 / 60.0),
+                        None,
+                        &mut kinematics,
+                        -20.0,
+                        1.0 / 60.0,
+                        &grid,
+                        None,
+                    );
+                    position =
+                        snap_to_ground(position, COLLIDER, &mut kinematics, &grid, snap_distance);
+                    airborne_frames += usize::from(!kinematics.grounded);
+                }
+                (airborne_frames, position.y - COLLIDER.half_height)
+            };
+
+            let (snapped_airborne, snapped_feet) = descend(0.4);
+            assert_eq!(snapped_airborne, 0, "rise {rise} run {run}");
+            assert!(snapped_feet.abs() < 1e-3, "rise {rise} run {run}");
+            let (unsnapped_airborne, _) = descend(0.0);
+            assert!(unsnapped_airborne > 0, "rise {rise} run {run}");
+        }
+    }
+
+    #[test]
+    fn wrapped_yaw_stays_bounded_under_large_deltas() {
+        let mut yaw = 0.0_f32;
+        for i in 0..100_000 {
+            let delta = if i % 3 == 0 { -7.3 } else { 12.9 };
+            let unwrapped = yaw - delta;
+            yaw = wrap_angle(unwrapped);
+            assert!(yaw > -std::f32::consts::PI && yaw <= std::f32::consts::PI);
+            // Wrapping keeps the direction the unwrapped yaw points in.
+            assert!((yaw.sin() - unwrapped.sin()).abs() < 1e-4);
+            assert!((yaw.cos() - unwrapped.cos()).abs() < 1e-4);
+        }
+    }
+
+    /// Tower, walls and crate columns laid out the way `spawn_scenario_world` does.
+    fn scenario_collision_grid(scenario: &ScenarioDefinition) -> WorldCollisionGrid {
+        let stone = |center, half_extents| StaticCollider {
+            center,
+            half_extents,
+            surface: SurfaceType::Stone,
+            slope_normal: None,
+        };
+        let tower_half = scenario.tower_half_extents();
+        let wall_half = scenario.wall_half_extents();
+        let crate_half = scenario.crate_half_extents();
+        let mut colliders = vec![stone(
+            Vec3::new(0.0, tower_half.y, scenario.tower_z),
+            tower_half,
+        )];
+        for i in -scenario.wall_count..=scenario.wall_count {
+            colliders.push(stone(
+                Vec3::new(
+                    i as f32 * scenario.wall_spacing,
+                    wall_half.y,
+                    scenario.wall_z,
+                ),
+                wall_half,
+            ));
+        }
+        for (x, z) in scenario.crate_cells() {
+            let levels = scenario.crate_stack_levels(x, z) as f32;
+            let column_half = Vec3::new(crate_half.x, crate_half.y * levels, crate_half.z);
+            colliders.push(stone(
+                Vec3::new(
+                    x as f32 * scenario.crate_spacing,
+                    column_half.y,
+                    z as f32 * scenario.crate_spacing,
+                ),
+                column_half,
+            ));
+        }
+        WorldCollisionGrid::from_colliders(colliders, 4.0)
+    }
+
+    #[test]
+    fn raycasts_hit_the_tower_and_crates_at_their_faces() {
+        let scenario = default_scenarios().remove(0);
+        let grid = scenario_collision_grid(&scenario);
+        let tower_half = scenario.tower_half_extents();
+        let crate_half = scenario.crate_half_extents();
+
+        // Over the walls and crates, straight at the tower's near face.
+        let above_cover = scenario.wall_half_extents().y * 2.0 + 1.0;
+        assert!(above_cover < tower_half.y * 2.0);
+        let hit = grid
+            .raycast(Vec3::new(0.0, above_cover, 0.0), Vec3::NEG_Z, 100.0)
+            .expect("tower in line of sight");
+        let tower_face = -(scenario.tower_z + tower_half.z);
+        assert!((hit.distance - tower_face).abs() < 1e-4);
+        assert!(hit.normal.abs_diff_eq(Vec3::Z, 1e-6));
+
+        // At crate height the nearest column on the -Z axis is hit first.
+        let nearest_z = scenario
+            .crate_cells()
+            .filter(|&(x, z)| x == 0 && z < 0)
+            .map(|(_, z)| z)
+            .max()
+            .expect("a crate column on the -Z axis");
+        let crate_face = -(nearest_z as f32 * scenario.crate_spacing) - crate_half.z;
+        let origin = Vec3::new(0.0, crate_half.y, 0.0);
+        let hit = grid.raycast(origin, Vec3::NEG_Z, 100.0).unwrap();
+        assert!((hit.distance - crate_face).abs() < 1e-4);
+        assert!(
+            grid.raycast(origin, Vec3::NEG_Z, crate_face - 0.1)
+                .is_none()
+        );
+
+        // Straight down onto the top of a column.
+        let (x, z) = scenario.crate_cells().next().unwrap();
+        let top = 2.0 * crate_half.y * scenario.crate_stack_levels(x, z) as f32;
+        let above = Vec3::new(
+            x as f32 * scenario.crate_spacing,
+            top + 5.0,
+            z as f32 * scenario.crate_spacing,
+        );
+        let hit = grid.raycast(above, Vec3::NEG_Y, 10.0).unwrap();
+        assert!((hit.distance - 5.0).abs() < 1e-4);
+        assert!(hit.normal.abs_diff_eq(Vec3::Y, 1e-6));
+
+        assert!(grid.raycast(above, Vec3::Y, 100.0).is_none());
+    }
+
+    #[test]
+    fn camera_snaps_in_front_of_a_wall_and_eases_back_out_once_clear() {
+        let wall = StaticCollider {
+            center: Vec3::new(0.0, 1.5, 4.0),
+            half_extents: Vec3::new(3.0, 1.5, 0.25),
+            surface: SurfaceType::Stone,
+            slope_normal: None,
+        };
+        let grid = WorldCollisionGrid::from_colliders(vec![wall], 4.0);
+        let focus = Vec3::new(0.0, 1.5, 0.0);
+        let desired = Vec3::new(0.0, 1.5, 8.0);
+        let dt = 1.0 / 60.0;
+
+        let clear = camera_clear_distance(focus, desired, &grid);
+        let wall_face = 3.75 - CAMERA_COLLISION_RADIUS - CAMERA_COLLISION_SKIN;
+        assert!((clear - wall_face).abs() < 1e-4);
+        let mut held = eased_collision_distance(f32::INFINITY, clear, 8.0, dt);
+        assert_eq!(held, clear);
+
+        // The wall is gone: the camera moves out gradually, never jumping straight back.
+        let mut previous = held;
+        for _ in 0..10 {
+            held = eased_collision_distance(held, 8.0, 8.0, dt);
+            assert!(held > previous && held < 8.0);
+            previous = held;
+        }
+        for _ in 0..600 {
+            held = eased_collision_distance(held, 8.0, 8.0, dt);
+        }
+        assert_eq!(held, f32::INFINITY);
+        assert_eq!(
+            camera_clear_distance(focus, desired, &WorldCollisionGrid::default()),
+            8.0
+        );
+    }
+}
@@ -1,8 +1,10 @@
 use super::*;
+use std::collections::HashMap;
 
 pub(super) fn parse_cli_options() -> CliOptions {
     let mut options = CliOptions::default();
     let mut args = env::args().skip(1);
+    let mut scenarios_paths = Vec::new();
 
     while let Some(arg) = args.next() {
         match arg.as_str() {
@@ -18,7 +20,34 @@ pub(super) fn parse_cli_options() -> CliOptions {
                     eprintln!("{arg} verwacht een pad");
                     print_cli_help_and_exit(2);
                 };
-                options.scenarios_path = value;
+                scenarios_paths.extend(
+                    value
+                        .split(',')
+                        .map(str::trim)
+                        .filter(|path| !path.is_empty())
+                        .map(PathBuf::from),
+                );
+            }
+            "--benchmark" => {
+                let Some(value) = args.next() else {
+                    eprintln!("--benchmark verwacht een scenario-id");
+                    print_cli_help_and_exit(2);
+                };
+                options.scenario_id = Some(value.clone());
+                options.benchmark = Some(value);
+            }
+            "--validate" => {
+                options.validate = true;
+            }
+            "--headless" => {
+                options.headless = true;
+            }
+            "--steps" => {
+                let Some(steps) = args.next().and_then(|value| value.parse().ok()) else {
+                    eprintln!("--steps verwacht een aantal stappen");
+                    print_cli_help_and_exit(2);
+                };
+                options.headless_steps = steps;
             }
             "--help" | "-h" => {
                 print_cli_help_and_exit(0);
@@ -30,12 +59,15 @@ pub(super) fn parse_cli_options() -> CliOptions {
         }
     }
 
+    if !scenarios_paths.is_empty() {
+        options.scenarios_paths = scenarios_paths;
+    }
     options
 }
 
 pub(super) fn print_cli_help_and_exit(code: i32) -> ! {
     println!(
-        "Gebruik:\n  haemwend [opties]\n\nOpties:\n  -s, --scenario <id>         Start direct met scenario-id\n      --scenarios-dir <pad>   Map met scenario-bestanden (1 .ron per scenario)\n      --scenarios-path <pad>  Alias voor --scenarios-dir\n      --scenarios-file <pad>  Legacy alias (ondersteunt ook 1 bestand)\n  -h, --help                  Toon hulp"
+        "Gebruik:\n  haemwend [opties]\n\nOpties:\n  -s, --scenario <id>         Start direct met scenario-id\n      --scenarios-dir <pad>   Map met scenario-bestanden (1 .ron per scenario); herhaal of\n                              scheid met komma's, latere mappen overschrijven gelijke id's\n      --scenarios-path <pad>  Alias voor --scenarios-dir\n      --scenarios-file <pad>  Legacy alias (ondersteunt ook 1 bestand)\n      --validate              Controleer de scenario's en stop (exitcode 1 bij fouten)\n      --benchmark <id>        Vlieg 30 s door een scenario, log de FPS en stop\n      --headless              Draai alleen de simulatie, zonder venster of GPU\n      --steps <n>             Aantal simulatiestappen bij --headless (0 = tot stoppen)\n  -h, --help                  Toon hulp"
     );
     std::process::exit(code);
 }
@@ -44,8 +76,14 @@ pub(super) fn default_scenarios() -> Vec<ScenarioDefinition> {
     vec![
         ScenarioDefinition {
             id: "greenwood".to_string(),
+            base: None,
             name: "Greenwood Valley".to_string(),
             description: "Open veld met verspreide kratten en muursegmenten.".to_string(),
+            localized_name: HashMap::new(),
+            localized_description: HashMap::from([(
+                "en".to_string(),
+                "Open field with scattered crates and wall segments.".to_string(),
+            )]),
             ground_extent: 120.0,
             crate_grid_radius: 8,
             crate_spacing: 3.0,
@@ -55,11 +93,27 @@ pub(super) fn default_scenarios() -> Vec<ScenarioDefinition> {
             wall_z: -20.0,
             tower_z: -30.0,
             sun_position: [18.0, 24.0, 12.0],
+            crate_size: None,
+            wall_size: None,
+            tower_size: None,
+            crate_stack_height: 1,
+            crate_palette_size: 3,
+            target_dummy_position: None,
+            dummy_patrol_waypoints: Vec::new(),
+            dummy_patrol_speed: 2.0,
+            spawn_position: [0.0, 0.0, 0.0],
+            spawn_yaw: None,
         },
         ScenarioDefinition {
             id: "arena".to_string(),
+            base: None,
             name: "Iron Arena".to_string(),
             description: "Compacte arena met dichter op elkaar staande obstakels.".to_string(),
+            localized_name: HashMap::new(),
+            localized_description: HashMap::from([(
+                "en".to_string(),
+                "Compact arena with tightly packed obstacles.".to_string(),
+            )]),
             ground_extent: 80.0,
             crate_grid_radius: 6,
             crate_spacing: 2.6,
@@ -69,11 +123,27 @@ pub(super) fn default_scenarios() -> Vec<ScenarioDefinition> {
             wall_z: -16.0,
             tower_z: -24.0,
             sun_position: [14.0, 20.0, 10.0],
+            crate_size: None,
+            wall_size: None,
+            tower_size: None,
+            crate_stack_height: 1,
+            crate_palette_size: 3,
+            target_dummy_position: None,
+            dummy_patrol_waypoints: vec![[-2.0, 0.0, -2.6], [2.0, 0.0, -2.6]],
+            dummy_patrol_speed: 2.0,
+            spawn_position: [0.0, 0.0, 0.0],
+            spawn_yaw: None,
         },
         ScenarioDefinition {
             id: "canyon".to_string(),
+            base: None,
             name: "Red Canyon".to_string(),
             description: "Langgerekte map met pilaren en sterke dieptewerking.".to_string(),
+            localized_name: HashMap::new(),
+            localized_description: HashMap::from([(
+                "en".to_string(),
+                "Long map with pillars and a strong sense of depth.".to_string(),
+            )]),
             ground_extent: 180.0,
             crate_grid_radius: 10,
             crate_spacing: 3.4,
@@ -83,12 +153,28 @@ pub(super) fn default_scenarios() -> Vec<ScenarioDefinition> {
             wall_z: -30.0,
             tower_z: -42.0,
             sun_position: [22.0, 30.0, 14.0],
+            crate_size: None,
+            wall_size: None,
+            tower_size: None,
+            crate_stack_height: 1,
+            crate_palette_size: 3,
+            target_dummy_position: None,
+            dummy_patrol_waypoints: Vec::new(),
+            dummy_patrol_speed: 2.0,
+            spawn_position: [0.0, 0.0, 0.0],
+            spawn_yaw: None,
         },
         ScenarioDefinition {
             id: "gauntlet".to_string(),
+            base: None,
             name: "Stone Gauntlet".to_string(),
             description: "Smalle route met dichte obstakels voor korte, intensieve runs."
                 .to_string(),
+            localized_name: HashMap::new(),
+            localized_description: HashMap::from([(
+                "en".to_string(),
+                "Narrow route with dense obstacles for short, intense runs.".to_string(),
+            )]),
             ground_extent: 72.0,
             crate_grid_radius: 5,
             crate_spacing: 2.2,
@@ -98,11 +184,27 @@ pub(super) fn default_scenarios() -> Vec<ScenarioDefinition> {
             wall_z: -14.0,
             tower_z: -20.0,
             sun_position: [12.0, 18.0, 8.0],
+            crate_size: None,
+            wall_size: None,
+            tower_size: None,
+            crate_stack_height: 1,
+            crate_palette_size: 3,
+            target_dummy_position: None,
+            dummy_patrol_waypoints: Vec::new(),
+            dummy_patrol_speed: 2.0,
+            spawn_position: [0.0, 0.0, 0.0],
+            spawn_yaw: None,
         },
         ScenarioDefinition {
             id: "highlands".to_string(),
+            base: None,
             name: "Frost Highlands".to_string(),
             description: "Grote open vlakte met weinig dekking en lange zichtlijnen.".to_string(),
+            localized_name: HashMap::new(),
+            localized_description: HashMap::from([(
+                "en".to_string(),
+                "Wide open plain with little cover and long sight lines.".to_string(),
+            )]),
             ground_extent: 240.0,
             crate_grid_radius: 12,
             crate_spacing: 4.2,
@@ -112,6 +214,16 @@ pub(super) fn default_scenarios() -> Vec<ScenarioDefinition> {
             wall_z: -40.0,
             tower_z: -58.0,
             sun_position: [28.0, 35.0, 16.0],
+            crate_size: None,
+            wall_size: None,
+            tower_size: None,
+            crate_stack_height: 1,
+            crate_palette_size: 3,
+            target_dummy_position: None,
+            dummy_patrol_waypoints: Vec::new(),
+            dummy_patrol_speed: 2.0,
+            spawn_position: [0.0, 0.0, 0.0],
+            spawn_yaw: None,
         },
     ]
 }
@@ -125,14 +237,29 @@ pub(super) fn is_ron_file(path: &Path) -> bool {
 pub(super) fn filter_valid_scenarios(
     mut scenarios: Vec<ScenarioDefinition>,
     source: &str,
+    problems: &mut Vec<String>,
 ) -> Vec<ScenarioDefinition> {
     scenarios.retain(|scenario| !scenario.id.trim().is_empty() && !scenario.name.trim().is_empty());
     if scenarios.is_empty() {
-        eprintln!("Scenario-bron ({source}) bevat geen geldige scenario's");
+        problems.push(format!(
+            "Scenario-bron ({source}) bevat geen geldige scenario's"
+        ));
     }
     scenarios
 }
 
+fn log_problems(problems: Vec<String>) {
+    for problem in problems {
+        eprintln!("{problem}");
+    }
+}
+
+/// Top of every generated scenario file, for the fields whose defaults aren't obvious.
+const SCENARIO_FILE_HEADER: &str = "\
+// spawn_position: feet position of the player at spawn, default (0.0, 0.0, 0.0).
+// spawn_yaw: facing at spawn in radians, e.g. Some(1.57); None faces the tower.
+";
+
 pub(super) fn write_default_scenarios_to_dir(path: &Path) -> bool {
     if let Err(err) = fs::create_dir_all(path) {
         eprintln!("Kon scenario-map niet maken ({}): {err}", path.display());
@@ -154,7 +281,7 @@ pub(super) fn write_default_scenarios_to_dir(path: &Path) -> bool {
             }
         };
 
-        if let Err(err) = fs::write(&file_path, serialized) {
+        if let Err(err) = fs::write(&file_path, format!("{SCENARIO_FILE_HEADER}{serialized}")) {
             eprintln!("Kon scenario niet opslaan ({}): {err}", file_path.display());
             return false;
         }
@@ -164,11 +291,11 @@ pub(super) fn write_default_scenarios_to_dir(path: &Path) -> bool {
 }
 
 pub(super) fn write_default_scenarios_to_file(path: &Path) -> bool {
-    if let Some(parent) = path.parent() {
-        if let Err(err) = fs::create_dir_all(parent) {
-            eprintln!("Kon scenario-map niet maken ({}): {err}", parent.display());
-            return false;
-        }
+    if let Some(parent) = path.parent()
+        && let Err(err) = fs::create_dir_all(parent)
+    {
+        eprintln!("Kon scenario-map niet maken ({}): {err}", parent.display());
+        return false;
     }
 
     let serialized =
@@ -180,7 +307,7 @@ pub(super) fn write_default_scenarios_to_file(path: &Path) -> bool {
             }
         };
 
-    if let Err(err) = fs::write(path, serialized) {
+    if let Err(err) = fs::write(path, format!("{SCENARIO_FILE_HEADER}{serialized}")) {
         eprintln!(
             "Kon scenario-bestand niet opslaan ({}): {err}",
             path.display()
@@ -192,37 +319,60 @@ pub(super) fn write_default_scenarios_to_file(path: &Path) -> bool {
 }
 
 pub(super) fn load_scenarios_from_file(path: &Path) -> Vec<ScenarioDefinition> {
+    let mut problems = Vec::new();
+    let scenarios = collect_scenarios_from_file(path, &mut problems);
+    log_problems(problems);
+    scenarios
+}
+
+pub(super) fn collect_scenarios_from_file(
+    path: &Path,
+    problems: &mut Vec<String>,
+) -> Vec<ScenarioDefinition> {
     let source = path.display().to_string();
     let content = match fs::read_to_string(path) {
         Ok(content) => content,
         Err(err) => {
-            eprintln!(
+            problems.push(format!(
                 "Kon scenario-bestand niet lezen ({}): {err}",
                 path.display()
-            );
+            ));
             return Vec::new();
         }
     };
 
-    match ron::from_str::<Vec<ScenarioDefinition>>(&content) {
-        Ok(scenarios) => return filter_valid_scenarios(scenarios, &source),
-        Err(_) => {}
+    if let Ok(scenarios) = ron::from_str::<Vec<ScenarioDefinition>>(&content) {
+        return filter_valid_scenarios(scenarios, &source, problems);
     }
 
     match ron::from_str::<ScenarioDefinition>(&content) {
-        Ok(scenario) => filter_valid_scenarios(vec![scenario], &source),
+        Ok(scenario) => filter_valid_scenarios(vec![scenario], &source, problems),
         Err(err) => {
-            eprintln!("Kon scenario's niet parsen ({source}): {err}");
+            problems.push(format!("Kon scenario's niet parsen ({source}): {err}"));
             Vec::new()
         }
     }
 }
 
 pub(super) fn load_scenarios_from_dir(path: &Path) -> Vec<ScenarioDefinition> {
+    let mut problems = Vec::new();
+    let scenarios = collect_scenarios_from_dir(path, &mut problems);
+    log_problems(problems);
+    scenarios
+}
+
+/// Loads every `.ron` file in `path`, pushing unreadable or invalid files to `problems`.
+pub(super) fn collect_scenarios_from_dir(
+    path: &Path,
+    problems: &mut Vec<String>,
+) -> Vec<ScenarioDefinition> {
     let dir_iter = match fs::read_dir(path) {
         Ok(entries) => entries,
         Err(err) => {
-            eprintln!("Kon scenario-map niet lezen ({}): {err}", path.display());
+            problems.push(format!(
+                "Kon scenario-map niet lezen ({}): {err}",
+                path.display()
+            ));
             return Vec::new();
         }
     };
@@ -240,45 +390,298 @@ pub(super) fn load_scenarios_from_dir(path: &Path) -> Vec<ScenarioDefinition> {
     files.sort();
 
     let mut scenarios = Vec::new();
+    let mut variants = Vec::new();
+    let mut claimed_ids = HashMap::new();
     for file in files {
         let source = file.display().to_string();
         let content = match fs::read_to_string(&file) {
             Ok(content) => content,
             Err(err) => {
-                eprintln!(
+                problems.push(format!(
                     "Kon scenario-bestand niet lezen ({}): {err}",
                     file.display()
-                );
+                ));
                 continue;
             }
         };
 
+        if let Some(variant) = parse_scenario_variant(&content, &source, problems) {
+            if claim_scenario_id(&mut claimed_ids, &variant.id, &source, problems) {
+                variants.push(variant);
+            }
+            continue;
+        }
+
         match ron::from_str::<ScenarioDefinition>(&content) {
             Ok(scenario) => {
                 if scenario.id.trim().is_empty() || scenario.name.trim().is_empty() {
-                    eprintln!("Scenario-bestand ({source}) mist id of naam");
+                    problems.push(format!("Scenario-bestand ({source}) mist id of naam"));
                     continue;
                 }
-                scenarios.push(scenario);
+                if claim_scenario_id(&mut claimed_ids, &scenario.id, &source, problems) {
+                    scenarios.push(scenario);
+                }
             }
             Err(single_err) => match ron::from_str::<Vec<ScenarioDefinition>>(&content) {
                 Ok(list) => {
                     eprintln!(
                         "Scenario-bestand ({source}) bevat een lijst; gebruik bij voorkeur 1 bestand per scenario"
                     );
-                    scenarios.extend(filter_valid_scenarios(list, &source));
+                    for scenario in filter_valid_scenarios(list, &source, problems) {
+                        if claim_scenario_id(&mut claimed_ids, &scenario.id, &source, problems) {
+                            scenarios.push(scenario);
+                        }
+                    }
                 }
                 Err(_) => {
-                    eprintln!("Kon scenario-bestand niet parsen ({source}): {single_err}");
+                    problems.push(format!(
+                        "Kon scenario-bestand niet parsen ({source}): {single_err}"
+                    ));
                 }
             },
         }
     }
 
+    resolve_scenario_variants(&mut scenarios, variants, problems);
     scenarios
 }
 
-pub(super) fn load_scenario_catalog(path: &Path) -> ScenarioCatalog {
+/// Records which file first defined `id`; files sort by name, so the earliest one wins
+/// and later duplicates are reported and dropped.
+fn claim_scenario_id(
+    claimed: &mut HashMap<String, String>,
+    id: &str,
+    source: &str,
+    problems: &mut Vec<String>,
+) -> bool {
+    if let Some(first_source) = claimed.get(id) {
+        problems.push(format!(
+            "Dubbele scenario-id '{id}' in {source}; {first_source} wordt gebruikt"
+        ));
+        return false;
+    }
+    claimed.insert(id.to_string(), source.to_string());
+    true
+}
+
+/// Whether a window can be opened. Only Linux is checked: there, without an X11 or Wayland
+/// display, creating the window would panic.
+pub(super) fn display_available() -> bool {
+    if !cfg!(target_os = "linux") {
+        return true;
+    }
+    ["DISPLAY", "WAYLAND_DISPLAY"]
+        .iter()
+        .any(|name| env::var_os(name).is_some_and(|value| !value.is_empty()))
+}
+
+/// `--validate`: loads the scenarios without opening a window, prints every problem and
+/// returns the process exit code (1 if anything is wrong).
+pub(super) fn validate_scenarios(paths: &[PathBuf]) -> i32 {
+    let mut problems = Vec::new();
+    let mut scenarios = Vec::new();
+    for path in paths {
+        let layer = if path.is_dir() {
+            collect_scenarios_from_dir(path, &mut problems)
+        } else if path.is_file() {
+            collect_scenarios_from_file(path, &mut problems)
+        } else {
+            problems.push(format!("Scenario-pad bestaat niet: {}", path.display()));
+            Vec::new()
+        };
+        merge_scenario_layer(&mut scenarios, layer);
+    }
+
+    for scenario in &scenarios {
+        problems.extend(
+            scenario
+                .validate()
+                .into_iter()
+                .map(|problem| format!("Scenario '{}': {problem}", scenario.id)),
+        );
+    }
+
+    for problem in &problems {
+        eprintln!("{problem}");
+    }
+    println!(
+        "{} scenario's gecontroleerd, {} probleem/problemen",
+        scenarios.len(),
+        problems.len()
+    );
+    if problems.is_empty() { 0 } else { 1 }
+}
+
+/// Reads a scenario file that names a `base`; returns `None` for plain scenario files.
+pub(super) fn parse_scenario_variant(
+    content: &str,
+    source: &str,
+    problems: &mut Vec<String>,
+) -> Option<ScenarioVariant> {
+    let ron::Value::Map(fields) = ron::from_str::<ron::Value>(content).ok()? else {
+        return None;
+    };
+    let base = ron_string_field(&fields, "base")?;
+    let Some(id) = ron_string_field(&fields, "id").filter(|id| !id.trim().is_empty()) else {
+        problems.push(format!(
+            "Scenario-bestand ({source}) heeft een basis maar mist een id"
+        ));
+        return None;
+    };
+
+    Some(ScenarioVariant {
+        source: source.to_string(),
+        id,
+        base,
+        fields,
+    })
+}
+
+fn ron_string_field(fields: &ron::Map, key: &str) -> Option<String> {
+    match fields.get(&ron::Value::String(key.to_string()))? {
+        ron::Value::String(value) => Some(value.clone()),
+        ron::Value::Option(Some(value)) => match value.as_ref() {
+            ron::Value::String(value) => Some(value.clone()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Resolves variants against their bases (which may be variants themselves) and appends
+/// them to `scenarios`. Variants with a missing base or an inheritance cycle are skipped.
+pub(super) fn resolve_scenario_variants(
+    scenarios: &mut Vec<ScenarioDefinition>,
+    variants: Vec<ScenarioVariant>,
+    problems: &mut Vec<String>,
+) {
+    let by_id: HashMap<&str, &ScenarioVariant> = variants
+        .iter()
+        .map(|variant| (variant.id.as_str(), variant))
+        .collect();
+
+    for variant in &variants {
+        let mut chain = Vec::new();
+        if let Err(err) = resolve_scenario_variant(&variant.id, &by_id, scenarios, &mut chain) {
+            problems.push(format!(
+                "Scenario-variant ({}) overgeslagen: {err}",
+                variant.source
+            ));
+        }
+    }
+}
+
+fn resolve_scenario_variant(
+    id: &str,
+    variants: &HashMap<&str, &ScenarioVariant>,
+    scenarios: &mut Vec<ScenarioDefinition>,
+    chain: &mut Vec<String>,
+) -> Result<(), String> {
+    if scenarios.iter().any(|scenario| scenario.id == id) {
+        return Ok(());
+    }
+    if chain.iter().any(|visited| visited == id) {
+        return Err(format!("cyclische basis: {} -> {id}", chain.join(" -> ")));
+    }
+    let Some(variant) = variants.get(id) else {
+        return Err(format!("basis '{id}' niet gevonden"));
+    };
+
+    chain.push(id.to_string());
+    resolve_scenario_variant(&variant.base, variants, scenarios, chain)?;
+    chain.pop();
+
+    let base = scenarios
+        .iter()
+        .find(|scenario| scenario.id == variant.base)
+        .ok_or_else(|| format!("basis '{}' niet gevonden", variant.base))?;
+    let base_value = ron::to_string(base)
+        .ok()
+        .and_then(|content| ron::from_str::<ron::Value>(&content).ok());
+    let Some(ron::Value::Map(mut fields)) = base_value else {
+        return Err(format!(
+            "basis '{}' kon niet worden overgenomen",
+            variant.base
+        ));
+    };
+    // Inherited translations describe the base's texts, so a variant that rewrites a text
+    // without translating it falls back to its own untranslated text.
+    for (text, translations) in [
+        ("name", "localized_name"),
+        ("description", "localized_description"),
+    ] {
+        let key = |field: &str| ron::Value::String(field.to_string());
+        if variant.fields.get(&key(text)).is_some()
+            && variant.fields.get(&key(translations)).is_none()
+        {
+            fields.remove(&key(translations));
+        }
+    }
+    for (key, value) in variant.fields.iter() {
+        fields.insert(key.clone(), value.clone());
+    }
+
+    let scenario = ron::Value::Map(fields)
+        .into_rust::<ScenarioDefinition>()
+        .map_err(|err| format!("ongeldige velden voor '{id}': {err}"))?;
+    if scenario.name.trim().is_empty() {
+        return Err(format!("scenario '{id}' mist een naam"));
+    }
+    scenarios.push(scenario);
+    Ok(())
+}
+
+/// Adds `layer` on top of `scenarios`: a scenario whose id is already present replaces the
+/// earlier one in place, new ids are appended. Returns the replaced ids.
+pub(super) fn merge_scenario_layer(
+    scenarios: &mut Vec<ScenarioDefinition>,
+    layer: Vec<ScenarioDefinition>,
+) -> Vec<String> {
+    let mut replaced = Vec::new();
+    for scenario in layer {
+        match scenarios
+            .iter_mut()
+            .find(|existing| existing.id == scenario.id)
+        {
+            Some(existing) => {
+                replaced.push(scenario.id.clone());
+                *existing = scenario;
+            }
+            None => scenarios.push(scenario),
+        }
+    }
+    replaced
+}
+
+/// Loads and layers every scenario path in order. Only the first path is seeded with the
+/// default scenarios when it is missing or empty; later paths are optional overlays.
+pub(super) fn load_scenario_catalog(paths: &[PathBuf]) -> ScenarioCatalog {
+    let mut scenarios = Vec::new();
+    for (index, path) in paths.iter().enumerate() {
+        let layer = if index == 0 {
+            load_base_scenarios(path)
+        } else if path.is_dir() {
+            load_scenarios_from_dir(path)
+        } else if path.is_file() {
+            load_scenarios_from_file(path)
+        } else {
+            eprintln!("Scenario-pad bestaat niet: {}", path.display());
+            Vec::new()
+        };
+        for id in merge_scenario_layer(&mut scenarios, layer) {
+            println!("Scenario '{id}' overschreven door {}", path.display());
+        }
+    }
+
+    if scenarios.is_empty() {
+        eprintln!("Geen geldige scenario's beschikbaar, gebruik ingebouwde fallback.");
+        scenarios = default_scenarios();
+    }
+
+    ScenarioCatalog { scenarios }
+}
+
+fn load_base_scenarios(path: &Path) -> Vec<ScenarioDefinition> {
     let mut scenarios = if path.exists() {
         if path.is_dir() {
             load_scenarios_from_dir(path)
@@ -299,53 +702,90 @@ pub(super) fn load_scenario_catalog(path: &Path) -> ScenarioCatalog {
         Vec::new()
     };
 
-    if scenarios.is_empty() {
-        if path.is_dir() && write_default_scenarios_to_dir(path) {
-            scenarios = load_scenarios_from_dir(path);
-        }
-    }
-
-    if scenarios.is_empty() {
-        eprintln!("Geen geldige scenario's beschikbaar, gebruik ingebouwde fallback.");
-        scenarios = default_scenarios();
+    if scenarios.is_empty() && path.is_dir() && write_default_scenarios_to_dir(path) {
+        scenarios = load_scenarios_from_dir(path);
     }
-
-    ScenarioCatalog { scenarios }
+    scenarios
 }
 
 pub(super) fn load_persisted_config() -> PersistedConfig {
-    let path = Path::new(CONFIG_PATH);
+    load_persisted_config_from(Path::new(CONFIG_PATH))
+}
 
+/// Loads the config at `path`. A config that parses is copied to `<path>.bak`; one that
+/// doesn't is replaced by that last good backup before falling back to defaults.
+pub(super) fn load_persisted_config_from(path: &Path) -> PersistedConfig {
     let Ok(content) = fs::read_to_string(path) else {
         return PersistedConfig::default();
     };
 
-    match ron::from_str::<PersistedConfig>(&content) {
-        Ok(config) => config,
+    let backup_path = config_backup_path(path);
+    match parse_persisted_config(&content) {
+        Ok(config) => {
+            if let Err(err) = fs::write(&backup_path, &content) {
+                eprintln!(
+                    "Kon config-backup niet opslaan ({}): {err}",
+                    backup_path.display()
+                );
+            }
+            config
+        }
         Err(err) => {
             eprintln!("Kon config niet lezen ({}): {err}", path.display());
-            PersistedConfig::default()
+            let Ok(backup) = fs::read_to_string(&backup_path) else {
+                return PersistedConfig::default();
+            };
+            match parse_persisted_config(&backup) {
+                Ok(config) => {
+                    eprintln!("Config hersteld uit backup ({})", backup_path.display());
+                    if let Err(err) = fs::write(path, &backup) {
+                        eprintln!("Kon config niet herstellen ({}): {err}", path.display());
+                    }
+                    config
+                }
+                Err(err) => {
+                    eprintln!(
+                        "Kon config-backup niet lezen ({}): {err}",
+                        backup_path.display()
+                    );
+                    PersistedConfig::default()
+                }
+            }
         }
     }
 }
 
+fn parse_persisted_config(content: &str) -> ron::error::SpannedResult<PersistedConfig> {
+    let mut config = ron::from_str::<PersistedConfig>(content)?;
+    config.settings.migrate_legacy_fields();
+    Ok(config)
+}
+
+fn config_backup_path(path: &Path) -> PathBuf {
+    let mut backup = path.as_os_str().to_owned();
+    backup.push(".bak");
+    PathBuf::from(backup)
+}
+
 pub(super) fn save_persisted_config(
     settings: &GameSettings,
     keybinds: &GameKeybinds,
     debug: &DebugSettings,
+    last_scenario: &LastScenario,
 ) {
     let persisted = PersistedConfig {
         settings: settings.clone(),
         keybinds: PersistedKeybinds::from_runtime(keybinds),
         debug: debug.clone(),
+        last_scenario_id: last_scenario.0.clone(),
     };
 
     let path = Path::new(CONFIG_PATH);
-    if let Some(parent) = path.parent() {
-        if let Err(err) = fs::create_dir_all(parent) {
-            eprintln!("Kon config-map niet maken ({}): {err}", parent.display());
-            return;
-        }
+    if let Some(parent) = path.parent()
+        && let Err(err) = fs::create_dir_all(parent)
+    {
+        eprintln!("Kon config-map niet maken ({}): {err}", parent.display());
+        return;
     }
 
     let pretty = ron::ser::PrettyConfig::default();
@@ -362,6 +802,57 @@ pub(super) fn save_persisted_config(
     }
 }
 
+/// Writes `keybinds` to a standalone file in the config's keybind format, for sharing.
+pub(super) fn export_keybinds(keybinds: &GameKeybinds, path: &Path) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|err| err.to_string())?;
+    }
+    let serialized = ron::ser::to_string_pretty(
+        &PersistedKeybinds::from_runtime(keybinds),
+        ron::ser::PrettyConfig::default(),
+    )
+    .map_err(|err| err.to_string())?;
+    fs::write(path, serialized).map_err(|err| err.to_string())
+}
+
+/// Reads a shared keybinds file. Every field has to be a known action and every binding
+/// name has to parse, so a typo is reported instead of silently unbinding the action.
+pub(super) fn import_keybinds(path: &Path) -> Result<GameKeybinds, String> {
+    let content = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    let Ok(ron::Value::Map(fields)) = ron::from_str::<ron::Value>(&content) else {
+        return Err("geen geldige keybinds-map".to_string());
+    };
+    let known = ron::to_string(&PersistedKeybinds::from_runtime(&GameKeybinds::default()))
+        .ok()
+        .and_then(|content| ron::from_str::<ron::Value>(&content).ok());
+    let Some(ron::Value::Map(known)) = known else {
+        return Err("kon bekende acties niet bepalen".to_string());
+    };
+
+    for (field, value) in fields.iter() {
+        let ron::Value::String(field) = field else {
+            return Err(format!("ongeldige sleutel {field:?}"));
+        };
+        if known.get(&ron::Value::String(field.clone())).is_none() {
+            return Err(format!("onbekende actie '{field}'"));
+        }
+        let ron::Value::String(names) = value else {
+            return Err(format!("'{field}' is geen tekst"));
+        };
+        if let Some(bad) = names
+            .split('|')
+            .map(str::trim)
+            .find(|name| !name.is_empty() && binding_from_name(name).is_none())
+        {
+            return Err(format!("onbekende toets '{bad}' bij '{field}'"));
+        }
+    }
+
+    ron::from_str::<PersistedKeybinds>(&content)
+        .map(|persisted| persisted.to_runtime())
+        .map_err(|err| err.to_string())
+}
+
 pub(super) fn action_matches_filter(action: GameAction, filter: &str) -> bool {
     if filter.is_empty() {
         return true;
@@ -416,29 +907,111 @@ pub(super) fn keycode_to_filter_char(key: KeyCode) -> Option<char> {
     }
 }
 
-pub(super) fn keycodes_to_names(keys: &[KeyCode]) -> String {
-    keys.iter()
-        .map(|key| keycode_to_name(*key))
+pub(super) fn bindings_to_names(bindings: &[KeyBinding]) -> String {
+    bindings
+        .iter()
+        .map(|binding| binding_to_name(*binding))
         .collect::<Vec<_>>()
         .join("|")
 }
 
-pub(super) fn keycodes_from_names(raw: &str) -> Vec<KeyCode> {
+pub(super) fn bindings_from_names(raw: &str) -> Vec<KeyBinding> {
     let mut out = Vec::new();
     for segment in raw.split('|') {
-        let key_name = segment.trim();
-        if key_name.is_empty() {
+        let binding_name = segment.trim();
+        if binding_name.is_empty() {
             continue;
         }
-        if let Some(key) = keycode_from_name(key_name) {
-            if !out.contains(&key) {
-                out.push(key);
-            }
+        if let Some(binding) = binding_from_name(binding_name)
+            && !out.contains(&binding)
+        {
+            out.push(binding);
         }
     }
     out
 }
 
+pub(super) fn binding_to_name(binding: KeyBinding) -> String {
+    binding
+        .modifiers
+        .iter()
+        .map(|modifier| format!("{}+", modifier.name()))
+        .chain(std::iter::once(input_to_name(binding.input)))
+        .collect()
+}
+
+pub(super) fn binding_from_name(name: &str) -> Option<KeyBinding> {
+    let Some((modifiers, key)) = name.rsplit_once('+') else {
+        return input_from_name(name.trim()).map(|input| KeyBinding {
+            input,
+            modifiers: KeyModifiers::NONE,
+        });
+    };
+
+    let modifiers = modifiers
+        .split('+')
+        .map(|modifier| KeyModifier::from_name(modifier.trim()))
+        .try_fold(KeyModifiers::NONE, |set, modifier| {
+            Some(set.with(modifier?))
+        })?;
+    Some(KeyBinding {
+        input: input_from_name(key.trim())?,
+        modifiers,
+    })
+}
+
+fn input_to_name(input: InputBinding) -> String {
+    match input {
+        InputBinding::Key(key) => keycode_to_name(key),
+        InputBinding::Mouse(button) => mousebutton_to_name(button),
+    }
+}
+
+fn input_from_name(name: &str) -> Option<InputBinding> {
+    keycode_from_name(name)
+        .map(InputBinding::Key)
+        .or_else(|| mousebutton_from_name(name).map(InputBinding::Mouse))
+}
+
+/// Mouse buttons are numbered like most games do: Mouse1-3 are left, right and middle,
+/// Mouse4/Mouse5 the back and forward side buttons. Extra buttons keep their raw id.
+pub(super) fn mousebutton_to_name(button: MouseButton) -> String {
+    match button {
+        MouseButton::Left => "Mouse1".into(),
+        MouseButton::Right => "Mouse2".into(),
+        MouseButton::Middle => "Mouse3".into(),
+        MouseButton::Back => "Mouse4".into(),
+        MouseButton::Forward => "Mouse5".into(),
+        MouseButton::Other(id) => format!("MouseOther{id}"),
+    }
+}
+
+pub(super) fn mousebutton_to_label(button: MouseButton) -> String {
+    match button {
+        MouseButton::Left => "LMB".into(),
+        MouseButton::Right => "RMB".into(),
+        MouseButton::Middle => "MMB".into(),
+        MouseButton::Back => "Mouse 4".into(),
+        MouseButton::Forward => "Mouse 5".into(),
+        MouseButton::Other(id) => format!("Mouse ({id})"),
+    }
+}
+
+pub(super) fn mousebutton_from_name(name: &str) -> Option<MouseButton> {
+    match name {
+        "Mouse1" => Some(MouseButton::Left),
+        "Mouse2" => Some(MouseButton::Right),
+        "Mouse3" => Some(MouseButton::Middle),
+        "Mouse4" => Some(MouseButton::Back),
+        "Mouse5" => Some(MouseButton::Forward),
+        _ => name
+            .strip_prefix("MouseOther")?
+            .parse()
+            .ok()
+            .map(MouseButton::Other),
+    }
+}
+
 pub(super) fn keycode_to_name(key: KeyCode) -> String {
     format!("{key:?}")
 }
@@ -570,3 +1143,229 @@ pub(super) fn keycode_from_name(name: &str) -> Option<KeyCode> {
         _ => None,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variant(id: &str, base: &str, extra: &str) -> ScenarioVariant {
+        let content = format!("(id: \"{id}\", base: Some(\"{base}\"), {extra})");
+        parse_scenario_variant(&content, id, &mut Vec::new()).unwrap()
+    }
+
+    #[test]
+    fn scenario_variants_inherit_from_their_base() {
+        let mut scenarios = default_scenarios();
+        resolve_scenario_variants(
+            &mut scenarios,
+            vec![
+                variant("greenwood_night", "greenwood_dense", "name: \"Night\""),
+                variant("greenwood_dense", "greenwood", "crate_spacing: 1.5"),
+            ],
+            &mut Vec::new(),
+        );
+
+        let night = &scenarios[scenarios.len() - 1];
+        let greenwood = &scenarios[0];
+        assert_eq!(night.id, "greenwood_night");
+        assert_eq!(night.name, "Night");
+        assert_eq!(night.crate_spacing, 1.5);
+        assert_eq!(night.ground_extent, greenwood.ground_extent);
+        assert_eq!(night.base.as_deref(), Some("greenwood_dense"));
+    }
+
+    #[test]
+    fn scenario_texts_fall_back_to_the_untranslated_field() {
+        let mut scenarios = default_scenarios();
+        resolve_scenario_variants(
+            &mut scenarios,
+            vec![
+                variant("greenwood_dense", "greenwood", "crate_spacing: 1.5"),
+                variant(
+                    "greenwood_mist",
+                    "greenwood",
+                    "name: \"Mist\", localized_name: {\"nl\": \"Nevel\"}, description: \"Mistig.\"",
+                ),
+            ],
+            &mut Vec::new(),
+        );
+
+        let greenwood = &scenarios[0];
+        let dense = &scenarios[scenarios.len() - 2];
+        let mist = &scenarios[scenarios.len() - 1];
+        assert_eq!(
+            dense.description_in(Language::English),
+            greenwood.description_in(Language::English)
+        );
+        assert_eq!(mist.name_in(Language::Dutch), "Nevel");
+        assert_eq!(mist.name_in(Language::English), "Mist");
+        assert_eq!(mist.description_in(Language::English), "Mistig.");
+    }
+
+    #[test]
+    fn scenario_variant_cycles_are_skipped() {
+        let mut scenarios = default_scenarios();
+        let builtin = scenarios.len();
+        let mut problems = Vec::new();
+        resolve_scenario_variants(
+            &mut scenarios,
+            vec![
+                variant("loop_a", "loop_b", ""),
+                variant("loop_b", "loop_a", ""),
+                variant("orphan", "missing", ""),
+            ],
+            &mut problems,
+        );
+
+        assert_eq!(scenarios.len(), builtin);
+        assert_eq!(problems.len(), 3);
+    }
+
+    #[test]
+    fn later_scenario_layers_override_earlier_ids_in_place() {
+        let mut scenarios = default_scenarios();
+        let ids: Vec<String> = scenarios.iter().map(|s| s.id.clone()).collect();
+
+        let mut personal = scenarios[0].clone();
+        personal.name = "Personal".to_string();
+        let mut extra = scenarios[0].clone();
+        extra.id = "extra".to_string();
+
+        let replaced = merge_scenario_layer(&mut scenarios, vec![personal, extra]);
+        assert_eq!(replaced, vec![ids[0].clone()]);
+        assert_eq!(scenarios[0].name, "Personal");
+        assert_eq!(scenarios.len(), ids.len() + 1);
+        assert_eq!(scenarios.last().unwrap().id, "extra");
+    }
+
+    #[test]
+    fn generated_scenarios_load_back_with_their_spawn_defaults() {
+        let dir = env::temp_dir().join(format!("haemwend-spawn-defaults-{}", std::process::id()));
+        assert!(write_default_scenarios_to_dir(&dir));
+        let content = fs::read_to_string(dir.join("gauntlet.ron")).unwrap();
+        let mut problems = Vec::new();
+        let scenarios = collect_scenarios_from_dir(&dir, &mut problems);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(content.starts_with(SCENARIO_FILE_HEADER));
+        assert!(problems.is_empty(), "{problems:?}");
+        assert_eq!(scenarios.len(), default_scenarios().len());
+        assert!(scenarios.iter().all(|s| s.spawn_position == [0.0; 3]));
+
+        // Files written before the spawn fields existed still load, facing the tower.
+        let legacy = fs::read_to_string("config/scenarios/gauntlet.ron").unwrap();
+        let gauntlet: ScenarioDefinition = ron::from_str(&legacy).unwrap();
+        assert_eq!(gauntlet.spawn_yaw, None);
+        assert_eq!(gauntlet.spawn_yaw(), 0.0);
+    }
+
+    #[test]
+    fn duplicate_scenario_ids_keep_the_first_file() {
+        let dir = env::temp_dir().join(format!("haemwend-duplicate-ids-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let scenario = |name: &str| {
+            let mut scenario = default_scenarios().remove(0);
+            scenario.name = name.to_string();
+            ron::to_string(&scenario).unwrap()
+        };
+        fs::write(dir.join("a_first.ron"), scenario("First")).unwrap();
+        fs::write(dir.join("b_second.ron"), scenario("Second")).unwrap();
+
+        let mut problems = Vec::new();
+        let scenarios = collect_scenarios_from_dir(&dir, &mut problems);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(scenarios.len(), 1);
+        assert_eq!(scenarios[0].name, "First");
+        assert_eq!(problems.len(), 1);
+        assert!(problems[0].contains("Dubbele scenario-id 'greenwood'"));
+        assert!(problems[0].contains("b_second.ron"));
+        assert!(problems[0].contains("a_first.ron"));
+    }
+
+    #[test]
+    fn corrupt_config_falls_back_to_the_last_good_backup() {
+        let dir = env::temp_dir().join(format!("haemwend-config-backup-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("game_config.ron");
+
+        let mut config = PersistedConfig::default();
+        config.settings.render_scale = 0.5;
+        fs::write(&path, ron::to_string(&config).unwrap()).unwrap();
+        assert_eq!(load_persisted_config_from(&path).settings.render_scale, 0.5);
+        assert!(dir.join("game_config.ron.bak").is_file());
+
+        fs::write(&path, "(settings: (render_scale: ").unwrap();
+        let restored = load_persisted_config_from(&path);
+        let restored_file = fs::read_to_string(&path).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(restored.settings.render_scale, 0.5);
+        assert!(parse_persisted_config(&restored_file).is_ok());
+    }
+
+    #[test]
+    fn binding_names_round_trip() {
+        let bindings = [
+            KeyBinding::plain(KeyCode::KeyW),
+            KeyBinding {
+                input: InputBinding::Key(KeyCode::KeyW),
+                modifiers: KeyModifiers::NONE.with(KeyModifier::Shift),
+            },
+            KeyBinding {
+                input: InputBinding::Key(KeyCode::F5),
+                modifiers: KeyModifiers::NONE
+                    .with(KeyModifier::Ctrl)
+                    .with(KeyModifier::Shift)
+                    .with(KeyModifier::Alt),
+            },
+            KeyBinding {
+                input: InputBinding::Mouse(MouseButton::Back),
+                modifiers: KeyModifiers::NONE,
+            },
+            KeyBinding {
+                input: InputBinding::Mouse(MouseButton::Other(9)),
+                modifiers: KeyModifiers::NONE.with(KeyModifier::Ctrl),
+            },
+        ];
+
+        for binding in bindings {
+            let name = binding_to_name(binding);
+            assert_eq!(binding_from_name(&name), Some(binding), "{name}");
+        }
+        assert_eq!(binding_to_name(bindings[2]), "Shift+Ctrl+Alt+F5");
+        assert_eq!(binding_to_name(bindings[3]), "Mouse4");
+        assert_eq!(bindings_from_names("Mouse4|MouseOtherX"), vec![bindings[3]]);
+    }
+
+    #[test]
+    fn shared_keybinds_round_trip_and_reject_typos() {
+        let dir = env::temp_dir().join(format!("haemwend-shared-keys-{}", std::process::id()));
+        let path = dir.join("shared_keybinds.ron");
+
+        let mut keybinds = GameKeybinds::default();
+        keybinds.apply_classic_wasd();
+        export_keybinds(&keybinds, &path).unwrap();
+        let imported = import_keybinds(&path).unwrap();
+        for action in ACTION_ORDER {
+            assert_eq!(imported.keys_for(action), keybinds.keys_for(action));
+        }
+
+        fs::write(&path, "(jump: \"Spcae\")").unwrap();
+        assert!(import_keybinds(&path).unwrap_err().contains("Spcae"));
+        fs::write(&path, "(jupm: \"Space\")").unwrap();
+        assert!(import_keybinds(&path).unwrap_err().contains("jupm"));
+        fs::write(&path, "not ron").unwrap();
+        assert!(import_keybinds(&path).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn binding_names_reject_unknown_parts() {
+        assert_eq!(binding_from_name("Hyper+KeyW"), None);
+        assert_eq!(binding_from_name("Shift+Meta+KeyW"), None);
+        assert_eq!(binding_from_name("Shift+NotAKey"), None);
+        assert_eq!(binding_from_name("+KeyW"), None);
+    }
+}
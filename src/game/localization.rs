@@ -0,0 +1,93 @@
+use super::settings::Language;
+
+/// UI strings by id: (id, Dutch, English).
+const STRINGS: &[(&str, &str, &str)] = &[
+    ("start.title", "Selecteer Scenario", "Select Scenario"),
+    ("start.start", "Start", "Start"),
+    ("start.quick_start", "Snel starten", "Quick Start"),
+    ("start.continue", "Doorgaan", "Continue"),
+    ("start.exit", "Afsluiten", "Exit"),
+    (
+        "start.stats_on",
+        "Scenariostatistieken: aan",
+        "Scenario Stats: On",
+    ),
+    (
+        "start.stats_off",
+        "Scenariostatistieken: uit",
+        "Scenario Stats: Off",
+    ),
+    (
+        "start.stats",
+        "Colliders: ~{total} (kratten {crates}, muren {walls}, treden {stairs}, objecten {props})",
+        "Colliders: ~{total} (crates {crates}, walls {walls}, stairs {stairs}, props {props})",
+    ),
+    ("menu.title", "Spelmenu", "Game Menu"),
+    ("menu.settings", "Instellingen", "Settings"),
+    ("menu.debug", "Debug", "Debug"),
+    ("menu.keybinds", "Toetsen", "Keybinds"),
+    ("menu.resume", "Verder", "Resume"),
+    ("menu.back", "Terug", "Back"),
+    ("menu.exit", "Afsluiten", "Exit"),
+    (
+        "menu.to_main_menu",
+        "Terug naar hoofdmenu",
+        "Back to main menu",
+    ),
+    ("menu.quit_title", "Afsluiten", "Quit"),
+    ("menu.language", "Taal", "Language"),
+    (
+        "confirm.to_main_menu",
+        "Terug naar het hoofdmenu?",
+        "Return to the main menu?",
+    ),
+    ("confirm.exit", "Weet je het zeker?", "Are you sure?"),
+    ("confirm.yes_main_menu", "Ja, hoofdmenu", "Yes, main menu"),
+    ("confirm.yes_exit", "Ja, afsluiten", "Yes, exit"),
+    ("confirm.no_back", "Nee, terug", "No, go back"),
+    (
+        "confirm.quit",
+        "Het spel afsluiten? De huidige run gaat verloren.",
+        "Quit the game? The current run will be lost.",
+    ),
+    ("confirm.yes_quit", "Ja, afsluiten", "Yes, quit"),
+    ("confirm.no_continue", "Nee, doorspelen", "No, keep playing"),
+    (
+        "notice.scenario_not_found",
+        "Scenario '{id}' niet gevonden. Beschikbaar: {available}",
+        "Scenario '{id}' not found. Available: {available}",
+    ),
+    (
+        "notice.keybinds_exported",
+        "Toetsen geëxporteerd naar {path}",
+        "Keybinds exported to {path}",
+    ),
+    (
+        "notice.keybinds_imported",
+        "Toetsen geïmporteerd uit {path}",
+        "Keybinds imported from {path}",
+    ),
+    (
+        "notice.keybinds_failed",
+        "{path}: {error}",
+        "{path}: {error}",
+    ),
+    (
+        "notice.scenario_invalid",
+        "Scenario {id} kon niet worden geladen. Beschikbaar: {available}",
+        "Scenario {id} could not be loaded. Available: {available}",
+    ),
+];
+
+/// Looks up `id` in the current language; unknown ids come back unchanged so a missing
+/// entry shows up on screen instead of an empty label.
+pub(super) fn tr(language: Language, id: &'static str) -> &'static str {
+    STRINGS
+        .iter()
+        .find(|(key, _, _)| *key == id)
+        .map(|(_, nl, en)| match language {
+            Language::Dutch => *nl,
+            Language::English => *en,
+        })
+        .unwrap_or(id)
+}
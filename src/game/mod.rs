@@ -1,10 +1,17 @@
-use bevy::app::AppExit;
+use bevy::anti_alias::fxaa::Fxaa;
+use bevy::anti_alias::taa::TemporalAntiAliasing;
+use bevy::app::{AppExit, ScheduleRunnerPlugin};
+use bevy::core_pipeline::prepass::{DepthPrepass, MotionVectorPrepass};
 use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin};
-use bevy::input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll};
+use bevy::input::InputPlugin;
+use bevy::input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll, MouseScrollUnit};
 use bevy::light::{NotShadowCaster, NotShadowReceiver};
+use bevy::log::LogPlugin;
 use bevy::pbr::wireframe::Wireframe;
 use bevy::pbr::wireframe::WireframePlugin;
 use bevy::prelude::*;
+use bevy::render::camera::{MipBias, TemporalJitter};
+use bevy::time::TimeUpdateStrategy;
 use bevy::window::{CursorGrabMode, CursorOptions, PresentMode, PrimaryWindow, WindowResolution};
 use bevy_egui::{EguiPlugin, EguiPrimaryContextPass};
 use std::env;
@@ -15,6 +22,7 @@ use std::time::Duration;
 mod components;
 mod gameplay_physics;
 mod io_and_scenarios;
+mod localization;
 mod settings;
 mod types;
 mod ui_and_flow;
@@ -22,27 +30,40 @@ mod ui_and_flow;
 use components::*;
 use gameplay_physics::*;
 use io_and_scenarios::*;
+use localization::*;
 use settings::*;
 use types::*;
 use ui_and_flow::*;
 
 pub fn run() {
     let cli = parse_cli_options();
-    let scenario_catalog = load_scenario_catalog(Path::new(&cli.scenarios_path));
+    if cli.validate {
+        std::process::exit(validate_scenarios(&cli.scenarios_paths));
+    }
+    if cli.benchmark.is_some() && (cli.headless || !display_available()) {
+        eprintln!("--benchmark meet de rendering en heeft een venster nodig");
+        std::process::exit(2);
+    }
+    let scenario_catalog = load_scenario_catalog(&cli.scenarios_paths);
+    let persisted = load_persisted_config();
+    let mut notice = PendingNotice::default();
     let pending_scenario = if let Some(requested_id) = cli.scenario_id.as_deref() {
         match scenario_catalog.index_by_id(requested_id) {
             Some(index) => Some(index),
             None => {
-                let available = scenario_catalog
-                    .scenarios
-                    .iter()
-                    .map(|scenario| scenario.id.as_str())
-                    .collect::<Vec<_>>()
-                    .join(", ");
+                let available = scenario_catalog.available_ids();
                 eprintln!(
                     "Scenario '{}' niet gevonden. Beschikbaar: {}",
                     requested_id, available
                 );
+                if cli.benchmark.is_some() {
+                    std::process::exit(2);
+                }
+                notice.0 = Some(
+                    tr(persisted.settings.language, "notice.scenario_not_found")
+                        .replace("{id}", requested_id)
+                        .replace("{available}", &available),
+                );
                 None
             }
         }
@@ -50,84 +71,289 @@ pub fn run() {
         None
     };
 
-    let persisted = load_persisted_config();
     let initial_settings = persisted.settings;
     let initial_keybinds = persisted.keybinds.to_runtime();
     let initial_debug = persisted.debug;
+    let last_scenario = LastScenario(persisted.last_scenario_id);
 
-    App::new()
-        .add_plugins(DefaultPlugins.set(WindowPlugin {
-            primary_window: Some(Window {
-                title: "haemwend".into(),
-                resolution: WindowResolution::new(1920, 1080),
-                present_mode: PresentMode::Immediate,
-                ..default()
-            }),
+    if cli.headless || !display_available() {
+        if !cli.headless {
+            eprintln!("Geen display gevonden; start zonder venster (--headless)");
+        }
+        run_headless(
+            &cli,
+            scenario_catalog,
+            pending_scenario,
+            initial_settings,
+            initial_keybinds,
+            initial_debug,
+        );
+        return;
+    }
+
+    let mut app = App::new();
+    app.add_plugins(DefaultPlugins.set(WindowPlugin {
+        primary_window: Some(Window {
+            title: "haemwend".into(),
+            resolution: WindowResolution::new(1920, 1080),
+            present_mode: PresentMode::Immediate,
+            ..default()
+        }),
+        // `confirm_window_close` decides whether a close request quits.
+        close_when_requested: false,
+        ..default()
+    }))
+    .add_plugins((
+        FrameTimeDiagnosticsPlugin::default(),
+        LogDiagnosticsPlugin {
+            wait_duration: Duration::from_secs(2),
             ..default()
-        }))
+        },
+        WireframePlugin::default(),
+        EguiPlugin::default(),
+    ))
+    .insert_resource(initial_settings)
+    .insert_resource(initial_keybinds)
+    .insert_resource(initial_debug)
+    .insert_resource(GameFlowState {
+        in_game: false,
+        pending_scenario,
+        current_scenario: None,
+    })
+    .insert_resource(scenario_catalog)
+    .insert_resource(MenuState::default())
+    .insert_resource(MouseLookCaptureState::default())
+    .insert_resource(WorldCollisionGrid::default())
+    .insert_resource(FogAlphaMaterialCache::default())
+    .insert_resource(SimPaused::default())
+    .insert_resource(ControllerContacts::default())
+    .insert_resource(LimbHitboxes::default())
+    .insert_resource(JumpTracker::default())
+    .insert_resource(DiagnosticsRecorder::default())
+    .insert_resource(notice)
+    .insert_resource(last_scenario)
+    .insert_resource(HitchLog::default())
+    .insert_resource(FrameTimeHistory::default())
+    .insert_resource(MeasurementState::default())
+    .insert_resource(PhotoModeState::default())
+    .add_message::<FootstepMessage>()
+    .add_message::<LandedMessage>()
+    .insert_resource(ClearColor(Color::srgb(0.57, 0.70, 0.92)))
+    .insert_resource(GlobalAmbientLight {
+        color: Color::srgb(0.56, 0.61, 0.67),
+        brightness: 135.0,
+        affects_lightmapped_meshes: true,
+    })
+    .add_systems(Startup, setup_start_menu)
+    .add_systems(
+        Update,
+        (
+            handle_start_menu_buttons,
+            sync_start_menu_stats,
+            load_pending_scenario,
+        )
+            .chain(),
+    )
+    .add_systems(
+        Update,
+        (
+            toggle_menu_on_escape,
+            handle_menu_buttons,
+            capture_rebind_input,
+            capture_keybind_filter_input,
+            apply_runtime_settings,
+            rebuild_menu_ui,
+            persist_config_on_change,
+        )
+            .chain(),
+    )
+    .add_systems(Update, sync_mouse_capture_with_focus)
+    .add_systems(
+        Update,
+        (show_pending_notice, expire_transient_notices)
+            .chain()
+            .after(load_pending_scenario),
+    )
+    .add_systems(
+        Update,
+        (sync_scenario_preview, spin_scenario_preview).chain(),
+    )
+    .add_systems(Update, confirm_window_close.before(rebuild_menu_ui))
+    .add_systems(Update, update_render_scale.after(apply_runtime_settings))
+    .add_systems(
+        Update,
+        sync_look_sensitivity
+            .after(apply_runtime_settings)
+            .before(third_person_camera),
+    )
+    .add_systems(
+        Update,
+        toggle_wireframe_hotkey
+            .after(toggle_menu_on_escape)
+            .before(apply_runtime_settings),
+    )
+    .add_systems(
+        Update,
+        (toggle_photo_mode, photo_mode_camera)
+            .chain()
+            .after(toggle_menu_on_escape)
+            .after(third_person_camera)
+            .after(update_measurement_tool)
+            .after(apply_runtime_settings),
+    )
+    .add_systems(
+        Update,
+        (
+            handle_sim_pause_input,
+            player_move,
+            animate_procedural_human,
+            update_player_blob_shadow,
+            third_person_camera,
+            apply_fog_alpha_materials,
+        )
+            .chain()
+            .after(rebuild_menu_ui),
+    )
+    .add_systems(
+        Update,
+        consume_sim_step
+            .after(animate_procedural_human)
+            .after(update_patrols),
+    )
+    .add_systems(Update, billboard_stair_labels.after(third_person_camera))
+    .add_systems(Update, update_player_lod.after(third_person_camera))
+    .add_systems(Update, fade_player_near_camera.after(photo_mode_camera))
+    .add_systems(
+        Update,
+        (update_patrols, update_target_dummy_triggers)
+            .chain()
+            .after(player_move),
+    )
+    .add_systems(
+        Update,
+        update_comfort_vignette.after(animate_procedural_human),
+    )
+    .add_systems(
+        Update,
+        update_prop_lod
+            .after(third_person_camera)
+            .before(apply_fog_alpha_materials),
+    )
+    .add_systems(
+        Update,
+        update_player_shading
+            .after(apply_runtime_settings)
+            .before(apply_fog_alpha_materials),
+    )
+    .add_systems(
+        Update,
+        (
+            place_performance_overlay,
+            update_performance_overlay,
+            update_fps_graph,
+        ),
+    )
+    .add_systems(Update, record_diagnostics_csv.after(rebuild_menu_ui))
+    .add_systems(Update, detect_hitches.before(rebuild_menu_ui))
+    .add_systems(Update, update_measurement_tool.after(third_person_camera))
+    .add_systems(Update, update_jump_readout.after(player_move))
+    .add_systems(
+        Update,
+        (spawn_dust_particles, update_dust_particles)
+            .chain()
+            .after(animate_procedural_human),
+    )
+    .add_systems(
+        Update,
+        (configure_debug_gizmo_depth, draw_debug_geometry).chain(),
+    )
+    .add_systems(
+        PostUpdate,
+        update_limb_hitboxes.after(TransformSystems::Propagate),
+    )
+    .add_systems(Last, flush_on_exit)
+    .add_systems(
+        EguiPrimaryContextPass,
+        (
+            fog_debug_sliders_ui,
+            input_debug_panel_ui,
+            ik_debug_panel_ui,
+        )
+            .run_if(primary_egui_context_exists),
+    )
+    .add_systems(
+        Update,
+        run_benchmark_flythrough
+            .run_if(resource_exists::<BenchmarkRun>)
+            .after(photo_mode_camera),
+    );
+    if let Some(scenario_id) = cli.benchmark {
+        app.insert_resource(BenchmarkRun {
+            scenario_id,
+            ..default()
+        });
+    }
+    app.run();
+}
+
+/// Runs the scenario without a window or renderer: `MinimalPlugins` with a fixed timestep
+/// and only the simulation systems, for CI and machines without a GPU.
+fn run_headless(
+    cli: &CliOptions,
+    scenario_catalog: ScenarioCatalog,
+    pending_scenario: Option<usize>,
+    settings: GameSettings,
+    keybinds: GameKeybinds,
+    debug: DebugSettings,
+) {
+    App::new()
+        .add_plugins(MinimalPlugins.set(ScheduleRunnerPlugin::run_loop(Duration::ZERO)))
         .add_plugins((
-            FrameTimeDiagnosticsPlugin::default(),
-            LogDiagnosticsPlugin {
-                wait_duration: Duration::from_secs(2),
-                ..default()
-            },
-            WireframePlugin::default(),
-            EguiPlugin::default(),
+            LogPlugin::default(),
+            AssetPlugin::default(),
+            InputPlugin,
+            TransformPlugin,
         ))
-        .insert_resource(initial_settings)
-        .insert_resource(initial_keybinds)
-        .insert_resource(initial_debug)
+        .init_asset::<Mesh>()
+        .init_asset::<StandardMaterial>()
+        .init_asset::<Image>()
+        .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f64(
+            HEADLESS_STEP_SECS,
+        )))
+        .insert_resource(HeadlessRun {
+            steps_left: (cli.headless_steps > 0).then_some(cli.headless_steps),
+            steps_done: 0,
+        })
+        .insert_resource(settings)
+        .insert_resource(keybinds)
+        .insert_resource(debug)
         .insert_resource(GameFlowState {
             in_game: false,
-            pending_scenario,
+            pending_scenario: pending_scenario.or(Some(0)),
+            current_scenario: None,
         })
         .insert_resource(scenario_catalog)
         .insert_resource(MenuState::default())
-        .insert_resource(MouseLookCaptureState::default())
         .insert_resource(WorldCollisionGrid::default())
-        .insert_resource(ClearColor(Color::srgb(0.57, 0.70, 0.92)))
-        .insert_resource(GlobalAmbientLight {
-            color: Color::srgb(0.56, 0.61, 0.67),
-            brightness: 135.0,
-            affects_lightmapped_meshes: true,
-        })
-        .add_systems(Startup, setup_start_menu)
-        .add_systems(
-            Update,
-            (handle_start_menu_buttons, load_pending_scenario).chain(),
-        )
-        .add_systems(
-            Update,
-            (
-                toggle_menu_on_escape,
-                handle_menu_buttons,
-                capture_rebind_input,
-                capture_keybind_filter_input,
-                apply_runtime_settings,
-                rebuild_menu_ui,
-                persist_config_on_change,
-            )
-                .chain(),
-        )
-        .add_systems(Update, sync_mouse_capture_with_focus)
+        .insert_resource(FogAlphaMaterialCache::default())
+        .insert_resource(SimPaused::default())
+        .insert_resource(ControllerContacts::default())
+        .insert_resource(JumpTracker::default())
+        .insert_resource(MeasurementState::default())
+        .insert_resource(PhotoModeState::default())
+        .insert_resource(PendingNotice::default())
+        .insert_resource(LastScenario::default())
+        .add_message::<LandedMessage>()
         .add_systems(
             Update,
             (
+                load_pending_scenario,
                 player_move,
-                animate_procedural_human,
-                update_player_blob_shadow,
-                third_person_camera,
-                apply_fog_alpha_materials,
+                update_patrols,
+                update_target_dummy_triggers,
+                count_headless_steps,
             )
-                .chain()
-                .after(rebuild_menu_ui),
-        )
-        .add_systems(Update, billboard_stair_labels.after(third_person_camera))
-        .add_systems(Update, update_performance_overlay)
-        .add_systems(
-            Update,
-            (configure_debug_gizmo_depth, draw_debug_geometry).chain(),
+                .chain(),
         )
-        .add_systems(EguiPrimaryContextPass, fog_debug_sliders_ui)
         .run();
 }
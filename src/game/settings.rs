@@ -1,4 +1,4 @@
-use bevy::prelude::Resource;
+use bevy::prelude::{Msaa, Node, Resource, Val};
 use bevy::window::{MonitorSelection, VideoModeSelection, WindowMode};
 use serde::{Deserialize, Serialize};
 
@@ -10,6 +10,32 @@ pub(super) const RESOLUTION_OPTIONS: &[(u32, u32)] = &[
     (3440, 1440),
 ];
 
+pub(super) const RENDER_SCALE_OPTIONS: &[f32] = &[1.0, 0.85, 0.75, 0.6, 0.5];
+pub(super) const TIME_SCALE_OPTIONS: &[f32] = &[1.0, 0.5, 0.25, 0.1, 2.0];
+pub(super) const DIAGNOSTICS_DURATION_OPTIONS: &[f32] = &[30.0, 60.0, 120.0, 10.0];
+/// Frame times (ms) that count as a hitch; 0 turns hitch logging off.
+pub(super) const HITCH_THRESHOLD_OPTIONS: &[f32] = &[50.0, 100.0, 33.0, 0.0];
+pub(super) const TIME_SCALE_RANGE: (f32, f32) = (0.05, 2.0);
+/// Camera yaw/pitch in radians per pixel of mouse motion.
+pub(super) const LOOK_SENSITIVITY_DEFAULT: f32 = 0.0025;
+pub(super) const LOOK_SENSITIVITY_OPTIONS: &[f32] = &[
+    LOOK_SENSITIVITY_DEFAULT,
+    LOOK_SENSITIVITY_DEFAULT * 1.5,
+    LOOK_SENSITIVITY_DEFAULT * 2.0,
+    LOOK_SENSITIVITY_DEFAULT * 0.5,
+    LOOK_SENSITIVITY_DEFAULT * 0.75,
+];
+pub(super) const LOOK_SENSITIVITY_RANGE: (f32, f32) = (0.0005, 0.01);
+pub(super) const FOV_DEGREES_OPTIONS: &[f32] = &[60.0, 75.0, 90.0, 105.0, 50.0];
+pub(super) const FOV_DEGREES_RANGE: (f32, f32) = (50.0, 110.0);
+pub(super) const CAMERA_FOCUS_HEIGHT_OPTIONS: &[f32] = &[1.1, 1.4, 1.7, 0.8];
+pub(super) const CAMERA_FOCUS_HEIGHT_RANGE: (f32, f32) = (0.3, 2.0);
+pub(super) const CAMERA_HEIGHT_OPTIONS: &[f32] = &[2.0, 2.75, 3.5, 1.0];
+pub(super) const CAMERA_HEIGHT_RANGE: (f32, f32) = (0.0, 4.0);
+pub(super) const CAMERA_SHOULDER_OFFSET_OPTIONS: &[f32] = &[0.0, 0.5, 0.8];
+/// Gravity multipliers near the jump apex; 1.0 turns hang-time off.
+pub(super) const JUMP_APEX_HANG_OPTIONS: &[f32] = &[1.0, 0.75, 0.5, 0.35];
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub(super) enum DisplayModeSetting {
     Windowed,
@@ -45,6 +71,50 @@ impl DisplayModeSetting {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) enum AntiAliasingSetting {
+    Off,
+    Msaa2,
+    Msaa4,
+    Msaa8,
+    Fxaa,
+    Taa,
+}
+
+impl AntiAliasingSetting {
+    pub(super) fn next(self) -> Self {
+        match self {
+            Self::Off => Self::Msaa2,
+            Self::Msaa2 => Self::Msaa4,
+            Self::Msaa4 => Self::Msaa8,
+            Self::Msaa8 => Self::Fxaa,
+            Self::Fxaa => Self::Taa,
+            Self::Taa => Self::Off,
+        }
+    }
+
+    pub(super) fn label(self) -> &'static str {
+        match self {
+            Self::Off => "Off",
+            Self::Msaa2 => "MSAA 2x",
+            Self::Msaa4 => "MSAA 4x",
+            Self::Msaa8 => "MSAA 8x",
+            Self::Fxaa => "FXAA",
+            Self::Taa => "TAA",
+        }
+    }
+
+    /// Post-process methods run without MSAA; TAA requires it to be off.
+    pub(super) fn msaa(self) -> Msaa {
+        match self {
+            Self::Msaa2 => Msaa::Sample2,
+            Self::Msaa4 => Msaa::Sample4,
+            Self::Msaa8 => Msaa::Sample8,
+            Self::Off | Self::Fxaa | Self::Taa => Msaa::Off,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub(super) enum ShadowModeSetting {
     Blob,
@@ -67,6 +137,138 @@ impl ShadowModeSetting {
     }
 }
 
+/// Language of the menus and scenario texts; see `localization::tr`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) enum Language {
+    Dutch,
+    English,
+}
+
+impl Language {
+    pub(super) fn next(self) -> Self {
+        match self {
+            Self::Dutch => Self::English,
+            Self::English => Self::Dutch,
+        }
+    }
+
+    pub(super) fn label(self) -> &'static str {
+        match self {
+            Self::Dutch => "Nederlands",
+            Self::English => "English",
+        }
+    }
+
+    /// Key of this language in a scenario's `localized_name`/`localized_description` maps.
+    pub(super) fn code(self) -> &'static str {
+        match self {
+            Self::Dutch => "nl",
+            Self::English => "en",
+        }
+    }
+}
+
+/// How the procedural human is shaded; `Flat` swaps in unlit copies of its materials.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) enum HumanShadingSetting {
+    Lit,
+    Flat,
+}
+
+impl HumanShadingSetting {
+    pub(super) fn next(self) -> Self {
+        match self {
+            Self::Lit => Self::Flat,
+            Self::Flat => Self::Lit,
+        }
+    }
+
+    pub(super) fn label(self) -> &'static str {
+        match self {
+            Self::Lit => "Lit",
+            Self::Flat => "Flat",
+        }
+    }
+}
+
+/// Procedural body proportions, applied when the player spawns.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) enum BodyPresetSetting {
+    Average,
+    Tall,
+    Short,
+}
+
+impl BodyPresetSetting {
+    pub(super) fn next(self) -> Self {
+        match self {
+            Self::Average => Self::Tall,
+            Self::Tall => Self::Short,
+            Self::Short => Self::Average,
+        }
+    }
+
+    pub(super) fn label(self) -> &'static str {
+        match self {
+            Self::Average => "Average",
+            Self::Tall => "Tall",
+            Self::Short => "Short",
+        }
+    }
+
+    pub(super) fn proportions(self) -> BodyProportions {
+        // (leg, arm, upper body) scale relative to the average body.
+        let (leg, arm, upper_body) = match self {
+            Self::Average => (1.0, 1.0, 1.0),
+            Self::Tall => (1.12, 1.08, 1.06),
+            Self::Short => (0.85, 0.90, 0.92),
+        };
+        BodyProportions {
+            upper_arm_len: 0.28 * arm,
+            lower_arm_len: 0.26 * arm,
+            upper_leg_len: 0.40 * leg,
+            lower_leg_len: 0.40 * leg,
+            ankle_height: 0.08,
+            upper_body_scale: upper_body,
+        }
+    }
+}
+
+/// Limb lengths of a body preset. Heights are measured up from the soles, with the hip
+/// joint sitting exactly one leg chain above the floor so the leg IK can always reach it.
+#[derive(Debug, Clone, Copy)]
+pub(super) struct BodyProportions {
+    pub(super) upper_arm_len: f32,
+    pub(super) lower_arm_len: f32,
+    pub(super) upper_leg_len: f32,
+    pub(super) lower_leg_len: f32,
+    pub(super) ankle_height: f32,
+    pub(super) upper_body_scale: f32,
+}
+
+impl BodyProportions {
+    pub(super) fn hip_height(&self) -> f32 {
+        self.upper_leg_len + self.lower_leg_len + self.ankle_height
+    }
+
+    pub(super) fn torso_height(&self) -> f32 {
+        self.hip_height() + 0.36 * self.upper_body_scale
+    }
+
+    pub(super) fn shoulder_height(&self) -> f32 {
+        self.hip_height() + 0.52 * self.upper_body_scale
+    }
+
+    pub(super) fn head_height(&self) -> f32 {
+        self.hip_height() + 0.76 * self.upper_body_scale
+    }
+
+    /// Half the standing height, from the soles to the top of the hair.
+    pub(super) fn half_height(&self) -> f32 {
+        (self.head_height() + 0.16) * 0.5
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub(super) enum FogCurveSetting {
     Linear,
@@ -101,16 +303,321 @@ impl FogAnchorSetting {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) enum OverlayCornerSetting {
+    TopLeft,
+    TopRight,
+    BottomLeft,
+    BottomRight,
+}
+
+impl OverlayCornerSetting {
+    pub(super) fn next(self) -> Self {
+        match self {
+            Self::TopLeft => Self::TopRight,
+            Self::TopRight => Self::BottomRight,
+            Self::BottomRight => Self::BottomLeft,
+            Self::BottomLeft => Self::TopLeft,
+        }
+    }
+
+    pub(super) fn label(self) -> &'static str {
+        match self {
+            Self::TopLeft => "Top Left",
+            Self::TopRight => "Top Right",
+            Self::BottomLeft => "Bottom Left",
+            Self::BottomRight => "Bottom Right",
+        }
+    }
+
+    /// Pins an absolutely positioned node into this corner with the given margin.
+    pub(super) fn place(self, node: &mut Node, margin: Val) {
+        let (top, bottom) = match self {
+            Self::TopLeft | Self::TopRight => (margin, Val::Auto),
+            Self::BottomLeft | Self::BottomRight => (Val::Auto, margin),
+        };
+        let (left, right) = match self {
+            Self::TopLeft | Self::BottomLeft => (margin, Val::Auto),
+            Self::TopRight | Self::BottomRight => (Val::Auto, margin),
+        };
+        node.top = top;
+        node.bottom = bottom;
+        node.left = left;
+        node.right = right;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) enum OverlayMetric {
+    Fps,
+    FrameTime,
+    EntityCount,
+    Playtime,
+}
+
+impl OverlayMetric {
+    pub(super) const ALL: [Self; 4] = [
+        Self::Fps,
+        Self::FrameTime,
+        Self::EntityCount,
+        Self::Playtime,
+    ];
+
+    pub(super) fn label(self) -> &'static str {
+        match self {
+            Self::Fps => "FPS",
+            Self::FrameTime => "Frame Time",
+            Self::EntityCount => "Entity Count",
+            Self::Playtime => "Playtime",
+        }
+    }
+}
+
+/// Which lines the performance overlay shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub(super) struct OverlayMetrics {
+    pub(super) fps: bool,
+    pub(super) frame_time: bool,
+    pub(super) entity_count: bool,
+    pub(super) playtime: bool,
+}
+
+impl Default for OverlayMetrics {
+    fn default() -> Self {
+        Self {
+            fps: true,
+            frame_time: true,
+            entity_count: false,
+            playtime: false,
+        }
+    }
+}
+
+impl OverlayMetrics {
+    pub(super) fn shows(self, metric: OverlayMetric) -> bool {
+        match metric {
+            OverlayMetric::Fps => self.fps,
+            OverlayMetric::FrameTime => self.frame_time,
+            OverlayMetric::EntityCount => self.entity_count,
+            OverlayMetric::Playtime => self.playtime,
+        }
+    }
+
+    pub(super) fn toggle(&mut self, metric: OverlayMetric) {
+        let flag = match metric {
+            OverlayMetric::Fps => &mut self.fps,
+            OverlayMetric::FrameTime => &mut self.frame_time,
+            OverlayMetric::EntityCount => &mut self.entity_count,
+            OverlayMetric::Playtime => &mut self.playtime,
+        };
+        *flag = !*flag;
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(super) enum AxesAnchorSetting {
+    Origin,
+    Player,
+    Both,
+}
+
+impl AxesAnchorSetting {
+    pub(super) fn next(self) -> Self {
+        match self {
+            Self::Origin => Self::Player,
+            Self::Player => Self::Both,
+            Self::Both => Self::Origin,
+        }
+    }
+
+    pub(super) fn label(self) -> &'static str {
+        match self {
+            Self::Origin => "Origin",
+            Self::Player => "Player",
+            Self::Both => "Origin + Player",
+        }
+    }
+
+    pub(super) fn at_origin(self) -> bool {
+        matches!(self, Self::Origin | Self::Both)
+    }
+
+    pub(super) fn at_player(self) -> bool {
+        matches!(self, Self::Player | Self::Both)
+    }
+}
+
+/// Named bundles of the camera/animation feel fields; the fields stay individually
+/// editable, and a tweaked combination simply no longer matches a preset. `Default`
+/// is the fresh-install feel; the others are opt-in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum GameFeelPreset {
+    Minimal,
+    Default,
+    Dynamic,
+    Cinematic,
+}
+
+impl GameFeelPreset {
+    const ALL: [Self; 4] = [Self::Minimal, Self::Default, Self::Dynamic, Self::Cinematic];
+
+    pub(super) fn next(self) -> Self {
+        match self {
+            Self::Minimal => Self::Default,
+            Self::Default => Self::Dynamic,
+            Self::Dynamic => Self::Cinematic,
+            Self::Cinematic => Self::Minimal,
+        }
+    }
+
+    pub(super) fn label(self) -> &'static str {
+        match self {
+            Self::Minimal => "Minimal",
+            Self::Default => "Default",
+            Self::Dynamic => "Dynamic",
+            Self::Cinematic => "Cinematic",
+        }
+    }
+
+    /// (sprint FOV boost in degrees, bob intensity, camera tilt in degrees, follow lag secs)
+    fn values(self) -> (f32, f32, f32, f32) {
+        match self {
+            Self::Minimal => (0.0, 0.4, 0.0, 0.0),
+            Self::Default => (0.0, 1.0, 0.0, 0.0),
+            Self::Dynamic => (4.0, 1.0, 1.0, 0.04),
+            Self::Cinematic => (10.0, 1.2, 3.0, 0.15),
+        }
+    }
+
+    pub(super) fn apply(self, settings: &mut GameSettings) {
+        let (fov_boost, bob, tilt, lag) = self.values();
+        settings.sprint_fov_boost_degrees = fov_boost;
+        settings.bob_intensity = bob;
+        settings.camera_tilt_degrees = tilt;
+        settings.camera_follow_lag_secs = lag;
+    }
+
+    pub(super) fn matching(settings: &GameSettings) -> Option<Self> {
+        Self::ALL.into_iter().find(|preset| {
+            let (fov_boost, bob, tilt, lag) = preset.values();
+            (settings.sprint_fov_boost_degrees - fov_boost).abs() < 1e-3
+                && (settings.bob_intensity - bob).abs() < 1e-3
+                && (settings.camera_tilt_degrees - tilt).abs() < 1e-3
+                && (settings.camera_follow_lag_secs - lag).abs() < 1e-3
+        })
+    }
+}
+
 #[derive(Resource, Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub(super) struct GameSettings {
+    pub(super) language: Language,
     pub(super) display_mode: DisplayModeSetting,
     pub(super) resolution_width: u32,
     pub(super) resolution_height: u32,
-    pub(super) msaa_enabled: bool,
+    pub(super) anti_aliasing: AntiAliasingSetting,
+    /// Fraction of the window resolution the 3D scene renders at; UI stays native.
+    pub(super) render_scale: f32,
+    /// Pre-`anti_aliasing` configs stored a single MSAA switch; read once and mapped on load.
+    #[serde(skip_serializing, deserialize_with = "deserialize_legacy_flag")]
+    pub(super) msaa_enabled: Option<bool>,
     pub(super) shadow_mode: ShadowModeSetting,
+    pub(super) human_shading: HumanShadingSetting,
+    pub(super) body_preset: BodyPresetSetting,
+    /// Spawn a plain box instead of the animated procedural human (applies on next spawn).
+    pub(super) simple_player_model: bool,
+    /// The head follows the camera direction at all times instead of only while LMB is held.
+    pub(super) head_always_tracks_camera: bool,
+    /// Procedural grass/dirt texture on the ground instead of a flat color (next spawn).
+    pub(super) ground_texture: bool,
+    /// Spawn the baked shadow quads under props at all. Off skips the entities entirely
+    /// (unlike the Debug visibility toggle) and only takes effect on the next scenario load.
+    pub(super) bake_world_shadows: bool,
+    /// Most crates a scenario may spawn; columns past it are skipped with a warning. 0 is
+    /// no limit.
+    pub(super) crate_budget: u32,
+    /// Dust puffs on footsteps and hard landings.
+    pub(super) dust_particles: bool,
+    /// Spinning low-resolution render of a scenario behind the start menu.
+    pub(super) menu_scenario_preview: bool,
+    /// Jitter crate columns on every scenario load, seeded by `chaos_seed`.
+    pub(super) chaos_mode: bool,
+    /// Fixed chaos seed, e.g. one copied from the log to replay a layout; unset rolls a
+    /// new seed per load.
+    pub(super) chaos_seed: Option<u64>,
+    /// Collider counts per scenario in the start menu.
+    pub(super) start_menu_stats: bool,
+    /// Scenario id launched by the start menu's Quick Start button; unset or unknown ids
+    /// fall back to the first scenario.
+    pub(super) quick_start_scenario: Option<String>,
+    /// Opening the menu freezes movement and animation; off is practice mode.
+    pub(super) pause_on_menu: bool,
+    /// Full-screen dim behind the pause menu (not on the Debug screen).
+    pub(super) pause_menu_dim: bool,
     pub(super) foot_support_max_drop: f32,
     pub(super) foot_support_max_rise: f32,
+    pub(super) player_lod_distance: f32,
+    pub(super) prop_lod_distance: f32,
+    pub(super) camera_relative_movement: bool,
+    /// RMB flips aim-move on and off instead of having to be held.
+    pub(super) toggle_aim: bool,
+    /// Sprint flips on and off instead of having to be held; stopping ends the sprint.
+    pub(super) sprint_toggle: bool,
+    pub(super) turn_acceleration_enabled: bool,
+    pub(super) turn_acceleration_ramp_secs: f32,
+    /// Comfort switch: removes the stride and idle body bob, the sprint FOV boost, the
+    /// sideways camera roll and the camera follow lag, and forces the comfort vignette on.
+    pub(super) reduce_motion: bool,
+    pub(super) comfort_vignette: bool,
+    /// Orbit speed of the mouse look; pushed into the camera rig when it changes.
+    pub(super) look_sensitivity: f32,
+    /// Moving the mouse up tilts the camera down instead of up.
+    pub(super) invert_y: bool,
+    /// Vertical field of view of the game camera; sprinting adds its boost on top.
+    pub(super) fov_degrees: f32,
+    /// Extra vertical FOV at full sprint speed.
+    pub(super) sprint_fov_boost_degrees: f32,
+    /// Scales the stride and idle body bob.
+    pub(super) bob_intensity: f32,
+    /// Camera roll at full sideways speed.
+    pub(super) camera_tilt_degrees: f32,
+    /// Time constant of the camera easing after the player; 0 follows rigidly.
+    pub(super) camera_follow_lag_secs: f32,
+    /// Height above the player's centre the camera looks at, and its extra orbit height.
+    pub(super) camera_focus_height: f32,
+    pub(super) camera_height: f32,
+    /// Sideways camera offset for an over-the-shoulder view; 0 keeps it centred.
+    pub(super) camera_shoulder_offset: f32,
+    /// Zoom distances remembered separately for orbiting and aim-move.
+    pub(super) camera_orbit_distance: f32,
+    pub(super) camera_aim_distance: f32,
+    /// Lift the camera over whatever ground or obstacle tops lie under it.
+    pub(super) camera_follow_ground: bool,
+    /// Fade the player out when the camera comes closer than `player_fade_distance`.
+    pub(super) fade_player_near_camera: bool,
+    pub(super) player_fade_distance: f32,
+    /// Hold Jump to charge; the jump fires on release between the min and max speed.
+    pub(super) charge_jump_enabled: bool,
+    pub(super) charge_jump_min_speed: f32,
+    pub(super) charge_jump_max_speed: f32,
+    /// Charge gained per second of holding, where 1.0 is a full charge.
+    pub(super) charge_jump_rate: f32,
+    /// Keep the cursor locked in-game so the camera always follows the mouse, instead of
+    /// only while a mouse button is held.
+    pub(super) always_capture_mouse: bool,
+    /// Horizontal scroll (trackpad swipe) orbits the camera yaw.
+    pub(super) horizontal_scroll_orbit: bool,
+    /// Jump off a wall touched mid-air within a short window.
+    pub(super) wall_jump_enabled: bool,
+    pub(super) wall_jump_push_speed: f32,
+    pub(super) wall_jump_up_speed: f32,
+    /// Gravity multiplier while airborne with |vertical speed| under the threshold (m/s),
+    /// for a floatier jump peak; 1.0 disables it.
+    pub(super) jump_apex_hang_factor: f32,
+    pub(super) jump_apex_hang_threshold: f32,
+    /// Largest drop a grounded, walking player is snapped down instead of falling; 0 is off.
+    pub(super) ground_snap_distance: f32,
 }
 
 impl Default for GameSettings {
@@ -119,10 +626,90 @@ impl Default for GameSettings {
             display_mode: DisplayModeSetting::Windowed,
             resolution_width: 1920,
             resolution_height: 1080,
-            msaa_enabled: true,
+            anti_aliasing: AntiAliasingSetting::Msaa4,
+            render_scale: 1.0,
+            msaa_enabled: None,
             shadow_mode: ShadowModeSetting::Blob,
+            language: Language::Dutch,
+            human_shading: HumanShadingSetting::Lit,
+            body_preset: BodyPresetSetting::Average,
+            simple_player_model: false,
+            head_always_tracks_camera: false,
+            ground_texture: true,
+            bake_world_shadows: true,
+            crate_budget: 2000,
+            dust_particles: true,
+            menu_scenario_preview: true,
+            chaos_mode: false,
+            chaos_seed: None,
+            start_menu_stats: false,
+            quick_start_scenario: None,
+            pause_on_menu: true,
+            pause_menu_dim: true,
             foot_support_max_drop: 0.45,
             foot_support_max_rise: 0.42,
+            player_lod_distance: 45.0,
+            prop_lod_distance: 60.0,
+            camera_relative_movement: false,
+            toggle_aim: false,
+            sprint_toggle: false,
+            turn_acceleration_enabled: false,
+            turn_acceleration_ramp_secs: 0.45,
+            reduce_motion: false,
+            comfort_vignette: false,
+            look_sensitivity: LOOK_SENSITIVITY_DEFAULT,
+            invert_y: false,
+            fov_degrees: 60.0,
+            sprint_fov_boost_degrees: 0.0,
+            bob_intensity: 1.0,
+            camera_tilt_degrees: 0.0,
+            camera_follow_lag_secs: 0.0,
+            camera_focus_height: 1.1,
+            camera_height: 2.0,
+            camera_shoulder_offset: 0.0,
+            camera_orbit_distance: 8.0,
+            camera_aim_distance: 5.0,
+            camera_follow_ground: false,
+            fade_player_near_camera: true,
+            player_fade_distance: 1.8,
+            charge_jump_enabled: false,
+            charge_jump_min_speed: 6.0,
+            charge_jump_max_speed: 10.5,
+            charge_jump_rate: 1.6,
+            always_capture_mouse: false,
+            horizontal_scroll_orbit: false,
+            wall_jump_enabled: false,
+            wall_jump_push_speed: 6.5,
+            wall_jump_up_speed: 7.0,
+            jump_apex_hang_factor: 1.0,
+            jump_apex_hang_threshold: 1.5,
+            ground_snap_distance: 0.4,
+        }
+    }
+}
+
+fn deserialize_legacy_flag<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    bool::deserialize(deserializer).map(Some)
+}
+
+impl GameSettings {
+    pub(super) fn fov_radians(&self) -> f32 {
+        self.fov_degrees
+            .clamp(FOV_DEGREES_RANGE.0, FOV_DEGREES_RANGE.1)
+            .to_radians()
+    }
+
+    /// Old configs only carry `msaa_enabled`; newer ones never serialize it.
+    pub(super) fn migrate_legacy_fields(&mut self) {
+        if let Some(msaa_enabled) = self.msaa_enabled.take() {
+            self.anti_aliasing = if msaa_enabled {
+                AntiAliasingSetting::Msaa4
+            } else {
+                AntiAliasingSetting::Off
+            };
         }
     }
 }
@@ -131,6 +718,12 @@ impl Default for GameSettings {
 #[serde(default)]
 pub(super) struct DebugSettings {
     pub(super) show_performance_overlay: bool,
+    /// Scrolling frame-time bar graph in the bottom-right corner.
+    pub(super) show_fps_graph: bool,
+    /// In-game egui panel with this frame's resolved actions, mouse and camera input.
+    pub(super) show_input_panel: bool,
+    pub(super) overlay_corner: OverlayCornerSetting,
+    pub(super) overlay_metrics: OverlayMetrics,
     pub(super) show_baked_shadows: bool,
     pub(super) show_fog: bool,
     pub(super) fog_anchor: FogAnchorSetting,
@@ -148,15 +741,42 @@ pub(super) struct DebugSettings {
     // Legacy field kept for backwards compatibility with older persisted configs.
     pub(super) fog_curvature: f32,
     pub(super) show_collision_shapes: bool,
+    /// Per-limb world boxes in `LimbHitboxes`; drawn with the collision shapes when on.
+    pub(super) limb_hitboxes: bool,
+    /// Draws the controller's contact points and normals of the last simulated frame.
+    pub(super) show_contact_points: bool,
+    /// HUD readout of the last jump's height and distance.
+    pub(super) show_jump_readout: bool,
     pub(super) show_animation_debug: bool,
     pub(super) show_wireframe: bool,
     pub(super) show_world_axes: bool,
+    pub(super) world_axes_anchor: AxesAnchorSetting,
+    pub(super) show_ground_grid: bool,
+    /// Arrow above the player along the sun's light direction, plus a marker at the sun.
+    pub(super) show_sun_direction: bool,
+    pub(super) measurement_tool: bool,
+    /// Multiplies the simulation dt of movement and animation (slow motion below 1).
+    pub(super) time_scale: f32,
+    /// Caps the simulation dt at `max_delta_secs` so a hitch can't launch the player.
+    pub(super) cap_delta_time: bool,
+    pub(super) max_delta_secs: f32,
+    /// Per-frame CSV capture; never persisted, so a restart doesn't resume a recording.
+    #[serde(skip)]
+    pub(super) record_diagnostics: bool,
+    pub(super) diagnostics_record_secs: f32,
+    pub(super) diagnostics_entity_count: bool,
+    /// Frames slower than this many ms are logged and kept in `HitchLog`; 0 disables it.
+    pub(super) hitch_threshold_ms: f32,
 }
 
 impl Default for DebugSettings {
     fn default() -> Self {
         Self {
             show_performance_overlay: true,
+            show_fps_graph: false,
+            show_input_panel: false,
+            overlay_corner: OverlayCornerSetting::TopRight,
+            overlay_metrics: OverlayMetrics::default(),
             show_baked_shadows: true,
             show_fog: true,
             fog_anchor: FogAnchorSetting::Character,
@@ -173,9 +793,59 @@ impl Default for DebugSettings {
             fog_hide_geometry: false,
             fog_curvature: 1.0,
             show_collision_shapes: false,
+            limb_hitboxes: false,
+            show_contact_points: false,
+            show_jump_readout: false,
             show_animation_debug: false,
             show_wireframe: false,
             show_world_axes: false,
+            world_axes_anchor: AxesAnchorSetting::Origin,
+            show_ground_grid: false,
+            show_sun_direction: false,
+            measurement_tool: false,
+            time_scale: 1.0,
+            cap_delta_time: true,
+            max_delta_secs: 0.1,
+            record_diagnostics: false,
+            diagnostics_record_secs: 30.0,
+            hitch_threshold_ms: 50.0,
+            diagnostics_entity_count: false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn legacy_msaa_flag_maps_to_anti_aliasing() {
+        let mut settings: GameSettings = ron::from_str("(msaa_enabled: false)").unwrap();
+        settings.migrate_legacy_fields();
+        assert_eq!(settings.anti_aliasing, AntiAliasingSetting::Off);
+        assert_eq!(settings.msaa_enabled, None);
+
+        let mut settings: GameSettings = ron::from_str("(anti_aliasing: Fxaa)").unwrap();
+        settings.migrate_legacy_fields();
+        assert_eq!(settings.anti_aliasing, AntiAliasingSetting::Fxaa);
+        assert!(!ron::to_string(&settings).unwrap().contains("msaa_enabled"));
+    }
+
+    #[test]
+    fn body_presets_fit_their_collider() {
+        let presets = [
+            BodyPresetSetting::Average,
+            BodyPresetSetting::Tall,
+            BodyPresetSetting::Short,
+        ];
+        for preset in presets {
+            let body = preset.proportions();
+            assert!(body.half_height() * 2.0 > body.head_height(), "{preset:?}");
+            assert!(body.shoulder_height() > body.torso_height(), "{preset:?}");
         }
+        let height = |preset: BodyPresetSetting| preset.proportions().half_height();
+        assert!((height(BodyPresetSetting::Average) - 0.9).abs() < 1e-5);
+        assert!(height(BodyPresetSetting::Tall) > height(BodyPresetSetting::Average));
+        assert!(height(BodyPresetSetting::Short) < height(BodyPresetSetting::Average));
     }
 }
@@ -1,16 +1,35 @@
-use super::io_and_scenarios::{keycode_to_label, keycodes_from_names, keycodes_to_names};
-use super::settings::{DebugSettings, GameSettings};
-use bevy::prelude::{ButtonInput, KeyCode, Resource, Vec3};
+use super::io_and_scenarios::{
+    bindings_from_names, bindings_to_names, keycode_to_label, mousebutton_to_label,
+};
+use super::settings::{DebugSettings, GameSettings, Language};
+use bevy::prelude::{ButtonInput, KeyCode, MouseButton, Resource, Vec2, Vec3};
+use rand::rngs::SmallRng;
+use rand::{RngExt, SeedableRng};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 pub(super) const CONFIG_PATH: &str = "config/game_config.ron";
+/// Standalone keybinds file written by Export and read by Import, for sharing schemes.
+pub(super) const SHARED_KEYBINDS_PATH: &str = "config/shared_keybinds.ron";
 pub(super) const SCENARIOS_PATH_DEFAULT: &str = "config/scenarios";
+/// Simulation steps of a headless run when `--steps` is not given (10 s at 60 Hz).
+pub(super) const HEADLESS_STEPS_DEFAULT: u32 = 600;
+pub(super) const HEADLESS_STEP_SECS: f64 = 1.0 / 60.0;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub(super) struct ScenarioDefinition {
     pub(super) id: String,
+    /// Scenario id this one inherits from; fields left out of the file come from the base.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) base: Option<String>,
     pub(super) name: String,
     pub(super) description: String,
+    /// Translations of `name`/`description` by language code (`"nl"`, `"en"`).
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub(super) localized_name: HashMap<String, String>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub(super) localized_description: HashMap<String, String>,
     pub(super) ground_extent: f32,
     pub(super) crate_grid_radius: i32,
     pub(super) crate_spacing: f32,
@@ -20,9 +39,208 @@ pub(super) struct ScenarioDefinition {
     pub(super) wall_z: f32,
     pub(super) tower_z: f32,
     pub(super) sun_position: [f32; 3],
+    /// Full obstacle sizes (x, y, z); omitted fields keep the classic 1/3/4x8x4 boxes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) crate_size: Option<[f32; 3]>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) wall_size: Option<[f32; 3]>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) tower_size: Option<[f32; 3]>,
+    /// Tallest crate stack; each crate column gets between 1 and this many crates.
+    #[serde(default = "default_crate_stack_height")]
+    pub(super) crate_stack_height: u32,
+    /// How many of the `CRATE_WOOD_TONES` the crates alternate between (1 = all alike).
+    #[serde(default = "default_crate_palette_size")]
+    pub(super) crate_palette_size: u32,
+    /// Feet position of the target dummy; defaults to the clearing just ahead of spawn.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) target_dummy_position: Option<[f32; 3]>,
+    /// Feet positions the dummy walks between; empty keeps it standing still.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub(super) dummy_patrol_waypoints: Vec<[f32; 3]>,
+    #[serde(default = "default_dummy_patrol_speed")]
+    pub(super) dummy_patrol_speed: f32,
+    /// Feet position of the player at spawn; the camera starts behind it.
+    #[serde(default)]
+    pub(super) spawn_position: [f32; 3],
+    /// Player facing at spawn in radians; `None` faces the tower.
+    #[serde(default)]
+    pub(super) spawn_yaw: Option<f32>,
 }
 
+fn default_dummy_patrol_speed() -> f32 {
+    2.0
+}
+
+fn default_crate_stack_height() -> u32 {
+    1
+}
+
+fn default_crate_palette_size() -> u32 {
+    3
+}
+
+/// Wood tones crates pick from, in sRGB; the first is the classic crate color.
+pub(super) const CRATE_WOOD_TONES: [[f32; 3]; 4] = [
+    [0.69, 0.44, 0.24],
+    [0.58, 0.36, 0.19],
+    [0.76, 0.55, 0.33],
+    [0.50, 0.33, 0.22],
+];
+
 impl ScenarioDefinition {
+    pub(super) fn name_in(&self, language: Language) -> &str {
+        self.localized_name
+            .get(language.code())
+            .unwrap_or(&self.name)
+    }
+
+    pub(super) fn description_in(&self, language: Language) -> &str {
+        self.localized_description
+            .get(language.code())
+            .unwrap_or(&self.description)
+    }
+
+    pub(super) fn crate_half_extents(&self) -> Vec3 {
+        size_to_half_extents(self.crate_size, [1.0, 1.0, 1.0])
+    }
+
+    pub(super) fn wall_half_extents(&self) -> Vec3 {
+        size_to_half_extents(self.wall_size, [3.0, 3.0, 3.0])
+    }
+
+    pub(super) fn tower_half_extents(&self) -> Vec3 {
+        size_to_half_extents(self.tower_size, [4.0, 8.0, 4.0])
+    }
+
+    pub(super) fn target_dummy_vec3(&self) -> Vec3 {
+        match self.target_dummy_position {
+            Some([x, y, z]) => Vec3::new(x, y, z),
+            None => Vec3::new(0.0, 0.0, -self.crate_spacing),
+        }
+    }
+
+    /// Deterministic stack height for the crate at grid cell (x, z), so reloading a
+    /// scenario always produces the same towers.
+    pub(super) fn crate_stack_levels(&self, x: i32, z: i32) -> u32 {
+        let max_levels = self.crate_stack_height.max(1);
+        1 + (x * 7 + z * 13).rem_euclid(max_levels as i32) as u32
+    }
+
+    pub(super) fn spawn_feet_vec3(&self) -> Vec3 {
+        Vec3::from_array(self.spawn_position)
+    }
+
+    /// Yaw that faces the player (and the camera behind it) at spawn: `spawn_yaw` if set,
+    /// else toward the tower, which always stands on the Z axis: forward is -Z, so a tower
+    /// at +Z needs a half turn.
+    pub(super) fn spawn_yaw(&self) -> f32 {
+        if let Some(yaw) = self.spawn_yaw {
+            yaw
+        } else if self.tower_z > 0.0 {
+            std::f32::consts::PI
+        } else {
+            0.0
+        }
+    }
+
+    /// Deterministic index into `CRATE_WOOD_TONES` for the crate at `level` of cell (x, z).
+    pub(super) fn crate_tone_index(&self, x: i32, z: i32, level: u32) -> usize {
+        let palette_size = self
+            .crate_palette_size
+            .clamp(1, CRATE_WOOD_TONES.len() as u32);
+        (x * 5 + z * 11 + level as i32 * 3).rem_euclid(palette_size as i32) as usize
+    }
+
+    /// Chaos mode: XZ offset and quarter turns for the crate column at cell (x, z), derived
+    /// from the run's seed. Offsets stay under half the gap to the neighbouring cells, so
+    /// columns never overlap or move into the clear area around spawn.
+    pub(super) fn chaos_crate_jitter(&self, seed: u64, x: i32, z: i32) -> (Vec2, u32) {
+        let cell_hash = (u64::from(x as u32) << 32) | u64::from(z as u32);
+        let mut rng = SmallRng::seed_from_u64(seed ^ cell_hash);
+
+        let half = self.crate_half_extents();
+        let gap = self.crate_spacing - 2.0 * half.x.max(half.z);
+        let max_offset = (gap * 0.45).max(0.0);
+        let offset =
+            Vec2::new(rng.random_range(-1.0..=1.0), rng.random_range(-1.0..=1.0)) * max_offset;
+        (offset, rng.random_range(0..4))
+    }
+
+    /// Grid cells (x, z) that get a crate column; the 3x3 around spawn stays clear.
+    pub(super) fn crate_cells(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        let radius = self.crate_grid_radius;
+        let pattern_mod = self.crate_pattern_mod.max(1);
+        (-radius..=radius)
+            .flat_map(move |x| (-radius..=radius).map(move |z| (x, z)))
+            .filter(move |&(x, z)| {
+                let near_spawn = (-1..=1).contains(&x) && (-1..=1).contains(&z);
+                (x + z).rem_euclid(pattern_mod) == 0 && !near_spawn
+            })
+    }
+
+    /// Authoring problems that would make the scenario build a broken world.
+    pub(super) fn validate(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if self.id.trim().is_empty() {
+            problems.push("id is leeg".to_string());
+        }
+        if self.name.trim().is_empty() {
+            problems.push("naam is leeg".to_string());
+        }
+        for (field, value) in [
+            ("ground_extent", self.ground_extent),
+            ("crate_spacing", self.crate_spacing),
+            ("wall_spacing", self.wall_spacing),
+        ] {
+            if !(value.is_finite() && value > 0.0) {
+                problems.push(format!("{field} moet groter dan 0 zijn ({value})"));
+            }
+        }
+        for (field, value) in [
+            ("crate_grid_radius", self.crate_grid_radius),
+            ("wall_count", self.wall_count),
+        ] {
+            if value < 0 {
+                problems.push(format!("{field} mag niet negatief zijn ({value})"));
+            }
+        }
+        if self.crate_pattern_mod < 1 {
+            problems.push(format!(
+                "crate_pattern_mod moet minstens 1 zijn ({})",
+                self.crate_pattern_mod
+            ));
+        }
+        for (field, size) in [
+            ("crate_size", self.crate_size),
+            ("wall_size", self.wall_size),
+            ("tower_size", self.tower_size),
+        ] {
+            if let Some(size) = size
+                && size.iter().any(|axis| !(axis.is_finite() && *axis > 0.0))
+            {
+                problems.push(format!(
+                    "{field} moet positieve afmetingen hebben ({size:?})"
+                ));
+            }
+        }
+        if self.spawn_position.iter().any(|axis| !axis.is_finite())
+            || self.spawn_yaw.is_some_and(|yaw| !yaw.is_finite())
+        {
+            problems.push(format!(
+                "spawn_position/spawn_yaw moeten eindig zijn ({:?}, {:?})",
+                self.spawn_position, self.spawn_yaw
+            ));
+        }
+        if !(self.dummy_patrol_speed.is_finite() && self.dummy_patrol_speed >= 0.0) {
+            problems.push(format!(
+                "dummy_patrol_speed mag niet negatief zijn ({})",
+                self.dummy_patrol_speed
+            ));
+        }
+        problems
+    }
+
     pub(super) fn sun_vec3(&self) -> Vec3 {
         Vec3::new(
             self.sun_position[0],
@@ -32,6 +250,35 @@ impl ScenarioDefinition {
     }
 }
 
+fn size_to_half_extents(size: Option<[f32; 3]>, fallback: [f32; 3]) -> Vec3 {
+    let [x, y, z] = size.unwrap_or(fallback);
+    (Vec3::new(x, y, z) * 0.5).max(Vec3::splat(0.05))
+}
+
+/// Scenario file with a `base`, kept as raw fields until its base has been loaded.
+#[derive(Debug, Clone)]
+pub(super) struct ScenarioVariant {
+    pub(super) source: String,
+    pub(super) id: String,
+    pub(super) base: String,
+    pub(super) fields: ron::Map,
+}
+
+/// Estimated collider counts of a scenario by kind, shown in the start menu.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct ScenarioStats {
+    pub(super) crates: u32,
+    pub(super) walls: u32,
+    pub(super) stairs: u32,
+    pub(super) props: u32,
+}
+
+impl ScenarioStats {
+    pub(super) fn total(&self) -> u32 {
+        self.crates + self.walls + self.stairs + self.props
+    }
+}
+
 #[derive(Resource, Debug, Clone)]
 pub(super) struct ScenarioCatalog {
     pub(super) scenarios: Vec<ScenarioDefinition>,
@@ -41,36 +288,54 @@ impl ScenarioCatalog {
     pub(super) fn index_by_id(&self, id: &str) -> Option<usize> {
         self.scenarios.iter().position(|scenario| scenario.id == id)
     }
+
+    /// Index Quick Start launches: the preferred id if it is still loaded, else the first.
+    pub(super) fn quick_start_index(&self, preferred: Option<&str>) -> usize {
+        preferred.and_then(|id| self.index_by_id(id)).unwrap_or(0)
+    }
+
+    /// Comma-separated scenario ids, for "not found" messages.
+    pub(super) fn available_ids(&self) -> String {
+        self.scenarios
+            .iter()
+            .map(|scenario| scenario.id.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
 }
 
 #[derive(Debug, Clone)]
 pub(super) struct CliOptions {
     pub(super) scenario_id: Option<String>,
-    pub(super) scenarios_path: String,
+    /// Scenario directories or files, layered in order: a later one overrides earlier ids.
+    pub(super) scenarios_paths: Vec<PathBuf>,
+    pub(super) validate: bool,
+    /// Scenario id to run the scripted `--benchmark` flythrough in.
+    pub(super) benchmark: Option<String>,
+    pub(super) headless: bool,
+    /// Steps of a headless run; 0 keeps it running until the process is stopped.
+    pub(super) headless_steps: u32,
 }
 
 impl Default for CliOptions {
     fn default() -> Self {
         Self {
             scenario_id: None,
-            scenarios_path: SCENARIOS_PATH_DEFAULT.to_string(),
+            scenarios_paths: vec![PathBuf::from(SCENARIOS_PATH_DEFAULT)],
+            validate: false,
+            benchmark: None,
+            headless: false,
+            headless_steps: HEADLESS_STEPS_DEFAULT,
         }
     }
 }
 
-#[derive(Resource, Debug)]
+#[derive(Resource, Debug, Default)]
 pub(super) struct GameFlowState {
     pub(super) in_game: bool,
     pub(super) pending_scenario: Option<usize>,
-}
-
-impl Default for GameFlowState {
-    fn default() -> Self {
-        Self {
-            in_game: false,
-            pending_scenario: None,
-        }
-    }
+    /// Catalog index of the scenario being played.
+    pub(super) current_scenario: Option<usize>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -83,9 +348,22 @@ pub(super) enum GameAction {
     TurnRight,
     Sprint,
     Jump,
+    AutoWalk,
+    PhotoMode,
+    PhotoScreenshot,
+    PhotoRollLeft,
+    PhotoRollRight,
+    PhotoResetRoll,
+    PhotoFlyDown,
+    PauseSimulation,
+    StepSimulation,
+    SwapShoulder,
+    RecenterCamera,
+    Crouch,
+    Measure,
 }
 
-pub(super) const ACTION_ORDER: [GameAction; 8] = [
+pub(super) const ACTION_ORDER: [GameAction; 21] = [
     GameAction::MoveForward,
     GameAction::MoveBackward,
     GameAction::StrafeLeft,
@@ -94,6 +372,19 @@ pub(super) const ACTION_ORDER: [GameAction; 8] = [
     GameAction::TurnRight,
     GameAction::Sprint,
     GameAction::Jump,
+    GameAction::AutoWalk,
+    GameAction::PhotoMode,
+    GameAction::PhotoScreenshot,
+    GameAction::PhotoRollLeft,
+    GameAction::PhotoRollRight,
+    GameAction::PhotoResetRoll,
+    GameAction::PhotoFlyDown,
+    GameAction::PauseSimulation,
+    GameAction::StepSimulation,
+    GameAction::SwapShoulder,
+    GameAction::RecenterCamera,
+    GameAction::Crouch,
+    GameAction::Measure,
 ];
 
 impl GameAction {
@@ -107,39 +398,268 @@ impl GameAction {
             Self::TurnRight => "Turn Right",
             Self::Sprint => "Sprint",
             Self::Jump => "Jump",
+            Self::AutoWalk => "Auto Walk",
+            Self::PhotoMode => "Photo Mode",
+            Self::PhotoScreenshot => "Photo Screenshot",
+            Self::PhotoRollLeft => "Photo Roll Left",
+            Self::PhotoRollRight => "Photo Roll Right",
+            Self::PhotoResetRoll => "Photo Reset Roll",
+            Self::PhotoFlyDown => "Photo Fly Down",
+            Self::PauseSimulation => "Pause Simulation",
+            Self::StepSimulation => "Step Simulation",
+            Self::SwapShoulder => "Swap Camera Shoulder",
+            Self::RecenterCamera => "Recenter Camera",
+            Self::Crouch => "Crouch",
+            Self::Measure => "Measure Point",
+        }
+    }
+
+    /// Essential actions always keep at least one bind; the rest may be left unbound.
+    pub(super) fn essential(self) -> bool {
+        matches!(
+            self,
+            Self::MoveForward
+                | Self::MoveBackward
+                | Self::StrafeLeft
+                | Self::StrafeRight
+                | Self::TurnLeft
+                | Self::TurnRight
+                | Self::Sprint
+                | Self::Jump
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum KeyModifier {
+    Shift,
+    Ctrl,
+    Alt,
+}
+
+pub(super) const MODIFIER_ORDER: [KeyModifier; 3] =
+    [KeyModifier::Shift, KeyModifier::Ctrl, KeyModifier::Alt];
+
+impl KeyModifier {
+    pub(super) fn from_key(key: KeyCode) -> Option<Self> {
+        match key {
+            KeyCode::ShiftLeft | KeyCode::ShiftRight => Some(Self::Shift),
+            KeyCode::ControlLeft | KeyCode::ControlRight => Some(Self::Ctrl),
+            KeyCode::AltLeft | KeyCode::AltRight => Some(Self::Alt),
+            _ => None,
+        }
+    }
+
+    pub(super) fn from_name(name: &str) -> Option<Self> {
+        MODIFIER_ORDER
+            .into_iter()
+            .find(|modifier| modifier.name() == name)
+    }
+
+    pub(super) fn name(self) -> &'static str {
+        match self {
+            Self::Shift => "Shift",
+            Self::Ctrl => "Ctrl",
+            Self::Alt => "Alt",
+        }
+    }
+
+    pub(super) fn held(self, input: &ButtonInput<KeyCode>) -> bool {
+        match self {
+            Self::Shift => input.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]),
+            Self::Ctrl => input.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]),
+            Self::Alt => input.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]),
+        }
+    }
+
+    fn bit(self) -> u8 {
+        match self {
+            Self::Shift => 1,
+            Self::Ctrl => 2,
+            Self::Alt => 4,
         }
     }
 }
 
+/// Set of modifiers a chord requires; empty for plain binds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(super) struct KeyModifiers(u8);
+
+impl KeyModifiers {
+    pub(super) const NONE: Self = Self(0);
+
+    pub(super) fn held(input: &ButtonInput<KeyCode>) -> Self {
+        MODIFIER_ORDER
+            .into_iter()
+            .filter(|modifier| modifier.held(input))
+            .fold(Self::NONE, Self::with)
+    }
+
+    pub(super) fn with(self, modifier: KeyModifier) -> Self {
+        Self(self.0 | modifier.bit())
+    }
+
+    pub(super) fn contains(self, modifier: KeyModifier) -> bool {
+        self.0 & modifier.bit() != 0
+    }
+
+    pub(super) fn iter(self) -> impl Iterator<Item = KeyModifier> {
+        MODIFIER_ORDER
+            .into_iter()
+            .filter(move |modifier| self.contains(*modifier))
+    }
+}
+
+/// The button a bind listens to: a keyboard key or a mouse button.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum InputBinding {
+    Key(KeyCode),
+    Mouse(MouseButton),
+}
+
+impl InputBinding {
+    fn pressed(self, keys: &ButtonInput<KeyCode>, mouse: &ButtonInput<MouseButton>) -> bool {
+        match self {
+            Self::Key(key) => keys.pressed(key),
+            Self::Mouse(button) => mouse.pressed(button),
+        }
+    }
+
+    fn just_pressed(self, keys: &ButtonInput<KeyCode>, mouse: &ButtonInput<MouseButton>) -> bool {
+        match self {
+            Self::Key(key) => keys.just_pressed(key),
+            Self::Mouse(button) => mouse.just_pressed(button),
+        }
+    }
+
+    fn just_released(self, keys: &ButtonInput<KeyCode>, mouse: &ButtonInput<MouseButton>) -> bool {
+        match self {
+            Self::Key(key) => keys.just_released(key),
+            Self::Mouse(button) => mouse.just_released(button),
+        }
+    }
+
+    pub(super) fn label(self) -> String {
+        match self {
+            Self::Key(key) => keycode_to_label(key),
+            Self::Mouse(button) => mousebutton_to_label(button),
+        }
+    }
+}
+
+/// A key or mouse button, optionally chorded with modifiers (e.g. Shift+W, Ctrl+Mouse4).
+/// Plain binds ignore the modifier state, so Sprint + W keeps working next to a Shift+W
+/// chord.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) struct KeyBinding {
+    pub(super) input: InputBinding,
+    pub(super) modifiers: KeyModifiers,
+}
+
+impl KeyBinding {
+    pub(super) const fn plain(key: KeyCode) -> Self {
+        Self {
+            input: InputBinding::Key(key),
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    pub(super) fn pressed(
+        self,
+        keys: &ButtonInput<KeyCode>,
+        mouse: &ButtonInput<MouseButton>,
+    ) -> bool {
+        self.input.pressed(keys, mouse) && self.modifier_held(keys)
+    }
+
+    pub(super) fn just_pressed(
+        self,
+        keys: &ButtonInput<KeyCode>,
+        mouse: &ButtonInput<MouseButton>,
+    ) -> bool {
+        self.input.just_pressed(keys, mouse) && self.modifier_held(keys)
+    }
+
+    /// Releasing the main button ends a chord even if its modifiers were let go first.
+    pub(super) fn just_released(
+        self,
+        keys: &ButtonInput<KeyCode>,
+        mouse: &ButtonInput<MouseButton>,
+    ) -> bool {
+        self.input.just_released(keys, mouse)
+    }
+
+    fn modifier_held(self, keys: &ButtonInput<KeyCode>) -> bool {
+        self.modifiers.iter().all(|modifier| modifier.held(keys))
+    }
+
+    pub(super) fn label(self) -> String {
+        self.modifiers
+            .iter()
+            .map(|modifier| format!("{}+", modifier.name()))
+            .chain(std::iter::once(self.input.label()))
+            .collect()
+    }
+}
+
 #[derive(Resource, Debug, Clone)]
 pub(super) struct GameKeybinds {
-    pub(super) move_forward: Vec<KeyCode>,
-    pub(super) move_backward: Vec<KeyCode>,
-    pub(super) strafe_left: Vec<KeyCode>,
-    pub(super) strafe_right: Vec<KeyCode>,
-    pub(super) turn_left: Vec<KeyCode>,
-    pub(super) turn_right: Vec<KeyCode>,
-    pub(super) sprint: Vec<KeyCode>,
-    pub(super) jump: Vec<KeyCode>,
+    pub(super) move_forward: Vec<KeyBinding>,
+    pub(super) move_backward: Vec<KeyBinding>,
+    pub(super) strafe_left: Vec<KeyBinding>,
+    pub(super) strafe_right: Vec<KeyBinding>,
+    pub(super) turn_left: Vec<KeyBinding>,
+    pub(super) turn_right: Vec<KeyBinding>,
+    pub(super) sprint: Vec<KeyBinding>,
+    pub(super) jump: Vec<KeyBinding>,
+    pub(super) auto_walk: Vec<KeyBinding>,
+    pub(super) photo_mode: Vec<KeyBinding>,
+    pub(super) photo_screenshot: Vec<KeyBinding>,
+    pub(super) photo_roll_left: Vec<KeyBinding>,
+    pub(super) photo_roll_right: Vec<KeyBinding>,
+    pub(super) photo_reset_roll: Vec<KeyBinding>,
+    pub(super) photo_fly_down: Vec<KeyBinding>,
+    pub(super) pause_simulation: Vec<KeyBinding>,
+    pub(super) step_simulation: Vec<KeyBinding>,
+    pub(super) swap_shoulder: Vec<KeyBinding>,
+    pub(super) recenter_camera: Vec<KeyBinding>,
+    pub(super) crouch: Vec<KeyBinding>,
+    pub(super) measure: Vec<KeyBinding>,
 }
 
 impl Default for GameKeybinds {
     fn default() -> Self {
         Self {
-            move_forward: vec![KeyCode::KeyW],
-            move_backward: vec![KeyCode::KeyS],
-            strafe_left: vec![KeyCode::KeyQ],
-            strafe_right: vec![KeyCode::KeyE],
-            turn_left: vec![KeyCode::KeyA],
-            turn_right: vec![KeyCode::KeyD],
-            sprint: vec![KeyCode::ShiftLeft],
-            jump: vec![KeyCode::Space],
+            move_forward: vec![KeyBinding::plain(KeyCode::KeyW)],
+            move_backward: vec![KeyBinding::plain(KeyCode::KeyS)],
+            strafe_left: vec![KeyBinding::plain(KeyCode::KeyQ)],
+            strafe_right: vec![KeyBinding::plain(KeyCode::KeyE)],
+            turn_left: vec![KeyBinding::plain(KeyCode::KeyA)],
+            turn_right: vec![KeyBinding::plain(KeyCode::KeyD)],
+            sprint: vec![KeyBinding::plain(KeyCode::ShiftLeft)],
+            jump: vec![KeyBinding::plain(KeyCode::Space)],
+            auto_walk: vec![KeyBinding::plain(KeyCode::KeyR)],
+            photo_mode: vec![KeyBinding::plain(KeyCode::KeyP)],
+            photo_screenshot: vec![KeyBinding::plain(KeyCode::F12)],
+            photo_roll_left: vec![KeyBinding::plain(KeyCode::KeyZ)],
+            photo_roll_right: vec![KeyBinding::plain(KeyCode::KeyC)],
+            photo_reset_roll: vec![KeyBinding::plain(KeyCode::KeyX)],
+            photo_fly_down: vec![KeyBinding::plain(KeyCode::ControlLeft)],
+            pause_simulation: vec![KeyBinding::plain(KeyCode::F6)],
+            step_simulation: vec![KeyBinding::plain(KeyCode::F7)],
+            swap_shoulder: vec![KeyBinding::plain(KeyCode::KeyV)],
+            recenter_camera: vec![KeyBinding::plain(KeyCode::KeyT)],
+            crouch: vec![KeyBinding::plain(KeyCode::ControlLeft)],
+            measure: vec![KeyBinding {
+                input: InputBinding::Mouse(MouseButton::Middle),
+                modifiers: KeyModifiers::NONE,
+            }],
         }
     }
 }
 
 impl GameKeybinds {
-    pub(super) fn keys_for(&self, action: GameAction) -> &[KeyCode] {
+    pub(super) fn keys_for(&self, action: GameAction) -> &[KeyBinding] {
         match action {
             GameAction::MoveForward => &self.move_forward,
             GameAction::MoveBackward => &self.move_backward,
@@ -149,10 +669,23 @@ impl GameKeybinds {
             GameAction::TurnRight => &self.turn_right,
             GameAction::Sprint => &self.sprint,
             GameAction::Jump => &self.jump,
+            GameAction::AutoWalk => &self.auto_walk,
+            GameAction::PhotoMode => &self.photo_mode,
+            GameAction::PhotoScreenshot => &self.photo_screenshot,
+            GameAction::PhotoRollLeft => &self.photo_roll_left,
+            GameAction::PhotoRollRight => &self.photo_roll_right,
+            GameAction::PhotoResetRoll => &self.photo_reset_roll,
+            GameAction::PhotoFlyDown => &self.photo_fly_down,
+            GameAction::PauseSimulation => &self.pause_simulation,
+            GameAction::StepSimulation => &self.step_simulation,
+            GameAction::SwapShoulder => &self.swap_shoulder,
+            GameAction::RecenterCamera => &self.recenter_camera,
+            GameAction::Crouch => &self.crouch,
+            GameAction::Measure => &self.measure,
         }
     }
 
-    pub(super) fn keys_for_mut(&mut self, action: GameAction) -> &mut Vec<KeyCode> {
+    pub(super) fn keys_for_mut(&mut self, action: GameAction) -> &mut Vec<KeyBinding> {
         match action {
             GameAction::MoveForward => &mut self.move_forward,
             GameAction::MoveBackward => &mut self.move_backward,
@@ -162,24 +695,56 @@ impl GameKeybinds {
             GameAction::TurnRight => &mut self.turn_right,
             GameAction::Sprint => &mut self.sprint,
             GameAction::Jump => &mut self.jump,
+            GameAction::AutoWalk => &mut self.auto_walk,
+            GameAction::PhotoMode => &mut self.photo_mode,
+            GameAction::PhotoScreenshot => &mut self.photo_screenshot,
+            GameAction::PhotoRollLeft => &mut self.photo_roll_left,
+            GameAction::PhotoRollRight => &mut self.photo_roll_right,
+            GameAction::PhotoResetRoll => &mut self.photo_reset_roll,
+            GameAction::PhotoFlyDown => &mut self.photo_fly_down,
+            GameAction::PauseSimulation => &mut self.pause_simulation,
+            GameAction::StepSimulation => &mut self.step_simulation,
+            GameAction::SwapShoulder => &mut self.swap_shoulder,
+            GameAction::RecenterCamera => &mut self.recenter_camera,
+            GameAction::Crouch => &mut self.crouch,
+            GameAction::Measure => &mut self.measure,
         }
     }
 
-    pub(super) fn action_pressed(&self, input: &ButtonInput<KeyCode>, action: GameAction) -> bool {
-        self.keys_for(action).iter().any(|key| input.pressed(*key))
+    pub(super) fn action_pressed(
+        &self,
+        keys: &ButtonInput<KeyCode>,
+        mouse: &ButtonInput<MouseButton>,
+        action: GameAction,
+    ) -> bool {
+        self.keys_for(action)
+            .iter()
+            .any(|key| key.pressed(keys, mouse))
     }
 
     pub(super) fn action_just_pressed(
         &self,
-        input: &ButtonInput<KeyCode>,
+        keys: &ButtonInput<KeyCode>,
+        mouse: &ButtonInput<MouseButton>,
         action: GameAction,
     ) -> bool {
         self.keys_for(action)
             .iter()
-            .any(|key| input.just_pressed(*key))
+            .any(|key| key.just_pressed(keys, mouse))
     }
 
-    pub(super) fn add_key(&mut self, action: GameAction, key: KeyCode) -> bool {
+    pub(super) fn action_just_released(
+        &self,
+        keys: &ButtonInput<KeyCode>,
+        mouse: &ButtonInput<MouseButton>,
+        action: GameAction,
+    ) -> bool {
+        self.keys_for(action)
+            .iter()
+            .any(|key| key.just_released(keys, mouse))
+    }
+
+    pub(super) fn add_key(&mut self, action: GameAction, key: KeyBinding) -> bool {
         let keys = self.keys_for_mut(action);
         if keys.contains(&key) {
             return false;
@@ -189,9 +754,9 @@ impl GameKeybinds {
         true
     }
 
-    pub(super) fn remove_key(&mut self, action: GameAction, key: KeyCode) -> bool {
+    pub(super) fn remove_key(&mut self, action: GameAction, key: KeyBinding) -> bool {
         let keys = self.keys_for_mut(action);
-        if keys.len() <= 1 {
+        if keys.len() <= 1 && action.essential() {
             return false;
         }
 
@@ -200,21 +765,43 @@ impl GameKeybinds {
         old_len != keys.len()
     }
 
-    pub(super) fn has_key(&self, action: GameAction, key: KeyCode) -> bool {
+    /// Drops every bind of `action` except its first.
+    pub(super) fn keep_primary_key(&mut self, action: GameAction) {
+        self.keys_for_mut(action).truncate(1);
+        self.ensure_non_empty();
+    }
+
+    pub(super) fn restore_default(&mut self, action: GameAction) {
+        *self.keys_for_mut(action) = GameKeybinds::default().keys_for(action).to_vec();
+        self.ensure_non_empty();
+    }
+
+    /// Classic layout: A/D strafe and Q/E turn, the reverse of the defaults.
+    pub(super) fn apply_classic_wasd(&mut self) {
+        self.strafe_left = vec![KeyBinding::plain(KeyCode::KeyA)];
+        self.strafe_right = vec![KeyBinding::plain(KeyCode::KeyD)];
+        self.turn_left = vec![KeyBinding::plain(KeyCode::KeyQ)];
+        self.turn_right = vec![KeyBinding::plain(KeyCode::KeyE)];
+    }
+
+    pub(super) fn has_key(&self, action: GameAction, key: KeyBinding) -> bool {
         self.keys_for(action).contains(&key)
     }
 
     pub(super) fn display_keys(&self, action: GameAction) -> String {
+        if self.keys_for(action).is_empty() {
+            return "<unbound>".to_string();
+        }
         self.keys_for(action)
             .iter()
-            .map(|key| keycode_to_label(*key))
+            .map(|key| key.label())
             .collect::<Vec<_>>()
             .join(", ")
     }
 
     pub(super) fn ensure_non_empty(&mut self) {
         for action in ACTION_ORDER {
-            if self.keys_for(action).is_empty() {
+            if action.essential() && self.keys_for(action).is_empty() {
                 let fallback = GameKeybinds::default();
                 self.keys_for_mut(action).push(fallback.keys_for(action)[0]);
             }
@@ -223,6 +810,7 @@ impl GameKeybinds {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub(super) struct PersistedKeybinds {
     pub(super) move_forward: String,
     pub(super) move_backward: String,
@@ -232,6 +820,19 @@ pub(super) struct PersistedKeybinds {
     pub(super) turn_right: String,
     pub(super) sprint: String,
     pub(super) jump: String,
+    pub(super) auto_walk: String,
+    pub(super) photo_mode: String,
+    pub(super) photo_screenshot: String,
+    pub(super) photo_roll_left: String,
+    pub(super) photo_roll_right: String,
+    pub(super) photo_reset_roll: String,
+    pub(super) photo_fly_down: String,
+    pub(super) pause_simulation: String,
+    pub(super) step_simulation: String,
+    pub(super) swap_shoulder: String,
+    pub(super) recenter_camera: String,
+    pub(super) crouch: String,
+    pub(super) measure: String,
 }
 
 impl Default for PersistedKeybinds {
@@ -243,47 +844,180 @@ impl Default for PersistedKeybinds {
 impl PersistedKeybinds {
     pub(super) fn from_runtime(bindings: &GameKeybinds) -> Self {
         Self {
-            move_forward: keycodes_to_names(bindings.keys_for(GameAction::MoveForward)),
-            move_backward: keycodes_to_names(bindings.keys_for(GameAction::MoveBackward)),
-            strafe_left: keycodes_to_names(bindings.keys_for(GameAction::StrafeLeft)),
-            strafe_right: keycodes_to_names(bindings.keys_for(GameAction::StrafeRight)),
-            turn_left: keycodes_to_names(bindings.keys_for(GameAction::TurnLeft)),
-            turn_right: keycodes_to_names(bindings.keys_for(GameAction::TurnRight)),
-            sprint: keycodes_to_names(bindings.keys_for(GameAction::Sprint)),
-            jump: keycodes_to_names(bindings.keys_for(GameAction::Jump)),
+            move_forward: bindings_to_names(bindings.keys_for(GameAction::MoveForward)),
+            move_backward: bindings_to_names(bindings.keys_for(GameAction::MoveBackward)),
+            strafe_left: bindings_to_names(bindings.keys_for(GameAction::StrafeLeft)),
+            strafe_right: bindings_to_names(bindings.keys_for(GameAction::StrafeRight)),
+            turn_left: bindings_to_names(bindings.keys_for(GameAction::TurnLeft)),
+            turn_right: bindings_to_names(bindings.keys_for(GameAction::TurnRight)),
+            sprint: bindings_to_names(bindings.keys_for(GameAction::Sprint)),
+            jump: bindings_to_names(bindings.keys_for(GameAction::Jump)),
+            auto_walk: bindings_to_names(bindings.keys_for(GameAction::AutoWalk)),
+            photo_mode: bindings_to_names(bindings.keys_for(GameAction::PhotoMode)),
+            photo_screenshot: bindings_to_names(bindings.keys_for(GameAction::PhotoScreenshot)),
+            photo_roll_left: bindings_to_names(bindings.keys_for(GameAction::PhotoRollLeft)),
+            photo_roll_right: bindings_to_names(bindings.keys_for(GameAction::PhotoRollRight)),
+            photo_reset_roll: bindings_to_names(bindings.keys_for(GameAction::PhotoResetRoll)),
+            photo_fly_down: bindings_to_names(bindings.keys_for(GameAction::PhotoFlyDown)),
+            pause_simulation: bindings_to_names(bindings.keys_for(GameAction::PauseSimulation)),
+            step_simulation: bindings_to_names(bindings.keys_for(GameAction::StepSimulation)),
+            swap_shoulder: bindings_to_names(bindings.keys_for(GameAction::SwapShoulder)),
+            recenter_camera: bindings_to_names(bindings.keys_for(GameAction::RecenterCamera)),
+            crouch: bindings_to_names(bindings.keys_for(GameAction::Crouch)),
+            measure: bindings_to_names(bindings.keys_for(GameAction::Measure)),
         }
     }
 
     pub(super) fn to_runtime(&self) -> GameKeybinds {
         let mut runtime = GameKeybinds {
-            move_forward: keycodes_from_names(&self.move_forward),
-            move_backward: keycodes_from_names(&self.move_backward),
-            strafe_left: keycodes_from_names(&self.strafe_left),
-            strafe_right: keycodes_from_names(&self.strafe_right),
-            turn_left: keycodes_from_names(&self.turn_left),
-            turn_right: keycodes_from_names(&self.turn_right),
-            sprint: keycodes_from_names(&self.sprint),
-            jump: keycodes_from_names(&self.jump),
+            move_forward: bindings_from_names(&self.move_forward),
+            move_backward: bindings_from_names(&self.move_backward),
+            strafe_left: bindings_from_names(&self.strafe_left),
+            strafe_right: bindings_from_names(&self.strafe_right),
+            turn_left: bindings_from_names(&self.turn_left),
+            turn_right: bindings_from_names(&self.turn_right),
+            sprint: bindings_from_names(&self.sprint),
+            jump: bindings_from_names(&self.jump),
+            auto_walk: bindings_from_names(&self.auto_walk),
+            photo_mode: bindings_from_names(&self.photo_mode),
+            photo_screenshot: bindings_from_names(&self.photo_screenshot),
+            photo_roll_left: bindings_from_names(&self.photo_roll_left),
+            photo_roll_right: bindings_from_names(&self.photo_roll_right),
+            photo_reset_roll: bindings_from_names(&self.photo_reset_roll),
+            photo_fly_down: bindings_from_names(&self.photo_fly_down),
+            pause_simulation: bindings_from_names(&self.pause_simulation),
+            step_simulation: bindings_from_names(&self.step_simulation),
+            swap_shoulder: bindings_from_names(&self.swap_shoulder),
+            recenter_camera: bindings_from_names(&self.recenter_camera),
+            crouch: bindings_from_names(&self.crouch),
+            measure: bindings_from_names(&self.measure),
         };
         runtime.ensure_non_empty();
         runtime
     }
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(default)]
 pub(super) struct PersistedConfig {
     pub(super) settings: GameSettings,
     pub(super) keybinds: PersistedKeybinds,
     pub(super) debug: DebugSettings,
+    /// Id of the last scenario that loaded, offered by the start menu's Continue button.
+    pub(super) last_scenario_id: Option<String>,
 }
 
-impl Default for PersistedConfig {
-    fn default() -> Self {
-        Self {
-            settings: GameSettings::default(),
-            keybinds: PersistedKeybinds::default(),
-            debug: DebugSettings::default(),
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn action_just_released_fires_once_per_release() {
+        let mut keybinds = GameKeybinds::default();
+        keybinds.add_key(
+            GameAction::Jump,
+            KeyBinding {
+                input: InputBinding::Key(KeyCode::KeyJ),
+                modifiers: KeyModifiers::NONE.with(KeyModifier::Ctrl),
+            },
+        );
+        let mut input = ButtonInput::<KeyCode>::default();
+        let mouse = ButtonInput::<MouseButton>::default();
+
+        input.press(KeyCode::Space);
+        assert!(!keybinds.action_just_released(&input, &mouse, GameAction::Jump));
+
+        input.clear();
+        input.release(KeyCode::Space);
+        assert!(keybinds.action_just_released(&input, &mouse, GameAction::Jump));
+        assert!(!keybinds.action_just_released(&input, &mouse, GameAction::Sprint));
+
+        input.clear();
+        assert!(!keybinds.action_just_released(&input, &mouse, GameAction::Jump));
+
+        // A chord ends when its key goes up, even after the modifier was released.
+        input.press(KeyCode::ControlLeft);
+        input.press(KeyCode::KeyJ);
+        input.clear();
+        input.release(KeyCode::ControlLeft);
+        input.clear();
+        input.release(KeyCode::KeyJ);
+        assert!(keybinds.action_just_released(&input, &mouse, GameAction::Jump));
+    }
+
+    #[test]
+    fn mouse_buttons_trigger_their_actions() {
+        let mut keybinds = GameKeybinds::default();
+        keybinds.add_key(
+            GameAction::Sprint,
+            KeyBinding {
+                input: InputBinding::Mouse(MouseButton::Back),
+                modifiers: KeyModifiers::NONE,
+            },
+        );
+        keybinds.add_key(
+            GameAction::Jump,
+            KeyBinding {
+                input: InputBinding::Mouse(MouseButton::Forward),
+                modifiers: KeyModifiers::NONE.with(KeyModifier::Shift),
+            },
+        );
+        let mut keys = ButtonInput::<KeyCode>::default();
+        let mut mouse = ButtonInput::<MouseButton>::default();
+
+        mouse.press(MouseButton::Back);
+        mouse.press(MouseButton::Forward);
+        assert!(keybinds.action_pressed(&keys, &mouse, GameAction::Sprint));
+        assert!(!keybinds.action_just_pressed(&keys, &mouse, GameAction::Jump));
+
+        mouse.clear();
+        mouse.release(MouseButton::Forward);
+        keys.press(KeyCode::ShiftLeft);
+        mouse.press(MouseButton::Forward);
+        assert!(keybinds.action_just_pressed(&keys, &mouse, GameAction::Jump));
+        assert_eq!(
+            keybinds.display_keys(GameAction::Sprint),
+            "ShiftLeft, Mouse 4"
+        );
+    }
+
+    #[test]
+    fn chaos_jitter_is_seeded_and_keeps_columns_apart() {
+        let scenario = &super::super::io_and_scenarios::default_scenarios()[0];
+        let half = scenario.crate_half_extents();
+        let max_offset = (scenario.crate_spacing - 2.0 * half.x.max(half.z)) * 0.5;
+        for (x, z) in scenario.crate_cells() {
+            let jitter = scenario.chaos_crate_jitter(7, x, z);
+            assert_eq!(jitter, scenario.chaos_crate_jitter(7, x, z));
+            assert!(jitter.0.abs().max_element() < max_offset.max(1e-6));
+            assert!(jitter.1 < 4);
         }
+        let layout = |seed| {
+            scenario
+                .crate_cells()
+                .map(|(x, z)| scenario.chaos_crate_jitter(seed, x, z))
+                .collect::<Vec<_>>()
+        };
+        assert_ne!(layout(7), layout(8));
+    }
+
+    #[test]
+    fn only_non_essential_actions_can_be_unbound() {
+        let mut keybinds = GameKeybinds::default();
+        let jump = keybinds.keys_for(GameAction::Jump)[0];
+        let auto_walk = keybinds.keys_for(GameAction::AutoWalk)[0];
+        assert!(!keybinds.remove_key(GameAction::Jump, jump));
+        assert!(keybinds.remove_key(GameAction::AutoWalk, auto_walk));
+        assert_eq!(keybinds.display_keys(GameAction::AutoWalk), "<unbound>");
+
+        let mut input = ButtonInput::<KeyCode>::default();
+        input.press(KeyCode::KeyR);
+        let mouse = ButtonInput::<MouseButton>::default();
+        assert!(!keybinds.action_pressed(&input, &mouse, GameAction::AutoWalk));
+
+        // The empty bind survives a save/load round trip instead of reverting to default.
+        let restored = PersistedKeybinds::from_runtime(&keybinds).to_runtime();
+        assert!(restored.keys_for(GameAction::AutoWalk).is_empty());
+        assert_eq!(restored.keys_for(GameAction::Jump), &[jump]);
     }
 }
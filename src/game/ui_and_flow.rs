@@ -1,18 +1,62 @@
 use super::*;
+use bevy::camera::visibility::RenderLayers;
+use bevy::camera::{ImageRenderTarget, RenderTarget};
+use bevy::image::{
+    BevyDefault, ImageAddressMode, ImageFilterMode, ImageSampler, ImageSamplerDescriptor,
+};
+use bevy::math::Affine2;
+use bevy::render::render_resource::{Extent3d, TextureFormat};
+use bevy::window::{WindowCloseRequested, WindowRef};
 use bevy_egui::{EguiContexts, PrimaryEguiContext, egui};
 
+const FOG_ALPHA_LEVELS: u8 = 32;
+const PROP_LOD_FAR_SHADE: f32 = 0.7;
+const NOTICE_DURATION_SECS: f32 = 6.0;
+const GROUND_TEXTURE_SIZE: usize = 256;
+/// Ground meters covered by one repeat of the ground texture.
+const GROUND_TEXTURE_TILE_METERS: f32 = 4.0;
+/// Render layer of the start menu's scenario preview; layer 1 is the UI overlay.
+const SCENARIO_PREVIEW_LAYER: usize = 2;
+const SCENARIO_PREVIEW_SIZE: (u32, u32) = (480, 270);
+const SCENARIO_PREVIEW_FRAME_SECS: f32 = 0.1;
+const SCENARIO_PREVIEW_SPIN_SPEED: f32 = 0.12;
+/// Greenwood's test stair lanes as (rise, run), from shallow to steep.
+pub(super) const STAIR_PROFILES: [(f32, f32); 5] = [
+    (0.16, 1.05),
+    (0.20, 0.92),
+    (0.24, 0.80),
+    (0.30, 0.72),
+    (0.36, 0.64),
+];
+const STAIR_WIDTH: f32 = 2.2;
+const STAIR_DEPTH: f32 = 0.82;
+const STAIRS_PER_PROFILE: usize = 5;
+
 pub(super) fn setup_start_menu(
     mut commands: Commands,
     flow: Res<GameFlowState>,
     scenarios: Res<ScenarioCatalog>,
+    settings: Res<GameSettings>,
+    last_scenario: Res<LastScenario>,
 ) {
     if flow.pending_scenario.is_none() {
         commands.spawn((Camera2d, StartMenuCamera));
-        spawn_start_menu_ui(&mut commands, &scenarios);
+        spawn_start_menu_ui(&mut commands, &scenarios, &settings, &last_scenario);
     }
 }
 
-pub(super) fn spawn_start_menu_ui(commands: &mut Commands, scenarios: &ScenarioCatalog) {
+pub(super) fn spawn_start_menu_ui(
+    commands: &mut Commands,
+    scenarios: &ScenarioCatalog,
+    settings: &GameSettings,
+    last_scenario: &LastScenario,
+) {
+    let language = settings.language;
+    // A stored id that is no longer in the catalog just means no Continue and no highlight.
+    let last_index = last_scenario
+        .0
+        .as_deref()
+        .and_then(|id| scenarios.index_by_id(id));
     commands
         .spawn((
             StartMenuRoot,
@@ -39,32 +83,114 @@ pub(super) fn spawn_start_menu_ui(commands: &mut Commands, scenarios: &ScenarioC
             ))
             .with_children(|panel| {
                 panel.spawn((
-                    Text::new("Selecteer Scenario"),
+                    Text::new(tr(language, "start.title")),
                     Node {
                         margin: UiRect::bottom(px(12)),
                         ..default()
                     },
                 ));
 
+                if let Some(index) = last_index {
+                    panel
+                        .spawn((
+                            Button,
+                            StartMenuButton(StartMenuButtonAction::Continue(index)),
+                            menu_button_node(),
+                            menu_button_normal_color(),
+                        ))
+                        .with_child(Text::new(format!(
+                            "{}: {}",
+                            tr(language, "start.continue"),
+                            scenarios.scenarios[index].name_in(language)
+                        )));
+                }
+
+                if let Some(quick_start) = scenarios
+                    .scenarios
+                    .get(scenarios.quick_start_index(settings.quick_start_scenario.as_deref()))
+                {
+                    panel
+                        .spawn((
+                            Button,
+                            StartMenuButton(StartMenuButtonAction::QuickStart),
+                            Node {
+                                height: px(52),
+                                margin: UiRect::bottom(px(14)),
+                                ..menu_button_node()
+                            },
+                            menu_button_normal_color(),
+                        ))
+                        .with_child((
+                            Text::new(format!(
+                                "{}: {}",
+                                tr(language, "start.quick_start"),
+                                quick_start.name_in(language)
+                            )),
+                            TextFont::from_font_size(22.0),
+                        ));
+                }
+
                 for (index, scenario) in scenarios.scenarios.iter().enumerate() {
                     panel.spawn((
-                        Text::new(format!("{}: {}", scenario.name, scenario.description)),
+                        Text::new(format!(
+                            "{}: {}",
+                            scenario.name_in(language),
+                            scenario.description_in(language)
+                        )),
                         Node {
                             margin: UiRect::bottom(px(6)),
                             ..default()
                         },
                     ));
 
-                    panel
-                        .spawn((
-                            Button,
-                            StartMenuButton(StartMenuButtonAction::StartScenario(index)),
-                            menu_button_node(),
-                            menu_button_normal_color(),
-                        ))
-                        .with_child(Text::new(format!("Start {}", scenario.name)));
+                    let stats = scenario_stats(scenario, settings.crate_budget);
+                    panel.spawn((
+                        ScenarioStatsText,
+                        Text::new(
+                            tr(language, "start.stats")
+                                .replace("{total}", &stats.total().to_string())
+                                .replace("{crates}", &stats.crates.to_string())
+                                .replace("{walls}", &stats.walls.to_string())
+                                .replace("{stairs}", &stats.stairs.to_string())
+                                .replace("{props}", &stats.props.to_string()),
+                        ),
+                        TextFont::from_font_size(14.0),
+                        TextColor(Color::srgb(0.70, 0.74, 0.80)),
+                        Node {
+                            display: stats_display(settings.start_menu_stats),
+                            margin: UiRect::bottom(px(6)),
+                            ..default()
+                        },
+                    ));
+
+                    let mut button = panel.spawn((
+                        Button,
+                        StartMenuButton(StartMenuButtonAction::StartScenario(index)),
+                        menu_button_node(),
+                        menu_button_normal_color(),
+                    ));
+                    if last_index == Some(index) {
+                        button.insert((LastPlayedScenarioButton, menu_button_selected_color()));
+                    }
+                    button.with_child(Text::new(format!(
+                        "{} {}",
+                        tr(language, "start.start"),
+                        scenario.name_in(language)
+                    )));
                 }
 
+                panel
+                    .spawn((
+                        Button,
+                        StartMenuButton(StartMenuButtonAction::ToggleScenarioStats),
+                        menu_button_node(),
+                        menu_button_normal_color(),
+                    ))
+                    .with_child((
+                        ScenarioStatsToggleLabel,
+                        Text::new(stats_toggle_label(settings)),
+                    ));
+
                 panel
                     .spawn((
                         Button,
@@ -72,31 +198,109 @@ pub(super) fn spawn_start_menu_ui(commands: &mut Commands, scenarios: &ScenarioC
                         menu_button_node(),
                         menu_button_normal_color(),
                     ))
-                    .with_child(Text::new("Exit"));
+                    .with_child(Text::new(tr(language, "start.exit")));
             });
         });
 }
 
+fn stats_display(shown: bool) -> Display {
+    if shown { Display::Flex } else { Display::None }
+}
+
+fn stats_toggle_label(settings: &GameSettings) -> &'static str {
+    if settings.start_menu_stats {
+        tr(settings.language, "start.stats_on")
+    } else {
+        tr(settings.language, "start.stats_off")
+    }
+}
+
+/// Collider counts `spawn_scenario_world` will produce for `scenario`; keep the two in sync.
+pub(super) fn scenario_stats(scenario: &ScenarioDefinition, crate_budget: u32) -> ScenarioStats {
+    let crate_budget = if crate_budget == 0 {
+        u32::MAX
+    } else {
+        crate_budget
+    };
+    // Same whole-column cut-off as the spawn loop.
+    let mut crates = 0_u32;
+    for (x, z) in scenario.crate_cells() {
+        let levels = scenario.crate_stack_levels(x, z);
+        if crates + levels > crate_budget {
+            break;
+        }
+        crates += levels;
+    }
+    let greenwood = scenario.id == "greenwood";
+    ScenarioStats {
+        crates,
+        walls: (2 * scenario.wall_count + 1).max(0) as u32,
+        stairs: if greenwood {
+            (STAIR_PROFILES.len() * STAIRS_PER_PROFILE) as u32
+        } else {
+            0
+        },
+        // Tower and target dummy, plus Greenwood's table.
+        props: 2 + u32::from(greenwood),
+    }
+}
+
+/// Shows or hides the start menu's scenario stats when the setting flips.
+pub(super) fn sync_start_menu_stats(
+    settings: Res<GameSettings>,
+    mut stats: Query<&mut Node, With<ScenarioStatsText>>,
+    mut labels: Query<&mut Text, With<ScenarioStatsToggleLabel>>,
+) {
+    if !settings.is_changed() {
+        return;
+    }
+    for mut node in &mut stats {
+        node.display = stats_display(settings.start_menu_stats);
+    }
+    for mut label in &mut labels {
+        **label = stats_toggle_label(&settings).to_string();
+    }
+}
+
+#[allow(clippy::type_complexity)]
 pub(super) fn handle_start_menu_buttons(
     mut interactions: Query<
-        (&Interaction, &StartMenuButton, &mut BackgroundColor),
+        (
+            &Interaction,
+            &StartMenuButton,
+            &mut BackgroundColor,
+            Has<LastPlayedScenarioButton>,
+        ),
         (Changed<Interaction>, With<Button>),
     >,
+    scenarios: Res<ScenarioCatalog>,
     mut flow: ResMut<GameFlowState>,
+    mut settings: ResMut<GameSettings>,
     mut app_exit: MessageWriter<AppExit>,
 ) {
     if flow.in_game {
         return;
     }
 
-    for (interaction, button, mut background) in &mut interactions {
+    for (interaction, button, mut background, last_played) in &mut interactions {
         match *interaction {
             Interaction::Pressed => {
                 *background = menu_button_pressed_color();
                 match button.0 {
+                    StartMenuButtonAction::Continue(scenario) => {
+                        flow.pending_scenario = Some(scenario);
+                    }
+                    StartMenuButtonAction::QuickStart => {
+                        flow.pending_scenario = Some(
+                            scenarios.quick_start_index(settings.quick_start_scenario.as_deref()),
+                        );
+                    }
                     StartMenuButtonAction::StartScenario(scenario) => {
                         flow.pending_scenario = Some(scenario);
                     }
+                    StartMenuButtonAction::ToggleScenarioStats => {
+                        settings.start_menu_stats = !settings.start_menu_stats;
+                    }
                     StartMenuButtonAction::ExitGame => {
                         app_exit.write(AppExit::Success);
                     }
@@ -105,6 +309,9 @@ pub(super) fn handle_start_menu_buttons(
             Interaction::Hovered => {
                 *background = menu_button_hover_color();
             }
+            Interaction::None if last_played => {
+                *background = menu_button_selected_color();
+            }
             Interaction::None => {
                 *background = menu_button_normal_color();
             }
@@ -112,6 +319,7 @@ pub(super) fn handle_start_menu_buttons(
     }
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub(super) fn load_pending_scenario(
     mut commands: Commands,
     mut flow: ResMut<GameFlowState>,
@@ -120,22 +328,39 @@ pub(super) fn load_pending_scenario(
     mut settings: ResMut<GameSettings>,
     mut menu: ResMut<MenuState>,
     start_menu_roots: Query<Entity, With<StartMenuRoot>>,
-    start_menu_cameras: Query<Entity, With<StartMenuCamera>>,
+    start_menu_cameras: Query<
+        Entity,
+        (
+            Or<(With<StartMenuCamera>, With<ScenarioPreview>)>,
+            Without<ImageNode>,
+        ),
+    >,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
     mut images: ResMut<Assets<Image>>,
+    mut fog_material_cache: ResMut<FogAlphaMaterialCache>,
+    mut measurement: ResMut<MeasurementState>,
+    mut notice: ResMut<PendingNotice>,
+    mut last_scenario: ResMut<LastScenario>,
 ) {
     let Some(scenario_index) = flow.pending_scenario.take() else {
         return;
     };
     let Some(scenario) = scenarios.scenarios.get(scenario_index).cloned() else {
         eprintln!("Scenario index {} is ongeldig", scenario_index);
+        notice.0 = Some(
+            tr(settings.language, "notice.scenario_invalid")
+                .replace("{id}", &scenario_index.to_string())
+                .replace("{available}", &scenarios.available_ids()),
+        );
         return;
     };
 
     for root in &start_menu_roots {
         commands.entity(root).despawn();
     }
+    // Also drops the scenario preview, so its camera never overlaps the game's. The preview's
+    // image node is a child of the start menu root and already goes with it.
     for camera in &start_menu_cameras {
         commands.entity(camera).despawn();
     }
@@ -144,6 +369,8 @@ pub(super) fn load_pending_scenario(
     menu.screen = MenuScreen::Main;
     menu.awaiting_rebind = None;
     menu.dirty = false;
+    fog_material_cache.variants.clear();
+    measurement.points.clear();
 
     spawn_scenario_world(
         &mut commands,
@@ -152,9 +379,257 @@ pub(super) fn load_pending_scenario(
         &mut materials,
         &mut images,
         &scenario,
+        &settings,
     );
     settings.set_changed();
     flow.in_game = true;
+    flow.current_scenario = Some(scenario_index);
+    last_scenario.0 = Some(scenario.id.clone());
+}
+
+/// Center and half extents of each tread of a stair lane rising toward +Z from `base_z`.
+pub(super) fn stair_treads(
+    lane_x: f32,
+    base_z: f32,
+    rise: f32,
+    run: f32,
+) -> impl Iterator<Item = (Vec3, Vec3)> {
+    (0..STAIRS_PER_PROFILE).map(move |step| {
+        let idx = step as f32;
+        let center = Vec3::new(
+            lane_x,
+            rise * 0.5 + idx * rise,
+            base_z + idx * run + STAIR_DEPTH * 0.5,
+        );
+        (center, Vec3::new(STAIR_WIDTH, rise, STAIR_DEPTH) * 0.5)
+    })
+}
+
+/// Puts a queued `PendingNotice` on screen, replacing any notice still showing.
+pub(super) fn show_pending_notice(
+    mut commands: Commands,
+    mut notice: ResMut<PendingNotice>,
+    existing: Query<Entity, With<TransientNotice>>,
+) {
+    let Some(message) = notice.0.take() else {
+        return;
+    };
+    for entity in &existing {
+        commands.entity(entity).despawn();
+    }
+    commands
+        .spawn((
+            TransientNotice {
+                remaining_secs: NOTICE_DURATION_SECS,
+            },
+            GlobalZIndex(800),
+            Node {
+                position_type: PositionType::Absolute,
+                top: px(24),
+                width: percent(100),
+                justify_content: JustifyContent::Center,
+                ..default()
+            },
+            Pickable::IGNORE,
+        ))
+        .with_children(|root| {
+            root.spawn((
+                Node {
+                    max_width: px(720),
+                    padding: UiRect::axes(px(16), px(10)),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(0.45, 0.08, 0.08, 0.92)),
+            ))
+            .with_child(Text::new(message));
+        });
+}
+
+pub(super) fn expire_transient_notices(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut notices: Query<(Entity, &mut TransientNotice)>,
+) {
+    for (entity, mut notice) in &mut notices {
+        notice.remaining_secs -= time.delta_secs();
+        if notice.remaining_secs <= 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Spawns a layout-only copy of `scenario` (ground, crates, walls, tower) on its own render
+/// layer, with an orbiting camera that renders it into a small image. Every entity gets
+/// `ScenarioPreview`, so despawning those removes the whole preview.
+pub(super) fn spawn_scenario_preview(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    images: &mut Assets<Image>,
+    scenario: &ScenarioDefinition,
+) -> Handle<Image> {
+    let layer = RenderLayers::layer(SCENARIO_PREVIEW_LAYER);
+    let image = images.add(Image::new_target_texture(
+        SCENARIO_PREVIEW_SIZE.0,
+        SCENARIO_PREVIEW_SIZE.1,
+        TextureFormat::bevy_default(),
+        None,
+    ));
+
+    let mut spawn_box = |center: Vec3, half_extents: Vec3, color: Color| {
+        commands.spawn((
+            ScenarioPreview,
+            Mesh3d(meshes.add(Cuboid::from_size(half_extents * 2.0))),
+            MeshMaterial3d(materials.add(StandardMaterial {
+                base_color: color,
+                perceptual_roughness: 0.9,
+                ..default()
+            })),
+            Transform::from_translation(center),
+            NotShadowCaster,
+            layer.clone(),
+        ));
+    };
+
+    let ground_extent = scenario.ground_extent;
+    spawn_box(
+        Vec3::new(0.0, -0.05, 0.0),
+        Vec3::new(ground_extent * 0.5, 0.05, ground_extent * 0.5),
+        Color::srgb(0.22, 0.43, 0.20),
+    );
+    let crate_half = scenario.crate_half_extents();
+    for (x, z) in scenario.crate_cells() {
+        let levels = scenario.crate_stack_levels(x, z) as f32;
+        let column_half = Vec3::new(crate_half.x, crate_half.y * levels, crate_half.z);
+        let center = Vec3::new(
+            x as f32 * scenario.crate_spacing,
+            column_half.y,
+            z as f32 * scenario.crate_spacing,
+        );
+        let [r, g, b] = CRATE_WOOD_TONES[scenario.crate_tone_index(x, z, 0)];
+        spawn_box(center, column_half, Color::srgb(r, g, b));
+    }
+    let wall_half = scenario.wall_half_extents();
+    for i in -scenario.wall_count..=scenario.wall_count {
+        let center = Vec3::new(
+            i as f32 * scenario.wall_spacing,
+            wall_half.y,
+            scenario.wall_z,
+        );
+        spawn_box(center, wall_half, Color::srgb(0.57, 0.52, 0.44));
+    }
+    let tower_half = scenario.tower_half_extents();
+    spawn_box(
+        Vec3::new(0.0, tower_half.y, scenario.tower_z),
+        tower_half,
+        Color::srgb(0.34, 0.38, 0.55),
+    );
+
+    commands.spawn((
+        ScenarioPreview,
+        DirectionalLight {
+            color: Color::srgb(1.0, 0.90, 0.70),
+            illuminance: 12_500.0,
+            ..default()
+        },
+        Transform::from_translation(scenario.sun_vec3()).looking_at(Vec3::ZERO, Vec3::Y),
+        layer.clone(),
+    ));
+
+    let radius = (ground_extent * 0.35).clamp(12.0, 60.0);
+    commands.spawn((
+        ScenarioPreview,
+        ScenarioPreviewCamera {
+            radius,
+            height: radius * 0.45,
+            yaw: 0.0,
+            since_render_secs: SCENARIO_PREVIEW_FRAME_SECS,
+        },
+        Camera3d::default(),
+        Camera {
+            order: -1,
+            clear_color: ClearColorConfig::Custom(Color::srgb(0.57, 0.70, 0.92)),
+            ..default()
+        },
+        RenderTarget::Image(image.clone().into()),
+        Msaa::Off,
+        Transform::default(),
+        layer,
+    ));
+
+    image
+}
+
+/// Shows a spinning preview of the first scenario behind the start menu while it is open
+/// and the setting allows it; despawns it otherwise.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn sync_scenario_preview(
+    mut commands: Commands,
+    settings: Res<GameSettings>,
+    scenarios: Res<ScenarioCatalog>,
+    start_menu_roots: Query<Entity, With<StartMenuRoot>>,
+    previews: Query<Entity, With<ScenarioPreview>>,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let root = start_menu_roots.single().ok();
+    let scenario = scenarios.scenarios.first();
+    let (Some(root), Some(scenario), true) = (root, scenario, settings.menu_scenario_preview)
+    else {
+        for entity in &previews {
+            commands.entity(entity).despawn();
+        }
+        return;
+    };
+    if !previews.is_empty() {
+        return;
+    }
+
+    let image = spawn_scenario_preview(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        &mut images,
+        scenario,
+    );
+    commands.entity(root).with_child((
+        ScenarioPreview,
+        ImageNode {
+            image,
+            color: Color::srgb(0.55, 0.55, 0.55),
+            ..default()
+        },
+        ZIndex(-1),
+        Node {
+            position_type: PositionType::Absolute,
+            width: percent(100),
+            height: percent(100),
+            ..default()
+        },
+    ));
+}
+
+/// Orbits the preview camera and re-renders it only every `SCENARIO_PREVIEW_FRAME_SECS`.
+pub(super) fn spin_scenario_preview(
+    time: Res<Time>,
+    mut cameras: Query<(&mut Camera, &mut Transform, &mut ScenarioPreviewCamera)>,
+) {
+    for (mut camera, mut transform, mut preview) in &mut cameras {
+        let dt = time.delta_secs();
+        preview.yaw += dt * SCENARIO_PREVIEW_SPIN_SPEED;
+        preview.since_render_secs += dt;
+        let render = preview.since_render_secs >= SCENARIO_PREVIEW_FRAME_SECS;
+        if render {
+            preview.since_render_secs = 0.0;
+            let offset = Quat::from_rotation_y(preview.yaw) * Vec3::new(0.0, 0.0, preview.radius);
+            *transform = Transform::from_translation(offset + Vec3::Y * preview.height)
+                .looking_at(Vec3::ZERO, Vec3::Y);
+        }
+        if camera.is_active != render {
+            camera.is_active = render;
+        }
+    }
 }
 
 fn default_distance_fog() -> DistanceFog {
@@ -281,18 +756,138 @@ fn distance_fog_from_debug(debug: &DebugSettings, anchor_offset: f32) -> Distanc
     }
 }
 
+/// Run condition for egui panels: the in-game UI camera carrying `PrimaryEguiContext` is
+/// despawned and respawned with each scenario, so there can be frames without one.
+pub(super) fn primary_egui_context_exists(cameras: Query<(), With<PrimaryEguiContext>>) -> bool {
+    !cameras.is_empty()
+}
+
+/// The primary egui context, or `None` when it can't be acquired this frame; every egui
+/// panel goes through this instead of unwrapping `ctx_mut`.
+fn primary_egui_context<'a>(contexts: &'a mut EguiContexts) -> Option<&'a mut egui::Context> {
+    contexts.ctx_mut().ok()
+}
+
+/// Shows what the input sources `player_move` and `third_person_camera` read resolve to
+/// this frame, for checking keybinds.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn input_debug_panel_ui(
+    mut contexts: EguiContexts,
+    flow: Res<GameFlowState>,
+    debug: Res<DebugSettings>,
+    settings: Res<GameSettings>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    keybinds: Res<GameKeybinds>,
+    mouse_motion: Res<AccumulatedMouseMotion>,
+    players: Query<&PlayerKinematics, With<Player>>,
+    rigs: Query<&ThirdPersonCameraRig>,
+) {
+    if !debug.show_input_panel || !flow.in_game {
+        return;
+    }
+    let Some(ctx) = primary_egui_context(&mut contexts) else {
+        return;
+    };
+
+    let (auto_walk, aiming) = players.single().map_or((false, false), |kinematics| {
+        (kinematics.auto_walk, kinematics.aiming)
+    });
+    let pressed = ACTION_ORDER
+        .into_iter()
+        .filter(|&action| keybinds.action_pressed(&keys, &mouse_buttons, action))
+        .map(GameAction::label)
+        .collect::<Vec<_>>();
+    let axes = movement_axes(
+        &keybinds,
+        &keys,
+        &mouse_buttons,
+        auto_walk,
+        aiming || settings.camera_relative_movement,
+    );
+
+    egui::Window::new("Input")
+        .collapsible(false)
+        .resizable(false)
+        .interactable(false)
+        .anchor(egui::Align2::LEFT_TOP, egui::vec2(18.0, 18.0))
+        .show(ctx, |ui| {
+            ui.label(format!(
+                "Actions: {}",
+                if pressed.is_empty() {
+                    "-".to_string()
+                } else {
+                    pressed.join(", ")
+                }
+            ));
+            ui.label(format!("Movement: ({:+.0}, {:+.0})", axes.x, axes.y));
+            ui.label(format!(
+                "Auto walk: {}  Aiming: {}",
+                if auto_walk { "on" } else { "off" },
+                if aiming { "on" } else { "off" }
+            ));
+            ui.label(format!(
+                "Mouse delta: ({:+.1}, {:+.1})",
+                mouse_motion.delta.x, mouse_motion.delta.y
+            ));
+            if let Ok(rig) = rigs.single() {
+                ui.label(format!(
+                    "Camera yaw/pitch: {:+.1}° / {:+.1}°",
+                    rig.yaw.to_degrees(),
+                    rig.pitch.to_degrees()
+                ));
+            }
+        });
+}
+
+/// Per-foot leg IK readout next to the animation debug gizmos: whether this frame's ankle
+/// target was reachable and how many frames it wasn't since the view was turned on.
+pub(super) fn ik_debug_panel_ui(
+    mut contexts: EguiContexts,
+    flow: Res<GameFlowState>,
+    debug: Res<DebugSettings>,
+    players: Query<&ProceduralHumanAnimState, With<Player>>,
+) {
+    if !debug.show_animation_debug || !flow.in_game {
+        return;
+    }
+    let Ok(anim_state) = players.single() else {
+        return;
+    };
+    let Some(ctx) = primary_egui_context(&mut contexts) else {
+        return;
+    };
+
+    egui::Window::new("Leg IK")
+        .collapsible(false)
+        .resizable(false)
+        .interactable(false)
+        .anchor(egui::Align2::LEFT_BOTTOM, egui::vec2(18.0, -18.0))
+        .show(ctx, |ui| {
+            for (side, foot) in ["Left", "Right"].into_iter().zip(&anim_state.foot_ik) {
+                ui.label(format!(
+                    "{side}: {}  miss {:.2} m  unreachable frames: {}",
+                    if foot.reachable { "ok" } else { "OUT OF REACH" },
+                    foot.attempted.distance(foot.achieved),
+                    foot.unreachable_frames
+                ));
+            }
+        });
+}
+
 pub(super) fn fog_debug_sliders_ui(
     mut contexts: EguiContexts,
     menu: Res<MenuState>,
     settings: Res<GameSettings>,
     keybinds: Res<GameKeybinds>,
+    last_scenario: Res<LastScenario>,
     mut debug: ResMut<DebugSettings>,
 ) {
     if !menu.open || menu.screen != MenuScreen::Debug {
         return;
     }
 
-    let Ok(ctx) = contexts.ctx_mut() else {
+    let Some(ctx) = primary_egui_context(&mut contexts) else {
         return;
     };
 
@@ -534,7 +1129,7 @@ pub(super) fn fog_debug_sliders_ui(
         });
 
     if changed {
-        save_persisted_config(&settings, &keybinds, &debug);
+        save_persisted_config(&settings, &keybinds, &debug, &last_scenario);
     }
 }
 
@@ -612,43 +1207,312 @@ fn create_debug_skybox_texture(images: &mut Assets<Image>) -> Handle<Image> {
     images.add(image)
 }
 
-pub(super) fn spawn_scenario_world(
-    commands: &mut Commands,
-    asset_server: &AssetServer,
-    meshes: &mut Assets<Mesh>,
-    materials: &mut Assets<StandardMaterial>,
-    images: &mut Assets<Image>,
-    scenario: &ScenarioDefinition,
-) {
-    let ground_extent = scenario.ground_extent;
-    let crate_grid_radius = scenario.crate_grid_radius;
+/// Value noise on a `cells`-wide lattice that wraps, so the texture built from it tiles.
+fn tiling_value_noise(x: f32, y: f32, cells: u32, seed: u32) -> f32 {
+    let hash = |ix: u32, iy: u32| {
+        let mut h = (ix % cells).wrapping_mul(0x27d4_eb2d)
+            ^ (iy % cells).wrapping_mul(0x1656_67b1)
+            ^ seed.wrapping_mul(0x9e37_79b9);
+        h ^= h >> 15;
+        h = h.wrapping_mul(0x85eb_ca6b);
+        h ^= h >> 13;
+        (h & 0xffff) as f32 / 65535.0
+    };
+    let (ix, iy) = (x.floor() as u32, y.floor() as u32);
+    let (fx, fy) = (x.fract(), y.fract());
+    let (sx, sy) = (fx * fx * (3.0 - 2.0 * fx), fy * fy * (3.0 - 2.0 * fy));
+    let top = hash(ix, iy) + (hash(ix + 1, iy) - hash(ix, iy)) * sx;
+    let bottom = hash(ix, iy + 1) + (hash(ix + 1, iy + 1) - hash(ix, iy + 1)) * sx;
+    top + (bottom - top) * sy
+}
+
+/// Tiling grass texture with dirt patches and speckle, with a full mip chain so the
+/// repeated pattern doesn't shimmer in the distance.
+fn create_ground_texture(images: &mut Assets<Image>) -> Handle<Image> {
+    let size = GROUND_TEXTURE_SIZE;
+    let grass = [0.22_f32, 0.43, 0.20];
+    let dirt = [0.38_f32, 0.31, 0.19];
+    let mut data = vec![0_u8; size * size * 4];
+
+    for y in 0..size {
+        let v = y as f32 / size as f32;
+        for x in 0..size {
+            let u = x as f32 / size as f32;
+            let idx = (y * size + x) * 4;
+
+            let patches = 0.6 * tiling_value_noise(u * 4.0, v * 4.0, 4, 1)
+                + 0.4 * tiling_value_noise(u * 8.0, v * 8.0, 8, 2);
+            let t = ((patches - 0.58) / 0.14).clamp(0.0, 1.0);
+            let dirt_weight = t * t * (3.0 - 2.0 * t);
+            let speckle = tiling_value_noise(u * 64.0, v * 64.0, 64, 3) - 0.5;
+            let shade = 1.0 + speckle * 0.3;
+
+            for channel in 0..3 {
+                let base = grass[channel] + (dirt[channel] - grass[channel]) * dirt_weight;
+                data[idx + channel] = ((base * shade).clamp(0.0, 1.0) * 255.0) as u8;
+            }
+            data[idx + 3] = 255;
+        }
+    }
+
+    let mut image = Image::new(
+        Extent3d {
+            width: size as u32,
+            height: size as u32,
+            depth_or_array_layers: 1,
+        },
+        bevy::render::render_resource::TextureDimension::D2,
+        data,
+        TextureFormat::Rgba8UnormSrgb,
+        bevy::asset::RenderAssetUsages::default(),
+    );
+
+    // Mip levels follow level 0 in the same buffer, each a 2x2 box filter of the last.
+    let mut levels = image.data.take().unwrap_or_default();
+    let (mut level_start, mut level_size, mut mip_count) = (0, size, 1);
+    while level_size > 1 {
+        let half = level_size / 2;
+        let mut next = vec![0_u8; half * half * 4];
+        for y in 0..half {
+            for x in 0..half {
+                for channel in 0..4 {
+                    let sum: u32 = [(0, 0), (1, 0), (0, 1), (1, 1)]
+                        .iter()
+                        .map(|(dx, dy)| {
+                            let texel = (y * 2 + dy) * level_size + x * 2 + dx;
+                            levels[level_start + texel * 4 + channel] as u32
+                        })
+                        .sum();
+                    next[(y * half + x) * 4 + channel] = (sum / 4) as u8;
+                }
+            }
+        }
+        level_start = levels.len();
+        levels.extend_from_slice(&next);
+        level_size = half;
+        mip_count += 1;
+    }
+    image.data = Some(levels);
+    image.texture_descriptor.mip_level_count = mip_count;
+    image.sampler = ImageSampler::Descriptor(ImageSamplerDescriptor {
+        address_mode_u: ImageAddressMode::Repeat,
+        address_mode_v: ImageAddressMode::Repeat,
+        mag_filter: ImageFilterMode::Linear,
+        min_filter: ImageFilterMode::Linear,
+        mipmap_filter: ImageFilterMode::Linear,
+        anisotropy_clamp: 8,
+        ..default()
+    });
+
+    images.add(image)
+}
+
+/// Copies the look sensitivity setting into the camera rig, also when a scenario spawns a
+/// fresh rig.
+pub(super) fn sync_look_sensitivity(
+    settings: Res<GameSettings>,
+    mut rigs: Query<&mut ThirdPersonCameraRig>,
+) {
+    let sensitivity = settings
+        .look_sensitivity
+        .clamp(LOOK_SENSITIVITY_RANGE.0, LOOK_SENSITIVITY_RANGE.1);
+    for mut rig in &mut rigs {
+        if settings.is_changed() || rig.is_added() {
+            rig.look_sensitivity = sensitivity;
+        }
+    }
+}
+
+/// Points the 3D camera at a scaled offscreen image (shown full-window) when render scale
+/// is below 1, and straight at the window otherwise.
+pub(super) fn update_render_scale(
+    settings: Res<GameSettings>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    mut camera_query: Query<&mut RenderTarget, (With<Camera3d>, Without<ScenarioPreviewCamera>)>,
+    mut output_query: Query<(&mut ImageNode, &mut Visibility), With<RenderScaleOutput>>,
+    mut images: ResMut<Assets<Image>>,
+) {
+    let Ok(mut target) = camera_query.single_mut() else {
+        return;
+    };
+    let Ok((mut output, mut output_visibility)) = output_query.single_mut() else {
+        return;
+    };
+
+    let scale = settings
+        .render_scale
+        .clamp(RENDER_SCALE_OPTIONS[RENDER_SCALE_OPTIONS.len() - 1], 1.0);
+    if scale >= 1.0 {
+        if !matches!(*target, RenderTarget::Window(_)) {
+            *target = RenderTarget::Window(WindowRef::Primary);
+        }
+        output_visibility.set_if_neq(Visibility::Hidden);
+        return;
+    }
+
+    let size = (window.physical_size().as_vec2() * scale)
+        .round()
+        .as_uvec2()
+        .max(UVec2::ONE);
+    // Matching the window's logical size keeps cursor picking and projection unchanged.
+    let scale_factor = window.scale_factor() * scale;
+    let extent = Extent3d {
+        width: size.x,
+        height: size.y,
+        depth_or_array_layers: 1,
+    };
+
+    let handle = match &*target {
+        RenderTarget::Image(image_target) => Some(image_target.handle.clone()),
+        _ => None,
+    };
+    let handle =
+        match handle.and_then(|handle| images.get_mut(&handle).map(|image| (handle, image))) {
+            Some((handle, image)) => {
+                if image.texture_descriptor.size != extent {
+                    image.resize(extent);
+                }
+                handle
+            }
+            None => images.add(Image::new_target_texture(
+                size.x,
+                size.y,
+                TextureFormat::bevy_default(),
+                None,
+            )),
+        };
+
+    let needs_update = match &*target {
+        RenderTarget::Image(image_target) => {
+            image_target.handle != handle || image_target.scale_factor != scale_factor
+        }
+        _ => true,
+    };
+    if needs_update {
+        *target = RenderTarget::Image(ImageRenderTarget {
+            handle: handle.clone(),
+            scale_factor,
+        });
+    }
+    if output.image != handle {
+        output.image = handle;
+    }
+    output_visibility.set_if_neq(Visibility::Inherited);
+}
+
+/// Unlit copy of a prop material for distant LOD. Unlit output skips the sun and ambient
+/// terms, so the base color is darkened to roughly the average lit brightness.
+fn far_prop_material(
+    materials: &mut Assets<StandardMaterial>,
+    near: &Handle<StandardMaterial>,
+) -> Handle<StandardMaterial> {
+    let mut far = materials.get(near).cloned().unwrap_or_default();
+    let linear = far.base_color.to_linear();
+    far.base_color = Color::linear_rgba(
+        linear.red * PROP_LOD_FAR_SHADE,
+        linear.green * PROP_LOD_FAR_SHADE,
+        linear.blue * PROP_LOD_FAR_SHADE,
+        linear.alpha,
+    );
+    far.unlit = true;
+    materials.add(far)
+}
+
+fn player_shading(
+    materials: &mut Assets<StandardMaterial>,
+    lit: &Handle<StandardMaterial>,
+) -> PlayerShading {
+    let mut flat = materials.get(lit).cloned().unwrap_or_default();
+    flat.unlit = true;
+    PlayerShading {
+        lit: lit.clone(),
+        flat: materials.add(flat),
+    }
+}
+
+fn create_vignette_texture(images: &mut Assets<Image>) -> Handle<Image> {
+    let size = 256usize;
+    let mut data = vec![0_u8; size * size * 4];
+
+    for y in 0..size {
+        let v = y as f32 / (size - 1) as f32 * 2.0 - 1.0;
+        for x in 0..size {
+            let u = x as f32 / (size - 1) as f32 * 2.0 - 1.0;
+            let idx = (y * size + x) * 4;
+            let radius = (u * u + v * v).sqrt() / std::f32::consts::SQRT_2;
+            let t = ((radius - 0.45) / 0.55).clamp(0.0, 1.0);
+            data[idx + 3] = (t * t * (3.0 - 2.0 * t) * 255.0) as u8;
+        }
+    }
+
+    let image = Image::new(
+        bevy::render::render_resource::Extent3d {
+            width: size as u32,
+            height: size as u32,
+            depth_or_array_layers: 1,
+        },
+        bevy::render::render_resource::TextureDimension::D2,
+        data,
+        bevy::render::render_resource::TextureFormat::Rgba8UnormSrgb,
+        bevy::asset::RenderAssetUsages::default(),
+    );
+
+    images.add(image)
+}
+
+pub(super) fn spawn_scenario_world(
+    commands: &mut Commands,
+    asset_server: &AssetServer,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    images: &mut Assets<Image>,
+    scenario: &ScenarioDefinition,
+    settings: &GameSettings,
+) {
+    let ground_extent = scenario.ground_extent;
     let crate_spacing = scenario.crate_spacing;
-    let crate_pattern_mod = scenario.crate_pattern_mod.max(1);
     let wall_count = scenario.wall_count;
     let wall_spacing = scenario.wall_spacing;
     let wall_z = scenario.wall_z;
     let tower_z = scenario.tower_z;
     let sun_position = scenario.sun_vec3();
     let mut static_colliders = Vec::new();
+    let mut baked_shadow_quads = Vec::<(Vec3, Vec2)>::new();
 
     let player_radius: f32 = 0.35;
-    let player_half_height: f32 = 0.9;
-    let torso_mesh = meshes.add(Cuboid::new(0.54, 0.66, 0.30));
+    let body = settings.body_preset.proportions();
+    let player_half_height = body.half_height();
+    let hip_height = body.hip_height();
+    let spawn_yaw = scenario.spawn_yaw();
+    let chaos_seed = settings
+        .chaos_mode
+        .then(|| settings.chaos_seed.unwrap_or_else(rand::random::<u64>));
+    if let Some(seed) = chaos_seed {
+        info!(
+            "Chaosmodus: kratten verschoven met seed {seed} (chaos_seed in de config herhaalt deze indeling)"
+        );
+    }
+    let spawn_rotation = Quat::from_rotation_y(spawn_yaw);
+    let spawn_feet = scenario.spawn_feet_vec3();
+    let spawn_center = spawn_feet + Vec3::Y * player_half_height;
+    let camera_start = spawn_feet + spawn_rotation * Vec3::new(0.0, 4.0, 10.0);
+    let torso_mesh = meshes.add(Cuboid::new(0.54, 0.66 * body.upper_body_scale, 0.30));
     let pelvis_mesh = meshes.add(Cuboid::new(0.42, 0.24, 0.26));
     let head_mesh = meshes.add(Cuboid::new(0.28, 0.30, 0.26));
     let hair_mesh = meshes.add(Cuboid::new(0.30, 0.10, 0.28));
-    let upper_arm_len = 0.28_f32;
-    let lower_arm_len = 0.26_f32;
+    let upper_arm_len = body.upper_arm_len;
+    let lower_arm_len = body.lower_arm_len;
     let upper_arm_mesh = meshes.add(Cuboid::new(0.12, upper_arm_len, 0.12));
     let lower_arm_mesh = meshes.add(Cuboid::new(0.11, lower_arm_len, 0.11));
-    // Keep leg chain length aligned with hip height so IK can actually reach the floor.
-    let upper_leg_len = 0.40_f32;
-    let lower_leg_len = 0.40_f32;
-    let ankle_height = 0.08_f32;
+    // The preset keeps leg chain length aligned with hip height so IK can reach the floor.
+    let upper_leg_len = body.upper_leg_len;
+    let lower_leg_len = body.lower_leg_len;
+    let ankle_height = body.ankle_height;
     let upper_leg_mesh = meshes.add(Cuboid::new(0.16, upper_leg_len, 0.16));
     let lower_leg_mesh = meshes.add(Cuboid::new(0.14, lower_leg_len, 0.14));
     let hand_mesh = meshes.add(Cuboid::new(0.11, 0.12, 0.10));
     let foot_mesh = meshes.add(Cuboid::new(0.14, 0.08, 0.24));
+    let lod_proxy_mesh = meshes.add(Cuboid::new(0.54, player_half_height * 2.0, 0.30));
 
     let skin_mat = materials.add(StandardMaterial {
         base_color: Color::srgb(0.93, 0.79, 0.67),
@@ -675,6 +1539,14 @@ pub(super) fn spawn_scenario_world(
         perceptual_roughness: 0.96,
         ..default()
     });
+    let [
+        skin_shading,
+        shirt_shading,
+        pants_shading,
+        hair_shading,
+        boot_shading,
+    ] = [&skin_mat, &shirt_mat, &pants_mat, &hair_mat, &boot_mat]
+        .map(|lit| player_shading(materials, lit));
     let baked_shadow_mesh = meshes.add(Plane3d::default().mesh().size(1.0, 1.0));
     let baked_shadow_mat = materials.add(StandardMaterial {
         base_color: Color::srgba(0.0, 0.0, 0.0, 0.34),
@@ -701,233 +1573,281 @@ pub(super) fn spawn_scenario_world(
         ..default()
     });
 
-    commands
+    let player = commands
         .spawn((
             Player::default(),
-            Transform::from_xyz(0.0, player_half_height, 0.0),
+            Transform::from_translation(spawn_center).with_rotation(spawn_rotation),
             NotShadowCaster,
             PlayerCollider {
                 radius: player_radius,
                 half_height: player_half_height,
             },
-            ProceduralHumanAnimState::from_position(Vec3::new(0.0, player_half_height, 0.0)),
             PlayerKinematics {
                 horizontal_velocity: Vec2::ZERO,
                 vertical_velocity: 0.0,
                 grounded: true,
+                ..default()
             },
             InGameEntity,
         ))
-        .with_children(|player| {
-            player
-                .spawn((
-                    ProceduralHumanVisualRoot,
-                    Transform::from_xyz(0.0, -player_half_height, 0.0),
-                ))
-                .with_children(|human| {
-                    human.spawn((
-                        PlayerVisualPart,
-                        Mesh3d(pelvis_mesh.clone()),
-                        MeshMaterial3d(pants_mat.clone()),
-                        Transform::from_xyz(0.0, 0.88, 0.0),
-                    ));
-                    human.spawn((
-                        PlayerVisualPart,
-                        Mesh3d(torso_mesh.clone()),
-                        MeshMaterial3d(shirt_mat.clone()),
-                        Transform::from_xyz(0.0, 1.24, 0.0),
-                    ));
-                    human
-                        .spawn((
-                            HumanHead {
-                                base_local: Vec3::new(0.0, 1.64, 0.0),
-                                max_yaw: 0.80,
-                                max_pitch_up: 0.42,
-                                max_pitch_down: 0.48,
-                            },
+        .id();
+    if settings.simple_player_model {
+        // Plain box instead of the limb hierarchy; without animation state the procedural
+        // human systems skip the player entirely.
+        commands.entity(player).with_child((
+            PlayerVisualPart,
+            Mesh3d(meshes.add(Cuboid::new(
+                player_radius * 2.0,
+                player_half_height * 2.0,
+                player_radius * 2.0,
+            ))),
+            MeshMaterial3d(shirt_mat.clone()),
+            shirt_shading.clone(),
+        ));
+    } else {
+        commands
+            .entity(player)
+            .insert(ProceduralHumanAnimState::from_position(spawn_center))
+            .with_children(|player| {
+                player
+                    .spawn((
+                        ProceduralHumanVisualRoot,
+                        Transform::from_xyz(0.0, -player_half_height, 0.0),
+                        Visibility::default(),
+                    ))
+                    .with_children(|human| {
+                        human.spawn((
                             PlayerVisualPart,
-                            Mesh3d(head_mesh.clone()),
-                            MeshMaterial3d(skin_mat.clone()),
-                            Transform::from_xyz(0.0, 1.64, 0.0),
-                        ))
-                        .with_children(|head| {
-                            head.spawn((
-                                PlayerVisualPart,
-                                Mesh3d(hair_mesh.clone()),
-                                MeshMaterial3d(hair_mat.clone()),
-                                Transform::from_xyz(0.0, 0.16, 0.0),
-                            ));
-                        });
-
-                    let left_arm_base = Vec3::new(-0.34, 1.40, 0.0);
-                    human
-                        .spawn((
-                            HumanArmPivot {
-                                side: LimbSide::Left,
-                                base_local: left_arm_base,
-                                upper_len: upper_arm_len,
-                                lower_len: lower_arm_len,
-                            },
-                            Transform::from_translation(left_arm_base),
-                        ))
-                        .with_children(|arm| {
-                            arm.spawn((
+                            Mesh3d(pelvis_mesh.clone()),
+                            MeshMaterial3d(pants_mat.clone()),
+                            pants_shading.clone(),
+                            Transform::from_xyz(0.0, hip_height, 0.0),
+                        ));
+                        human.spawn((
+                            PlayerVisualPart,
+                            Mesh3d(torso_mesh.clone()),
+                            MeshMaterial3d(shirt_mat.clone()),
+                            shirt_shading.clone(),
+                            Transform::from_xyz(0.0, body.torso_height(), 0.0),
+                        ));
+                        human
+                            .spawn((
+                                HumanHead {
+                                    base_local: Vec3::new(0.0, body.head_height(), 0.0),
+                                    max_yaw: 0.80,
+                                    max_pitch_up: 0.42,
+                                    max_pitch_down: 0.48,
+                                },
                                 PlayerVisualPart,
-                                Mesh3d(upper_arm_mesh.clone()),
-                                MeshMaterial3d(shirt_mat.clone()),
-                                Transform::from_xyz(0.0, -upper_arm_len * 0.5, 0.0),
-                            ));
-                            arm.spawn((
-                                HumanArmElbow,
-                                Transform::from_xyz(0.0, -upper_arm_len, 0.0),
+                                Mesh3d(head_mesh.clone()),
+                                MeshMaterial3d(skin_mat.clone()),
+                                skin_shading.clone(),
+                                Transform::from_xyz(0.0, body.head_height(), 0.0),
                             ))
-                            .with_children(|elbow| {
-                                elbow.spawn((
+                            .with_children(|head| {
+                                head.spawn((
                                     PlayerVisualPart,
-                                    Mesh3d(lower_arm_mesh.clone()),
-                                    MeshMaterial3d(shirt_mat.clone()),
-                                    Transform::from_xyz(0.0, -lower_arm_len * 0.5, 0.0),
-                                ));
-                                elbow.spawn((
-                                    PlayerVisualPart,
-                                    Mesh3d(hand_mesh.clone()),
-                                    MeshMaterial3d(skin_mat.clone()),
-                                    Transform::from_xyz(0.0, -(lower_arm_len + 0.07), 0.03),
+                                    Mesh3d(hair_mesh.clone()),
+                                    MeshMaterial3d(hair_mat.clone()),
+                                    hair_shading.clone(),
+                                    Transform::from_xyz(0.0, 0.16, 0.0),
                                 ));
                             });
-                        });
 
-                    let right_arm_base = Vec3::new(0.34, 1.40, 0.0);
-                    human
-                        .spawn((
-                            HumanArmPivot {
-                                side: LimbSide::Right,
-                                base_local: right_arm_base,
-                                upper_len: upper_arm_len,
-                                lower_len: lower_arm_len,
-                            },
-                            Transform::from_translation(right_arm_base),
-                        ))
-                        .with_children(|arm| {
-                            arm.spawn((
-                                PlayerVisualPart,
-                                Mesh3d(upper_arm_mesh.clone()),
-                                MeshMaterial3d(shirt_mat.clone()),
-                                Transform::from_xyz(0.0, -upper_arm_len * 0.5, 0.0),
-                            ));
-                            arm.spawn((
-                                HumanArmElbow,
-                                Transform::from_xyz(0.0, -upper_arm_len, 0.0),
+                        let left_arm_base = Vec3::new(-0.34, body.shoulder_height(), 0.0);
+                        human
+                            .spawn((
+                                HumanArmPivot {
+                                    side: LimbSide::Left,
+                                    base_local: left_arm_base,
+                                    upper_len: upper_arm_len,
+                                    lower_len: lower_arm_len,
+                                },
+                                Transform::from_translation(left_arm_base),
                             ))
-                            .with_children(|elbow| {
-                                elbow.spawn((
+                            .with_children(|arm| {
+                                arm.spawn((
                                     PlayerVisualPart,
-                                    Mesh3d(lower_arm_mesh.clone()),
+                                    Mesh3d(upper_arm_mesh.clone()),
                                     MeshMaterial3d(shirt_mat.clone()),
-                                    Transform::from_xyz(0.0, -lower_arm_len * 0.5, 0.0),
+                                    shirt_shading.clone(),
+                                    Transform::from_xyz(0.0, -upper_arm_len * 0.5, 0.0),
                                 ));
-                                elbow.spawn((
+                                arm.spawn((
+                                    HumanArmElbow,
+                                    Transform::from_xyz(0.0, -upper_arm_len, 0.0),
+                                ))
+                                .with_children(|elbow| {
+                                    elbow.spawn((
+                                        PlayerVisualPart,
+                                        Mesh3d(lower_arm_mesh.clone()),
+                                        MeshMaterial3d(shirt_mat.clone()),
+                                        shirt_shading.clone(),
+                                        Transform::from_xyz(0.0, -lower_arm_len * 0.5, 0.0),
+                                    ));
+                                    elbow.spawn((
+                                        PlayerVisualPart,
+                                        Mesh3d(hand_mesh.clone()),
+                                        MeshMaterial3d(skin_mat.clone()),
+                                        skin_shading.clone(),
+                                        Transform::from_xyz(0.0, -(lower_arm_len + 0.07), 0.03),
+                                    ));
+                                });
+                            });
+
+                        let right_arm_base = Vec3::new(0.34, body.shoulder_height(), 0.0);
+                        human
+                            .spawn((
+                                HumanArmPivot {
+                                    side: LimbSide::Right,
+                                    base_local: right_arm_base,
+                                    upper_len: upper_arm_len,
+                                    lower_len: lower_arm_len,
+                                },
+                                Transform::from_translation(right_arm_base),
+                            ))
+                            .with_children(|arm| {
+                                arm.spawn((
                                     PlayerVisualPart,
-                                    Mesh3d(hand_mesh.clone()),
-                                    MeshMaterial3d(skin_mat.clone()),
-                                    Transform::from_xyz(0.0, -(lower_arm_len + 0.07), 0.03),
+                                    Mesh3d(upper_arm_mesh.clone()),
+                                    MeshMaterial3d(shirt_mat.clone()),
+                                    shirt_shading.clone(),
+                                    Transform::from_xyz(0.0, -upper_arm_len * 0.5, 0.0),
                                 ));
+                                arm.spawn((
+                                    HumanArmElbow,
+                                    Transform::from_xyz(0.0, -upper_arm_len, 0.0),
+                                ))
+                                .with_children(|elbow| {
+                                    elbow.spawn((
+                                        PlayerVisualPart,
+                                        Mesh3d(lower_arm_mesh.clone()),
+                                        MeshMaterial3d(shirt_mat.clone()),
+                                        shirt_shading.clone(),
+                                        Transform::from_xyz(0.0, -lower_arm_len * 0.5, 0.0),
+                                    ));
+                                    elbow.spawn((
+                                        PlayerVisualPart,
+                                        Mesh3d(hand_mesh.clone()),
+                                        MeshMaterial3d(skin_mat.clone()),
+                                        skin_shading.clone(),
+                                        Transform::from_xyz(0.0, -(lower_arm_len + 0.07), 0.03),
+                                    ));
+                                });
                             });
-                        });
 
-                    let left_leg_base = Vec3::new(-0.16, 0.88, 0.0);
-                    human
-                        .spawn((
-                            HumanLegHip {
-                                side: LimbSide::Left,
-                                base_local: left_leg_base,
-                                upper_len: upper_leg_len,
-                                lower_len: lower_leg_len,
-                                ankle_height,
-                            },
-                            Transform::from_translation(left_leg_base),
-                        ))
-                        .with_children(|leg| {
-                            leg.spawn((
-                                PlayerVisualPart,
-                                Mesh3d(upper_leg_mesh.clone()),
-                                MeshMaterial3d(pants_mat.clone()),
-                                Transform::from_xyz(0.0, -upper_leg_len * 0.5, 0.0),
-                            ));
-                            leg.spawn((
-                                HumanLegKnee,
-                                Transform::from_xyz(0.0, -upper_leg_len, 0.0),
+                        let left_leg_base = Vec3::new(-0.16, hip_height, 0.0);
+                        human
+                            .spawn((
+                                HumanLegHip {
+                                    side: LimbSide::Left,
+                                    base_local: left_leg_base,
+                                    upper_len: upper_leg_len,
+                                    lower_len: lower_leg_len,
+                                    ankle_height,
+                                },
+                                Transform::from_translation(left_leg_base),
                             ))
-                            .with_children(|knee| {
-                                knee.spawn((
+                            .with_children(|leg| {
+                                leg.spawn((
                                     PlayerVisualPart,
-                                    Mesh3d(lower_leg_mesh.clone()),
+                                    Mesh3d(upper_leg_mesh.clone()),
                                     MeshMaterial3d(pants_mat.clone()),
-                                    Transform::from_xyz(0.0, -lower_leg_len * 0.5, 0.0),
-                                ));
-                                knee.spawn((
-                                    PlayerVisualPart,
-                                    Mesh3d(foot_mesh.clone()),
-                                    MeshMaterial3d(boot_mat.clone()),
-                                    Transform::from_xyz(
-                                        0.0,
-                                        -(lower_leg_len + ankle_height * 0.5),
-                                        0.09,
-                                    ),
+                                    pants_shading.clone(),
+                                    Transform::from_xyz(0.0, -upper_leg_len * 0.5, 0.0),
                                 ));
+                                leg.spawn((
+                                    HumanLegKnee,
+                                    Transform::from_xyz(0.0, -upper_leg_len, 0.0),
+                                ))
+                                .with_children(|knee| {
+                                    knee.spawn((
+                                        PlayerVisualPart,
+                                        Mesh3d(lower_leg_mesh.clone()),
+                                        MeshMaterial3d(pants_mat.clone()),
+                                        pants_shading.clone(),
+                                        Transform::from_xyz(0.0, -lower_leg_len * 0.5, 0.0),
+                                    ));
+                                    knee.spawn((
+                                        PlayerVisualPart,
+                                        Mesh3d(foot_mesh.clone()),
+                                        MeshMaterial3d(boot_mat.clone()),
+                                        boot_shading.clone(),
+                                        Transform::from_xyz(
+                                            0.0,
+                                            -(lower_leg_len + ankle_height * 0.5),
+                                            0.09,
+                                        ),
+                                    ));
+                                });
                             });
-                        });
 
-                    let right_leg_base = Vec3::new(0.16, 0.88, 0.0);
-                    human
-                        .spawn((
-                            HumanLegHip {
-                                side: LimbSide::Right,
-                                base_local: right_leg_base,
-                                upper_len: upper_leg_len,
-                                lower_len: lower_leg_len,
-                                ankle_height,
-                            },
-                            Transform::from_translation(right_leg_base),
-                        ))
-                        .with_children(|leg| {
-                            leg.spawn((
-                                PlayerVisualPart,
-                                Mesh3d(upper_leg_mesh.clone()),
-                                MeshMaterial3d(pants_mat.clone()),
-                                Transform::from_xyz(0.0, -upper_leg_len * 0.5, 0.0),
-                            ));
-                            leg.spawn((
-                                HumanLegKnee,
-                                Transform::from_xyz(0.0, -upper_leg_len, 0.0),
+                        let right_leg_base = Vec3::new(0.16, hip_height, 0.0);
+                        human
+                            .spawn((
+                                HumanLegHip {
+                                    side: LimbSide::Right,
+                                    base_local: right_leg_base,
+                                    upper_len: upper_leg_len,
+                                    lower_len: lower_leg_len,
+                                    ankle_height,
+                                },
+                                Transform::from_translation(right_leg_base),
                             ))
-                            .with_children(|knee| {
-                                knee.spawn((
+                            .with_children(|leg| {
+                                leg.spawn((
                                     PlayerVisualPart,
-                                    Mesh3d(lower_leg_mesh.clone()),
+                                    Mesh3d(upper_leg_mesh.clone()),
                                     MeshMaterial3d(pants_mat.clone()),
-                                    Transform::from_xyz(0.0, -lower_leg_len * 0.5, 0.0),
-                                ));
-                                knee.spawn((
-                                    PlayerVisualPart,
-                                    Mesh3d(foot_mesh.clone()),
-                                    MeshMaterial3d(boot_mat.clone()),
-                                    Transform::from_xyz(
-                                        0.0,
-                                        -(lower_leg_len + ankle_height * 0.5),
-                                        0.09,
-                                    ),
+                                    pants_shading.clone(),
+                                    Transform::from_xyz(0.0, -upper_leg_len * 0.5, 0.0),
                                 ));
+                                leg.spawn((
+                                    HumanLegKnee,
+                                    Transform::from_xyz(0.0, -upper_leg_len, 0.0),
+                                ))
+                                .with_children(|knee| {
+                                    knee.spawn((
+                                        PlayerVisualPart,
+                                        Mesh3d(lower_leg_mesh.clone()),
+                                        MeshMaterial3d(pants_mat.clone()),
+                                        pants_shading.clone(),
+                                        Transform::from_xyz(0.0, -lower_leg_len * 0.5, 0.0),
+                                    ));
+                                    knee.spawn((
+                                        PlayerVisualPart,
+                                        Mesh3d(foot_mesh.clone()),
+                                        MeshMaterial3d(boot_mat.clone()),
+                                        boot_shading.clone(),
+                                        Transform::from_xyz(
+                                            0.0,
+                                            -(lower_leg_len + ankle_height * 0.5),
+                                            0.09,
+                                        ),
+                                    ));
+                                });
                             });
-                        });
-                });
-        });
+                    });
+
+                // Single box that stands in for the limb hierarchy when the camera is far away.
+                player.spawn((
+                    PlayerLodProxy,
+                    PlayerVisualPart,
+                    Mesh3d(lod_proxy_mesh),
+                    MeshMaterial3d(shirt_mat.clone()),
+                    shirt_shading.clone(),
+                    Transform::IDENTITY,
+                    Visibility::Hidden,
+                ));
+            });
+    }
 
     commands.spawn((
         PlayerBlobShadow,
         Mesh3d(baked_shadow_mesh.clone()),
         MeshMaterial3d(player_shadow_mat),
-        Transform::from_xyz(0.0, 0.015, 0.0).with_scale(Vec3::new(0.9, 1.0, 0.9)),
+        Transform::from_translation(spawn_feet + Vec3::Y * 0.015)
+            .with_scale(Vec3::new(0.9, 1.0, 0.9)),
         NotShadowCaster,
         NotShadowReceiver,
         InGameEntity,
@@ -935,14 +1855,49 @@ pub(super) fn spawn_scenario_world(
 
     commands.spawn((
         Camera3d::default(),
-        PrimaryEguiContext,
-        Transform::from_xyz(0.0, 4.0, 10.0).looking_at(Vec3::ZERO, Vec3::Y),
-        ThirdPersonCameraRig::default(),
+        Transform::from_translation(camera_start).looking_at(spawn_feet, Vec3::Y),
+        ThirdPersonCameraRig {
+            yaw: spawn_yaw,
+            distance: settings.camera_orbit_distance,
+            orbit_distance: settings.camera_orbit_distance,
+            aim_distance: settings.camera_aim_distance,
+            ..default()
+        },
         Msaa::Sample4,
         default_distance_fog(),
         InGameEntity,
     ));
 
+    // UI and egui render here so they stay at native resolution; the own render layer keeps
+    // world-space 2D items (stair labels) out of it.
+    commands.spawn((
+        Camera2d,
+        Camera {
+            order: 1,
+            clear_color: ClearColorConfig::None,
+            ..default()
+        },
+        UiOverlayCamera,
+        IsDefaultUiCamera,
+        PrimaryEguiContext,
+        RenderLayers::layer(1),
+        Msaa::Off,
+        InGameEntity,
+    ));
+    commands.spawn((
+        RenderScaleOutput,
+        InGameEntity,
+        ImageNode::default(),
+        GlobalZIndex(i32::MIN),
+        Visibility::Hidden,
+        Node {
+            position_type: PositionType::Absolute,
+            width: percent(100),
+            height: percent(100),
+            ..default()
+        },
+    ));
+
     commands.spawn((
         SkyboxCube,
         Mesh3d(skybox_mesh),
@@ -965,10 +1920,21 @@ pub(super) fn spawn_scenario_world(
     ));
 
     let ground_mesh = meshes.add(Cuboid::new(ground_extent, 0.1, ground_extent));
-    let ground_mat = materials.add(StandardMaterial {
-        base_color: Color::srgb(0.22, 0.43, 0.20),
-        perceptual_roughness: 1.0,
-        ..default()
+    let ground_mat = materials.add(if settings.ground_texture {
+        StandardMaterial {
+            base_color_texture: Some(create_ground_texture(images)),
+            uv_transform: Affine2::from_scale(Vec2::splat(
+                ground_extent / GROUND_TEXTURE_TILE_METERS,
+            )),
+            perceptual_roughness: 1.0,
+            ..default()
+        }
+    } else {
+        StandardMaterial {
+            base_color: Color::srgb(0.22, 0.43, 0.20),
+            perceptual_roughness: 1.0,
+            ..default()
+        }
     });
 
     let ground_center = Vec3::new(0.0, -0.05, 0.0);
@@ -980,17 +1946,23 @@ pub(super) fn spawn_scenario_world(
         GroundPlane,
         WorldCollider {
             half_extents: ground_half,
+            surface: SurfaceType::Grass,
         },
         InGameEntity,
     ));
     static_colliders.push(StaticCollider {
         center: ground_center,
         half_extents: ground_half,
+        surface: SurfaceType::Grass,
+        slope_normal: None,
     });
 
-    let wall_mesh = meshes.add(Cuboid::new(3.0, 3.0, 3.0));
-    let tower_mesh = meshes.add(Cuboid::new(4.0, 8.0, 4.0));
-    let crate_mesh = meshes.add(Cuboid::new(1.0, 1.0, 1.0));
+    let crate_half = scenario.crate_half_extents();
+    let wall_half = scenario.wall_half_extents();
+    let tower_half = scenario.tower_half_extents();
+    let wall_mesh = meshes.add(Cuboid::from_size(wall_half * 2.0));
+    let tower_mesh = meshes.add(Cuboid::from_size(tower_half * 2.0));
+    let crate_mesh = meshes.add(Cuboid::from_size(crate_half * 2.0));
 
     let wall_mat = materials.add(StandardMaterial {
         base_color: Color::srgb(0.57, 0.52, 0.44),
@@ -1003,89 +1975,146 @@ pub(super) fn spawn_scenario_world(
         perceptual_roughness: 0.88,
         ..default()
     });
-    let crate_mat = materials.add(StandardMaterial {
-        base_color: Color::srgb(0.69, 0.44, 0.24),
-        perceptual_roughness: 0.86,
-        ..default()
+    let crate_mats = CRATE_WOOD_TONES.map(|[r, g, b]| {
+        let near = materials.add(StandardMaterial {
+            base_color: Color::srgb(r, g, b),
+            perceptual_roughness: 0.86,
+            ..default()
+        });
+        let far = far_prop_material(materials, &near);
+        (near, far)
     });
+    let wall_far_mat = far_prop_material(materials, &wall_mat);
+    let tower_far_mat = far_prop_material(materials, &tower_mat);
+    // Props start in the LOD they have from the spawn camera, so far ones never load lit.
+    let prop_lod_distance = settings.prop_lod_distance.max(0.0);
+    let prop_lod = |center: Vec3,
+                    near_material: &Handle<StandardMaterial>,
+                    far_material: &Handle<StandardMaterial>| {
+        let far = center.distance(camera_start) > prop_lod_distance;
+        let lod = PropLod {
+            near_material: near_material.clone(),
+            far_material: far_material.clone(),
+            far,
+        };
+        let material = if far {
+            far_material.clone()
+        } else {
+            near_material.clone()
+        };
+        (lod, MeshMaterial3d(material))
+    };
 
-    for x in -crate_grid_radius..=crate_grid_radius {
-        for z in -crate_grid_radius..=crate_grid_radius {
-            let near_spawn = (-1..=1).contains(&x) && (-1..=1).contains(&z);
-            if (x + z).rem_euclid(crate_pattern_mod) == 0 && !near_spawn {
-                commands.spawn((
-                    Mesh3d(crate_mesh.clone()),
-                    MeshMaterial3d(crate_mat.clone()),
-                    Transform::from_xyz(x as f32 * crate_spacing, 0.5, z as f32 * crate_spacing),
-                    NotShadowCaster,
-                    WorldCollider {
-                        half_extents: Vec3::splat(0.5),
-                    },
-                    InGameEntity,
-                ));
-                static_colliders.push(StaticCollider {
-                    center: Vec3::new(x as f32 * crate_spacing, 0.5, z as f32 * crate_spacing),
-                    half_extents: Vec3::splat(0.5),
-                });
-                spawn_baked_shadow(
-                    commands,
-                    &baked_shadow_mesh,
-                    &baked_shadow_mat,
-                    Vec3::new(x as f32 * crate_spacing, 0.011, z as f32 * crate_spacing),
-                    Vec2::new(1.25, 1.25),
-                );
-            }
+    let crate_budget = match settings.crate_budget {
+        0 => u32::MAX,
+        budget => budget,
+    };
+    let mut crates_spawned = 0_u32;
+    for (x, z) in scenario.crate_cells() {
+        let levels = scenario.crate_stack_levels(x, z);
+        if crates_spawned + levels > crate_budget {
+            let wanted: u32 = scenario
+                .crate_cells()
+                .map(|(x, z)| scenario.crate_stack_levels(x, z))
+                .sum();
+            warn!(
+                "Kratbudget van {} bereikt in '{}': {} van {} kratten geplaatst",
+                crate_budget, scenario.id, crates_spawned, wanted
+            );
+            break;
+        }
+        crates_spawned += levels;
+        let (offset, quarter_turns) = chaos_seed
+            .map(|seed| scenario.chaos_crate_jitter(seed, x, z))
+            .unwrap_or((Vec2::ZERO, 0));
+        // Quarter turns keep the box axis-aligned, so swapping X/Z keeps the AABB exact.
+        let column_half = if quarter_turns % 2 == 1 {
+            Vec3::new(crate_half.z, crate_half.y, crate_half.x)
+        } else {
+            crate_half
+        };
+        let column_rotation =
+            Quat::from_rotation_y(quarter_turns as f32 * std::f32::consts::FRAC_PI_2);
+        let column_x = x as f32 * crate_spacing + offset.x;
+        let column_z = z as f32 * crate_spacing + offset.y;
+        for level in 0..levels {
+            let crate_center = Vec3::new(
+                column_x,
+                crate_half.y * (1.0 + 2.0 * level as f32),
+                column_z,
+            );
+            let (crate_mat, crate_far_mat) = &crate_mats[scenario.crate_tone_index(x, z, level)];
+            commands.spawn((
+                Mesh3d(crate_mesh.clone()),
+                prop_lod(crate_center, crate_mat, crate_far_mat),
+                Transform::from_translation(crate_center).with_rotation(column_rotation),
+                NotShadowCaster,
+                WorldCollider {
+                    half_extents: column_half,
+                    surface: SurfaceType::Wood,
+                },
+                InGameEntity,
+            ));
+            static_colliders.push(StaticCollider {
+                center: crate_center,
+                half_extents: column_half,
+                surface: SurfaceType::Wood,
+                slope_normal: None,
+            });
         }
+        baked_shadow_quads.push((
+            Vec3::new(column_x, 0.011, column_z),
+            Vec2::new(column_half.x, column_half.z) * 2.5,
+        ));
     }
 
     for i in -wall_count..=wall_count {
-        let wall_center = Vec3::new(i as f32 * wall_spacing, 1.5, wall_z);
+        let wall_center = Vec3::new(i as f32 * wall_spacing, wall_half.y, wall_z);
         commands.spawn((
             Mesh3d(wall_mesh.clone()),
-            MeshMaterial3d(wall_mat.clone()),
+            prop_lod(wall_center, &wall_mat, &wall_far_mat),
             Transform::from_translation(wall_center),
             NotShadowCaster,
             WorldCollider {
-                half_extents: Vec3::splat(1.5),
+                half_extents: wall_half,
+                surface: SurfaceType::Stone,
             },
             InGameEntity,
         ));
         static_colliders.push(StaticCollider {
             center: wall_center,
-            half_extents: Vec3::splat(1.5),
+            half_extents: wall_half,
+            surface: SurfaceType::Stone,
+            slope_normal: None,
         });
-        spawn_baked_shadow(
-            commands,
-            &baked_shadow_mesh,
-            &baked_shadow_mat,
+        baked_shadow_quads.push((
             Vec3::new(i as f32 * wall_spacing, 0.011, wall_z),
-            Vec2::new(3.4, 3.0),
-        );
+            Vec2::new(wall_half.x * 2.0 + 0.4, wall_half.z * 2.0),
+        ));
     }
 
-    let tower_center = Vec3::new(0.0, 4.0, tower_z);
-    let tower_half = Vec3::new(2.0, 4.0, 2.0);
+    let tower_center = Vec3::new(0.0, tower_half.y, tower_z);
     commands.spawn((
         Mesh3d(tower_mesh),
-        MeshMaterial3d(tower_mat),
+        prop_lod(tower_center, &tower_mat, &tower_far_mat),
         Transform::from_translation(tower_center),
         NotShadowCaster,
         WorldCollider {
             half_extents: tower_half,
+            surface: SurfaceType::Stone,
         },
         InGameEntity,
     ));
     static_colliders.push(StaticCollider {
         center: tower_center,
         half_extents: tower_half,
+        surface: SurfaceType::Stone,
+        slope_normal: None,
     });
-    spawn_baked_shadow(
-        commands,
-        &baked_shadow_mesh,
-        &baked_shadow_mat,
+    baked_shadow_quads.push((
         Vec3::new(0.0, 0.011, tower_z),
-        Vec2::new(5.0, 5.0),
-    );
+        Vec2::new(tower_half.x, tower_half.z) * 2.5,
+    ));
 
     if scenario.id == "greenwood" {
         // Place the generated table model as a scene in Greenwood Valley.
@@ -1104,26 +2133,18 @@ pub(super) fn spawn_scenario_world(
             Transform::from_translation(table_collider_center),
             WorldCollider {
                 half_extents: table_collider_half,
+                surface: SurfaceType::Wood,
             },
             InGameEntity,
         ));
         static_colliders.push(StaticCollider {
             center: table_collider_center,
             half_extents: table_collider_half,
+            surface: SurfaceType::Wood,
+            slope_normal: None,
         });
 
         // Add 5 stair variants with different steepness for controller testing.
-        // (rise/run): from shallow to steep.
-        let stair_profiles = [
-            (0.16_f32, 1.05_f32),
-            (0.20_f32, 0.92_f32),
-            (0.24_f32, 0.80_f32),
-            (0.30_f32, 0.72_f32),
-            (0.36_f32, 0.64_f32),
-        ];
-        let stair_width = 2.2_f32;
-        let stair_depth = 0.82_f32;
-        let stairs_per_profile = 5;
         let base_x = -18.0_f32;
         let lane_spacing = 4.5_f32;
         let base_z = 8.0_f32;
@@ -1135,14 +2156,15 @@ pub(super) fn spawn_scenario_world(
             Color::srgb(0.60, 0.50, 0.42),
         ];
 
-        for (lane_idx, (stair_rise, stair_run)) in stair_profiles.into_iter().enumerate() {
+        for (lane_idx, (stair_rise, stair_run)) in STAIR_PROFILES.into_iter().enumerate() {
             let lane_x = base_x + lane_spacing * lane_idx as f32;
-            let stair_mesh = meshes.add(Cuboid::new(stair_width, stair_rise, stair_depth));
+            let stair_mesh = meshes.add(Cuboid::new(STAIR_WIDTH, stair_rise, STAIR_DEPTH));
             let stair_mat = materials.add(StandardMaterial {
                 base_color: stair_colors[lane_idx % stair_colors.len()],
                 perceptual_roughness: 0.94,
                 ..default()
             });
+            let stair_far_mat = far_prop_material(materials, &stair_mat);
 
             commands.spawn((
                 StairSteepnessLabel,
@@ -1155,40 +2177,128 @@ pub(super) fn spawn_scenario_world(
                 Transform::from_xyz(lane_x, 0.38, base_z - 0.55).with_scale(Vec3::splat(0.018)),
             ));
 
-            for step in 0..stairs_per_profile {
-                let idx = step as f32;
-                let center = Vec3::new(
-                    lane_x,
-                    stair_rise * 0.5 + idx * stair_rise,
-                    base_z + idx * stair_run + stair_depth * 0.5,
-                );
-                let half = Vec3::new(stair_width * 0.5, stair_rise * 0.5, stair_depth * 0.5);
-
+            for (center, half) in stair_treads(lane_x, base_z, stair_rise, stair_run) {
                 commands.spawn((
                     Mesh3d(stair_mesh.clone()),
-                    MeshMaterial3d(stair_mat.clone()),
+                    prop_lod(center, &stair_mat, &stair_far_mat),
                     Transform::from_translation(center),
                     NotShadowCaster,
-                    WorldCollider { half_extents: half },
+                    WorldCollider {
+                        half_extents: half,
+                        surface: SurfaceType::Stone,
+                    },
                     InGameEntity,
                 ));
                 static_colliders.push(StaticCollider {
                     center,
                     half_extents: half,
+                    surface: SurfaceType::Stone,
+                    slope_normal: None,
                 });
-                spawn_baked_shadow(
-                    commands,
-                    &baked_shadow_mesh,
-                    &baked_shadow_mat,
+                baked_shadow_quads.push((
                     Vec3::new(center.x, 0.011, center.z),
-                    Vec2::new(stair_width * 1.05, stair_depth * 1.15),
-                );
+                    Vec2::new(STAIR_WIDTH * 1.05, STAIR_DEPTH * 1.15),
+                ));
             }
         }
     }
 
+    // A patrolling dummy starts on its first waypoint.
+    let dummy_feet = scenario.dummy_patrol_waypoints.first().map_or_else(
+        || scenario.target_dummy_vec3(),
+        |&point| Vec3::from_array(point),
+    );
+    let dummy_half = Vec3::new(0.32, 0.9, 0.32);
+    let dummy_center = dummy_feet + Vec3::Y * dummy_half.y;
+    let dummy_mat = materials.add(StandardMaterial {
+        base_color: Color::srgb(0.78, 0.18, 0.16),
+        perceptual_roughness: 0.7,
+        ..default()
+    });
+    let dummy_patrol_waypoints = scenario
+        .dummy_patrol_waypoints
+        .iter()
+        .map(|&[x, _, z]| Vec2::new(x, z))
+        .collect::<Vec<_>>();
+    let dummy_patrols = !dummy_patrol_waypoints.is_empty();
+    let mut dummy = commands.spawn((
+        TargetDummy {
+            trigger_radius: 2.5,
+            player_in_range: false,
+        },
+        Mesh3d(meshes.add(Capsule3d::new(
+            dummy_half.x,
+            dummy_half.y * 2.0 - dummy_half.x * 2.0,
+        ))),
+        MeshMaterial3d(dummy_mat),
+        Transform::from_translation(dummy_center),
+        WorldCollider {
+            half_extents: dummy_half,
+            surface: SurfaceType::Neutral,
+        },
+        InGameEntity,
+    ));
+    dummy.with_child((
+        Mesh3d(meshes.add(Torus::new(0.12, 0.2))),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgb(0.95, 0.93, 0.88),
+            perceptual_roughness: 0.7,
+            ..default()
+        })),
+        Transform::from_xyz(0.0, 0.25, dummy_half.x - 0.02)
+            .with_rotation(Quat::from_rotation_x(std::f32::consts::FRAC_PI_2)),
+    ));
+    if dummy_patrols {
+        // A moving dummy cannot live in the static grid, or it would collide with itself.
+        dummy.insert((
+            Patrol {
+                waypoints: dummy_patrol_waypoints,
+                speed: scenario.dummy_patrol_speed.max(0.0),
+                next_waypoint: 1,
+                collider: PlayerCollider {
+                    radius: dummy_half.x,
+                    half_height: dummy_half.y,
+                },
+            },
+            PlayerKinematics {
+                grounded: true,
+                ..default()
+            },
+        ));
+    } else {
+        static_colliders.push(StaticCollider {
+            center: dummy_center,
+            half_extents: dummy_half,
+            surface: SurfaceType::Neutral,
+            slope_normal: None,
+        });
+        baked_shadow_quads.push((
+            Vec3::new(dummy_feet.x, dummy_feet.y + 0.011, dummy_feet.z),
+            Vec2::splat(0.9),
+        ));
+    }
+
+    if settings.bake_world_shadows {
+        spawn_baked_shadows(commands, meshes, &baked_shadow_mat, &baked_shadow_quads);
+    }
     commands.insert_resource(WorldCollisionGrid::from_colliders(static_colliders, 4.0));
 
+    commands.spawn((
+        ComfortVignette,
+        InGameEntity,
+        ImageNode {
+            image: create_vignette_texture(images),
+            color: Color::srgba(1.0, 1.0, 1.0, 0.0),
+            ..default()
+        },
+        Node {
+            position_type: PositionType::Absolute,
+            width: percent(100),
+            height: percent(100),
+            ..default()
+        },
+    ));
+
     commands
         .spawn((
             InGameEntity,
@@ -1201,11 +2311,37 @@ pub(super) fn spawn_scenario_world(
         ))
         .with_child(Text::new(
             format!(
-                "Scenario: {}\nESC: menu\nLMB: camera orbit\nRMB: aim-move mode\nScroll: zoom\n\nKeybinds zijn aanpasbaar in het menu.",
+                "Scenario: {}\nESC: menu\nLMB: camera orbit\nRMB: aim-move mode\nScroll: zoom\nT: recenter camera\nR: auto-walk\nP: photo mode (F12: screenshot)\nF6/F7: pause/step simulation\nF4: wireframe\n\nKeybinds zijn aanpasbaar in het menu.",
                 scenario.name
             ),
         ));
 
+    commands.spawn((
+        MeasurementReadoutText,
+        InGameEntity,
+        Text::new(""),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: px(12),
+            left: px(12),
+            ..default()
+        },
+        Visibility::Hidden,
+    ));
+
+    commands.spawn((
+        JumpReadoutText,
+        InGameEntity,
+        Text::new(""),
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: px(40),
+            left: px(12),
+            ..default()
+        },
+        Visibility::Hidden,
+    ));
+
     commands.spawn((
         PerformanceOverlayText,
         InGameEntity,
@@ -1217,14 +2353,69 @@ pub(super) fn spawn_scenario_world(
             ..default()
         },
     ));
+
+    commands
+        .spawn((
+            FpsGraph,
+            InGameEntity,
+            Node {
+                position_type: PositionType::Absolute,
+                bottom: px(12),
+                right: px(12),
+                height: px(FPS_GRAPH_HEIGHT_PX),
+                align_items: AlignItems::FlexEnd,
+                padding: UiRect::horizontal(px(2)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.45)),
+            Visibility::Hidden,
+            Pickable::IGNORE,
+        ))
+        .with_children(|graph| {
+            for index in 0..FPS_GRAPH_SAMPLES {
+                graph.spawn((
+                    FpsGraphBar(index),
+                    Node {
+                        width: px(2),
+                        height: px(0),
+                        ..default()
+                    },
+                    BackgroundColor(Color::NONE),
+                ));
+            }
+        });
+}
+
+/// Window close button: quits right away outside a run, otherwise asks first. A second
+/// close request while the question is open quits.
+pub(super) fn confirm_window_close(
+    mut close_requests: MessageReader<WindowCloseRequested>,
+    flow: Res<GameFlowState>,
+    mut menu: ResMut<MenuState>,
+    mut app_exit: MessageWriter<AppExit>,
+) {
+    if close_requests.read().count() == 0 {
+        return;
+    }
+
+    if !flow.in_game || (menu.open && menu.screen == MenuScreen::QuitConfirm) {
+        app_exit.write(AppExit::Success);
+        return;
+    }
+
+    menu.open = true;
+    menu.screen = MenuScreen::QuitConfirm;
+    menu.awaiting_rebind = None;
+    menu.dirty = true;
 }
 
 pub(super) fn toggle_menu_on_escape(
     keys: Res<ButtonInput<KeyCode>>,
     flow: Res<GameFlowState>,
+    photo_mode: Res<PhotoModeState>,
     mut menu: ResMut<MenuState>,
 ) {
-    if !flow.in_game {
+    if !flow.in_game || photo_mode.active {
         return;
     }
 
@@ -1245,6 +2436,7 @@ pub(super) fn toggle_menu_on_escape(
     menu.dirty = true;
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub(super) fn handle_menu_buttons(
     mut interactions: Query<
         (&Interaction, &MenuButton, &mut BackgroundColor),
@@ -1256,6 +2448,10 @@ pub(super) fn handle_menu_buttons(
     mut menu: ResMut<MenuState>,
     mut settings: ResMut<GameSettings>,
     mut debug: ResMut<DebugSettings>,
+    mut keybinds: ResMut<GameKeybinds>,
+    mut hitches: ResMut<HitchLog>,
+    mut notice: ResMut<PendingNotice>,
+    last_scenario: Res<LastScenario>,
     in_game_entities: Query<Entity, With<InGameEntity>>,
     start_menu_roots: Query<Entity, With<StartMenuRoot>>,
     start_menu_cameras: Query<Entity, With<StartMenuCamera>>,
@@ -1309,10 +2505,16 @@ pub(super) fn handle_menu_buttons(
                             }
 
                             commands.spawn((Camera2d, StartMenuCamera));
-                            spawn_start_menu_ui(&mut commands, &scenarios);
+                            spawn_start_menu_ui(
+                                &mut commands,
+                                &scenarios,
+                                &settings,
+                                &last_scenario,
+                            );
 
                             flow.in_game = false;
                             flow.pending_scenario = None;
+                            flow.current_scenario = None;
 
                             menu.open = false;
                             menu.screen = MenuScreen::Main;
@@ -1322,6 +2524,9 @@ pub(super) fn handle_menu_buttons(
                             app_exit.write(AppExit::Success);
                         }
                     }
+                    MenuButtonAction::QuitGame => {
+                        app_exit.write(AppExit::Success);
+                    }
                     MenuButtonAction::CycleDisplayMode => {
                         settings.display_mode = settings.display_mode.next();
                     }
@@ -1336,15 +2541,177 @@ pub(super) fn handle_menu_buttons(
                         settings.resolution_width = next.0;
                         settings.resolution_height = next.1;
                     }
-                    MenuButtonAction::ToggleMsaa => {
-                        settings.msaa_enabled = !settings.msaa_enabled;
+                    MenuButtonAction::CycleAntiAliasing => {
+                        settings.anti_aliasing = settings.anti_aliasing.next();
+                    }
+                    MenuButtonAction::CycleRenderScale => {
+                        let next_idx = RENDER_SCALE_OPTIONS
+                            .iter()
+                            .position(|&scale| (scale - settings.render_scale).abs() < 1e-3)
+                            .map(|idx| (idx + 1) % RENDER_SCALE_OPTIONS.len())
+                            .unwrap_or(0);
+                        settings.render_scale = RENDER_SCALE_OPTIONS[next_idx];
                     }
                     MenuButtonAction::ToggleShadowMode => {
                         settings.shadow_mode = settings.shadow_mode.next();
                     }
+                    MenuButtonAction::ToggleDustParticles => {
+                        settings.dust_particles = !settings.dust_particles;
+                    }
+                    MenuButtonAction::ToggleGroundTexture => {
+                        settings.ground_texture = !settings.ground_texture;
+                    }
+                    MenuButtonAction::ToggleBakeWorldShadows => {
+                        settings.bake_world_shadows = !settings.bake_world_shadows;
+                    }
+                    MenuButtonAction::ToggleSimplePlayerModel => {
+                        settings.simple_player_model = !settings.simple_player_model;
+                    }
+                    MenuButtonAction::CycleBodyPreset => {
+                        settings.body_preset = settings.body_preset.next();
+                    }
+                    MenuButtonAction::ToggleHeadTracking => {
+                        settings.head_always_tracks_camera = !settings.head_always_tracks_camera;
+                    }
+                    MenuButtonAction::CycleHumanShading => {
+                        settings.human_shading = settings.human_shading.next();
+                    }
+                    MenuButtonAction::ToggleCameraRelativeMovement => {
+                        settings.camera_relative_movement = !settings.camera_relative_movement;
+                    }
+                    MenuButtonAction::ToggleAimMode => {
+                        settings.toggle_aim = !settings.toggle_aim;
+                    }
+                    MenuButtonAction::ToggleSprintMode => {
+                        settings.sprint_toggle = !settings.sprint_toggle;
+                    }
+                    MenuButtonAction::ToggleChaosMode => {
+                        settings.chaos_mode = !settings.chaos_mode;
+                    }
+                    MenuButtonAction::CycleQuickStartScenario => {
+                        let current =
+                            scenarios.quick_start_index(settings.quick_start_scenario.as_deref());
+                        let next = (current + 1) % scenarios.scenarios.len().max(1);
+                        settings.quick_start_scenario = scenarios
+                            .scenarios
+                            .get(next)
+                            .map(|scenario| scenario.id.clone());
+                    }
+                    MenuButtonAction::TogglePauseOnMenu => {
+                        settings.pause_on_menu = !settings.pause_on_menu;
+                    }
+                    MenuButtonAction::ToggleTurnAcceleration => {
+                        settings.turn_acceleration_enabled = !settings.turn_acceleration_enabled;
+                    }
+                    MenuButtonAction::ToggleReduceMotion => {
+                        settings.reduce_motion = !settings.reduce_motion;
+                    }
+                    MenuButtonAction::ToggleComfortVignette => {
+                        settings.comfort_vignette = !settings.comfort_vignette;
+                    }
+                    MenuButtonAction::CycleGameFeel => {
+                        GameFeelPreset::matching(&settings)
+                            .map_or(GameFeelPreset::Minimal, GameFeelPreset::next)
+                            .apply(&mut settings);
+                    }
+                    MenuButtonAction::CycleFov => {
+                        let next_idx = FOV_DEGREES_OPTIONS
+                            .iter()
+                            .position(|&fov| (fov - settings.fov_degrees).abs() < 1e-3)
+                            .map(|idx| (idx + 1) % FOV_DEGREES_OPTIONS.len())
+                            .unwrap_or(0);
+                        settings.fov_degrees = FOV_DEGREES_OPTIONS[next_idx];
+                    }
+                    MenuButtonAction::CycleLookSensitivity => {
+                        let next_idx = LOOK_SENSITIVITY_OPTIONS
+                            .iter()
+                            .position(|&sensitivity| {
+                                (sensitivity - settings.look_sensitivity).abs() < 1e-6
+                            })
+                            .map(|idx| (idx + 1) % LOOK_SENSITIVITY_OPTIONS.len())
+                            .unwrap_or(0);
+                        settings.look_sensitivity = LOOK_SENSITIVITY_OPTIONS[next_idx];
+                    }
+                    MenuButtonAction::ToggleInvertY => {
+                        settings.invert_y = !settings.invert_y;
+                    }
+                    MenuButtonAction::CycleCameraFocusHeight => {
+                        let next_idx = CAMERA_FOCUS_HEIGHT_OPTIONS
+                            .iter()
+                            .position(|&height| {
+                                (height - settings.camera_focus_height).abs() < 1e-3
+                            })
+                            .map(|idx| (idx + 1) % CAMERA_FOCUS_HEIGHT_OPTIONS.len())
+                            .unwrap_or(0);
+                        settings.camera_focus_height = CAMERA_FOCUS_HEIGHT_OPTIONS[next_idx];
+                    }
+                    MenuButtonAction::CycleCameraHeight => {
+                        let next_idx = CAMERA_HEIGHT_OPTIONS
+                            .iter()
+                            .position(|&height| (height - settings.camera_height).abs() < 1e-3)
+                            .map(|idx| (idx + 1) % CAMERA_HEIGHT_OPTIONS.len())
+                            .unwrap_or(0);
+                        settings.camera_height = CAMERA_HEIGHT_OPTIONS[next_idx];
+                    }
+                    MenuButtonAction::CycleCameraShoulderOffset => {
+                        let next_idx = CAMERA_SHOULDER_OFFSET_OPTIONS
+                            .iter()
+                            .position(|&offset| {
+                                (offset - settings.camera_shoulder_offset).abs() < 1e-3
+                            })
+                            .map(|idx| (idx + 1) % CAMERA_SHOULDER_OFFSET_OPTIONS.len())
+                            .unwrap_or(0);
+                        settings.camera_shoulder_offset = CAMERA_SHOULDER_OFFSET_OPTIONS[next_idx];
+                    }
+                    MenuButtonAction::ToggleCameraFollowGround => {
+                        settings.camera_follow_ground = !settings.camera_follow_ground;
+                    }
+                    MenuButtonAction::ToggleMenuScenarioPreview => {
+                        settings.menu_scenario_preview = !settings.menu_scenario_preview;
+                    }
+                    MenuButtonAction::TogglePauseMenuDim => {
+                        settings.pause_menu_dim = !settings.pause_menu_dim;
+                    }
+                    MenuButtonAction::TogglePlayerCameraFade => {
+                        settings.fade_player_near_camera = !settings.fade_player_near_camera;
+                    }
+                    MenuButtonAction::ToggleChargeJump => {
+                        settings.charge_jump_enabled = !settings.charge_jump_enabled;
+                    }
+                    MenuButtonAction::CycleJumpApexHang => {
+                        let next_idx = JUMP_APEX_HANG_OPTIONS
+                            .iter()
+                            .position(|&factor| {
+                                (factor - settings.jump_apex_hang_factor).abs() < 1e-3
+                            })
+                            .map(|idx| (idx + 1) % JUMP_APEX_HANG_OPTIONS.len())
+                            .unwrap_or(0);
+                        settings.jump_apex_hang_factor = JUMP_APEX_HANG_OPTIONS[next_idx];
+                    }
+                    MenuButtonAction::ToggleWallJump => {
+                        settings.wall_jump_enabled = !settings.wall_jump_enabled;
+                    }
+                    MenuButtonAction::ToggleAlwaysCaptureMouse => {
+                        settings.always_capture_mouse = !settings.always_capture_mouse;
+                    }
+                    MenuButtonAction::ToggleScrollOrbit => {
+                        settings.horizontal_scroll_orbit = !settings.horizontal_scroll_orbit;
+                    }
                     MenuButtonAction::TogglePerformanceOverlay => {
                         debug.show_performance_overlay = !debug.show_performance_overlay;
                     }
+                    MenuButtonAction::ToggleFpsGraph => {
+                        debug.show_fps_graph = !debug.show_fps_graph;
+                    }
+                    MenuButtonAction::ToggleInputPanel => {
+                        debug.show_input_panel = !debug.show_input_panel;
+                    }
+                    MenuButtonAction::CycleOverlayCorner => {
+                        debug.overlay_corner = debug.overlay_corner.next();
+                    }
+                    MenuButtonAction::ToggleOverlayMetric(metric) => {
+                        debug.overlay_metrics.toggle(metric);
+                    }
                     MenuButtonAction::ToggleBakedShadows => {
                         debug.show_baked_shadows = !debug.show_baked_shadows;
                     }
@@ -1354,6 +2721,12 @@ pub(super) fn handle_menu_buttons(
                     MenuButtonAction::ToggleCollisionShapes => {
                         debug.show_collision_shapes = !debug.show_collision_shapes;
                     }
+                    MenuButtonAction::ToggleContactPoints => {
+                        debug.show_contact_points = !debug.show_contact_points;
+                    }
+                    MenuButtonAction::ToggleJumpReadout => {
+                        debug.show_jump_readout = !debug.show_jump_readout;
+                    }
                     MenuButtonAction::ToggleAnimationDebug => {
                         debug.show_animation_debug = !debug.show_animation_debug;
                     }
@@ -1363,10 +2736,102 @@ pub(super) fn handle_menu_buttons(
                     MenuButtonAction::ToggleWorldAxes => {
                         debug.show_world_axes = !debug.show_world_axes;
                     }
+                    MenuButtonAction::CycleWorldAxesAnchor => {
+                        debug.world_axes_anchor = debug.world_axes_anchor.next();
+                    }
+                    MenuButtonAction::ToggleGroundGrid => {
+                        debug.show_ground_grid = !debug.show_ground_grid;
+                    }
+                    MenuButtonAction::ToggleSunDirection => {
+                        debug.show_sun_direction = !debug.show_sun_direction;
+                    }
+                    MenuButtonAction::ToggleLimbHitboxes => {
+                        debug.limb_hitboxes = !debug.limb_hitboxes;
+                    }
+                    MenuButtonAction::ToggleMeasurementTool => {
+                        debug.measurement_tool = !debug.measurement_tool;
+                    }
+                    MenuButtonAction::CycleTimeScale => {
+                        let next_idx = TIME_SCALE_OPTIONS
+                            .iter()
+                            .position(|&scale| (scale - debug.time_scale).abs() < 1e-3)
+                            .map(|idx| (idx + 1) % TIME_SCALE_OPTIONS.len())
+                            .unwrap_or(0);
+                        debug.time_scale = TIME_SCALE_OPTIONS[next_idx];
+                    }
+                    MenuButtonAction::ResetTimeScale => {
+                        debug.time_scale = 1.0;
+                    }
+                    MenuButtonAction::ToggleDeltaCap => {
+                        debug.cap_delta_time = !debug.cap_delta_time;
+                    }
+                    MenuButtonAction::ToggleDiagnosticsRecording => {
+                        debug.record_diagnostics = !debug.record_diagnostics;
+                    }
+                    MenuButtonAction::CycleDiagnosticsDuration => {
+                        let next_idx = DIAGNOSTICS_DURATION_OPTIONS
+                            .iter()
+                            .position(|&secs| (secs - debug.diagnostics_record_secs).abs() < 1e-3)
+                            .map(|idx| (idx + 1) % DIAGNOSTICS_DURATION_OPTIONS.len())
+                            .unwrap_or(0);
+                        debug.diagnostics_record_secs = DIAGNOSTICS_DURATION_OPTIONS[next_idx];
+                    }
+                    MenuButtonAction::ToggleDiagnosticsEntityCount => {
+                        debug.diagnostics_entity_count = !debug.diagnostics_entity_count;
+                    }
+                    MenuButtonAction::CycleHitchThreshold => {
+                        let next_idx = HITCH_THRESHOLD_OPTIONS
+                            .iter()
+                            .position(|&ms| (ms - debug.hitch_threshold_ms).abs() < 1e-3)
+                            .map(|idx| (idx + 1) % HITCH_THRESHOLD_OPTIONS.len())
+                            .unwrap_or(0);
+                        debug.hitch_threshold_ms = HITCH_THRESHOLD_OPTIONS[next_idx];
+                    }
+                    MenuButtonAction::ClearHitchLog => {
+                        hitches.recent.clear();
+                    }
                     MenuButtonAction::StartRebind(action) => {
                         menu.screen = MenuScreen::Keybinds;
                         menu.awaiting_rebind = Some(action);
                     }
+                    MenuButtonAction::KeepPrimaryKey(action) => {
+                        keybinds.keep_primary_key(action);
+                    }
+                    MenuButtonAction::RestoreDefaultKeys(action) => {
+                        keybinds.restore_default(action);
+                    }
+                    MenuButtonAction::CycleLanguage => {
+                        settings.language = settings.language.next();
+                    }
+                    MenuButtonAction::ApplyClassicWasd => {
+                        keybinds.apply_classic_wasd();
+                    }
+                    MenuButtonAction::ExportKeybinds => {
+                        let path = Path::new(SHARED_KEYBINDS_PATH);
+                        notice.0 = Some(match export_keybinds(&keybinds, path) {
+                            Ok(()) => tr(settings.language, "notice.keybinds_exported")
+                                .replace("{path}", SHARED_KEYBINDS_PATH),
+                            Err(error) => tr(settings.language, "notice.keybinds_failed")
+                                .replace("{path}", SHARED_KEYBINDS_PATH)
+                                .replace("{error}", &error),
+                        });
+                    }
+                    MenuButtonAction::ImportKeybinds => {
+                        let path = Path::new(SHARED_KEYBINDS_PATH);
+                        notice.0 = Some(match import_keybinds(path) {
+                            Ok(imported) => {
+                                *keybinds = imported;
+                                tr(settings.language, "notice.keybinds_imported")
+                                    .replace("{path}", SHARED_KEYBINDS_PATH)
+                            }
+                            Err(error) => {
+                                warn!("Kon {SHARED_KEYBINDS_PATH} niet importeren: {error}");
+                                tr(settings.language, "notice.keybinds_failed")
+                                    .replace("{path}", SHARED_KEYBINDS_PATH)
+                                    .replace("{error}", &error)
+                            }
+                        });
+                    }
                     MenuButtonAction::ClearKeybindFilter => {
                         menu.keybind_filter.clear();
                     }
@@ -1386,6 +2851,7 @@ pub(super) fn handle_menu_buttons(
 
 pub(super) fn capture_rebind_input(
     keys: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
     mut menu: ResMut<MenuState>,
     mut keybinds: ResMut<GameKeybinds>,
 ) {
@@ -1397,20 +2863,37 @@ pub(super) fn capture_rebind_input(
         return;
     };
 
-    for key in keys.get_just_pressed() {
-        if *key == KeyCode::Escape {
-            continue;
-        }
+    let modifiers = KeyModifiers::held(&keys);
+    let key = keys
+        .get_just_pressed()
+        .copied()
+        .find(|key| *key != KeyCode::Escape && KeyModifier::from_key(*key).is_none())
+        .map(InputBinding::Key);
+    // The left button clicks the menu itself, so it never ends up as a bind.
+    let button = mouse_buttons
+        .get_just_pressed()
+        .copied()
+        .find(|button| *button != MouseButton::Left)
+        .map(InputBinding::Mouse);
+    let chord = key.or(button).map(|input| KeyBinding { input, modifiers });
+    // Modifier keys bind on release, so holding one can still start a chord.
+    let binding = chord.or_else(|| {
+        keys.get_just_released()
+            .copied()
+            .find(|key| KeyModifier::from_key(*key).is_some())
+            .map(KeyBinding::plain)
+    });
+    let Some(binding) = binding else {
+        return;
+    };
 
-        if keybinds.has_key(action, *key) {
-            keybinds.remove_key(action, *key)
-        } else {
-            keybinds.add_key(action, *key)
-        };
-        menu.awaiting_rebind = None;
-        menu.dirty = true;
-        break;
-    }
+    if keybinds.has_key(action, binding) {
+        keybinds.remove_key(action, binding)
+    } else {
+        keybinds.add_key(action, binding)
+    };
+    menu.awaiting_rebind = None;
+    menu.dirty = true;
 }
 
 pub(super) fn capture_keybind_filter_input(
@@ -1423,10 +2906,8 @@ pub(super) fn capture_keybind_filter_input(
 
     let mut changed = false;
 
-    if keys.just_pressed(KeyCode::Backspace) {
-        if menu.keybind_filter.pop().is_some() {
-            changed = true;
-        }
+    if keys.just_pressed(KeyCode::Backspace) && menu.keybind_filter.pop().is_some() {
+        changed = true;
     }
 
     for key in keys.get_just_pressed() {
@@ -1445,12 +2926,49 @@ pub(super) fn persist_config_on_change(
     settings: Res<GameSettings>,
     keybinds: Res<GameKeybinds>,
     debug: Res<DebugSettings>,
+    last_scenario: Res<LastScenario>,
+) {
+    if settings.is_changed()
+        || keybinds.is_changed()
+        || debug.is_changed()
+        || last_scenario.is_changed()
+    {
+        save_persisted_config(&settings, &keybinds, &debug, &last_scenario);
+    }
+}
+
+/// Runs in `Last` on the frame the app exits: saves the config one final time, whatever
+/// changed this frame, and logs a short summary of the session.
+#[allow(clippy::too_many_arguments)]
+pub(super) fn flush_on_exit(
+    mut exits: MessageReader<AppExit>,
+    time: Res<Time<Real>>,
+    flow: Res<GameFlowState>,
+    scenarios: Res<ScenarioCatalog>,
+    settings: Res<GameSettings>,
+    keybinds: Res<GameKeybinds>,
+    debug: Res<DebugSettings>,
+    last_scenario: Res<LastScenario>,
 ) {
-    if settings.is_changed() || keybinds.is_changed() || debug.is_changed() {
-        save_persisted_config(&settings, &keybinds, &debug);
+    if exits.read().last().is_none() {
+        return;
     }
+    save_persisted_config(&settings, &keybinds, &debug, &last_scenario);
+
+    let secs = time.elapsed_secs() as u32;
+    let scenario = flow
+        .current_scenario
+        .and_then(|index| scenarios.scenarios.get(index))
+        .map_or("geen", |scenario| scenario.id.as_str());
+    info!(
+        "Sessie afgesloten na {}:{:02}, actief scenario: {}",
+        secs / 60,
+        secs % 60,
+        scenario
+    );
 }
 
+#[allow(clippy::too_many_arguments)]
 pub(super) fn rebuild_menu_ui(
     mut commands: Commands,
     flow: Res<GameFlowState>,
@@ -1459,6 +2977,8 @@ pub(super) fn rebuild_menu_ui(
     settings: Res<GameSettings>,
     debug: Res<DebugSettings>,
     keybinds: Res<GameKeybinds>,
+    hitches: Res<HitchLog>,
+    scenarios: Res<ScenarioCatalog>,
 ) {
     if !menu.dirty {
         return;
@@ -1473,6 +2993,23 @@ pub(super) fn rebuild_menu_ui(
         return;
     }
 
+    // Left out on the Debug screen so fog and debug tweaks stay visible at full contrast;
+    // never pickable, so it can't swallow clicks meant for egui windows or the game.
+    if settings.pause_menu_dim && menu.screen != MenuScreen::Debug {
+        commands.spawn((
+            MenuRoot,
+            GlobalZIndex(499),
+            Node {
+                position_type: PositionType::Absolute,
+                width: percent(100),
+                height: percent(100),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5)),
+            Pickable::IGNORE,
+        ));
+    }
+
     commands
         .spawn((
             MenuRoot,
@@ -1495,207 +3032,919 @@ pub(super) fn rebuild_menu_ui(
                 BackgroundColor(Color::srgb(0.12, 0.14, 0.18)),
             ))
             .with_children(|panel| {
+                let language = settings.language;
                 panel.spawn((
-                    Text::new(match menu.screen {
-                        MenuScreen::Main => "Game Menu",
-                        MenuScreen::Settings => "Settings",
-                        MenuScreen::Debug => "Debug",
-                        MenuScreen::Keybinds => "Keybinds",
-                        MenuScreen::ExitConfirm => {
-                            if flow.in_game {
-                                "Terug naar hoofdmenu"
-                            } else {
-                                "Exit"
+                    Text::new(tr(
+                        language,
+                        match menu.screen {
+                            MenuScreen::Main => "menu.title",
+                            MenuScreen::Settings => "menu.settings",
+                            MenuScreen::Debug => "menu.debug",
+                            MenuScreen::Keybinds => "menu.keybinds",
+                            MenuScreen::ExitConfirm => {
+                                if flow.in_game {
+                                    "menu.to_main_menu"
+                                } else {
+                                    "menu.exit"
+                                }
                             }
+                            MenuScreen::QuitConfirm => "menu.quit_title",
+                        },
+                    )),
+                    Node {
+                        margin: UiRect::bottom(px(12)),
+                        ..default()
+                    },
+                ));
+
+                match menu.screen {
+                    MenuScreen::Main => {
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::Resume),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(tr(language, "menu.resume")));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::OpenSettings),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(tr(language, "menu.settings")));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::OpenKeybinds),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(tr(language, "menu.keybinds")));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::OpenDebug),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(tr(language, "menu.debug")));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::OpenExitConfirm),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(tr(
+                                language,
+                                if flow.in_game {
+                                    "menu.to_main_menu"
+                                } else {
+                                    "menu.exit"
+                                },
+                            )));
+                    }
+                    MenuScreen::Settings => {
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleLanguage),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "{}: {}",
+                                tr(language, "menu.language"),
+                                language.label()
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleDisplayMode),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Display mode: {}",
+                                settings.display_mode.label()
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleResolution),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Resolution: {}x{}",
+                                settings.resolution_width, settings.resolution_height
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleAntiAliasing),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Anti-aliasing: {}",
+                                settings.anti_aliasing.label()
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleRenderScale),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Render Scale: {:.0}%",
+                                settings.render_scale * 100.0
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleShadowMode),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Player Shadow: {}",
+                                settings.shadow_mode.label()
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleMenuScenarioPreview),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Start Menu Preview: {}",
+                                if settings.menu_scenario_preview {
+                                    "On"
+                                } else {
+                                    "Off"
+                                }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::TogglePauseMenuDim),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Pause Dim: {}",
+                                if settings.pause_menu_dim { "On" } else { "Off" }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleDustParticles),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Dust Particles: {}",
+                                if settings.dust_particles { "On" } else { "Off" }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleGroundTexture),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Ground Texture: {} (next run)",
+                                if settings.ground_texture { "On" } else { "Off" }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleBakeWorldShadows),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Spawn Baked Shadows: {} (next run)",
+                                if settings.bake_world_shadows {
+                                    "On"
+                                } else {
+                                    "Off"
+                                }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleSimplePlayerModel),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Player Model: {} (next run)",
+                                if settings.simple_player_model {
+                                    "Simple Box"
+                                } else {
+                                    "Procedural Human"
+                                }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleBodyPreset),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Body: {} (next run)",
+                                settings.body_preset.label()
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleHeadTracking),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Head Tracking: {}",
+                                if settings.head_always_tracks_camera {
+                                    "Always"
+                                } else {
+                                    "While LMB"
+                                }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleHumanShading),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Character Shading: {}",
+                                settings.human_shading.label()
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleCameraRelativeMovement),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Movement: {}",
+                                if settings.camera_relative_movement {
+                                    "Camera-relative"
+                                } else {
+                                    "Character-relative"
+                                }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleAimMode),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Aim (RMB): {}",
+                                if settings.toggle_aim { "Toggle" } else { "Hold" }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleSprintMode),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Sprint: {}",
+                                if settings.sprint_toggle { "Toggle" } else { "Hold" }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleChaosMode),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Chaos Mode: {} (next run)",
+                                if settings.chaos_mode { "On" } else { "Off" }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleQuickStartScenario),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Quick Start Scenario: {}",
+                                scenarios
+                                    .scenarios
+                                    .get(scenarios.quick_start_index(
+                                        settings.quick_start_scenario.as_deref()
+                                    ))
+                                    .map_or("-", |scenario| scenario.name_in(settings.language))
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::TogglePauseOnMenu),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Menu Pauses Game: {}",
+                                if settings.pause_on_menu {
+                                    "On"
+                                } else {
+                                    "Off (practice)"
+                                }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleTurnAcceleration),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Turn Acceleration: {}",
+                                if settings.turn_acceleration_enabled {
+                                    "On"
+                                } else {
+                                    "Off"
+                                }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleReduceMotion),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Reduce Motion: {}",
+                                if settings.reduce_motion { "On" } else { "Off" }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleComfortVignette),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Comfort Vignette: {}",
+                                if settings.comfort_vignette {
+                                    "On"
+                                } else if settings.reduce_motion {
+                                    "On (Reduce Motion)"
+                                } else {
+                                    "Off"
+                                }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleGameFeel),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Game Feel: {}",
+                                GameFeelPreset::matching(&settings)
+                                    .map_or("Custom", GameFeelPreset::label)
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleFov),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Field of View: {:.0}°",
+                                settings
+                                    .fov_degrees
+                                    .clamp(FOV_DEGREES_RANGE.0, FOV_DEGREES_RANGE.1)
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleLookSensitivity),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Look Sensitivity: {:.2}x",
+                                settings.look_sensitivity / LOOK_SENSITIVITY_DEFAULT
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleInvertY),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Invert Y: {}",
+                                if settings.invert_y { "On" } else { "Off" }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleCameraFocusHeight),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Camera Focus Height: {:.2} m",
+                                settings.camera_focus_height
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleCameraHeight),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Camera Height: {:.2} m",
+                                settings.camera_height
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleCameraShoulderOffset),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(if settings.camera_shoulder_offset > 0.0 {
+                                format!(
+                                    "Shoulder Camera: {:.1} m ({} swaps)",
+                                    settings.camera_shoulder_offset,
+                                    keybinds.display_keys(GameAction::SwapShoulder)
+                                )
+                            } else {
+                                "Shoulder Camera: Off".to_string()
+                            }));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleCameraFollowGround),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Camera Follows Ground: {}",
+                                if settings.camera_follow_ground {
+                                    "On"
+                                } else {
+                                    "Off"
+                                }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::TogglePlayerCameraFade),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Fade Player Near Camera: {}",
+                                if settings.fade_player_near_camera {
+                                    "On"
+                                } else {
+                                    "Off"
+                                }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleChargeJump),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Charge Jump: {}",
+                                if settings.charge_jump_enabled {
+                                    "On"
+                                } else {
+                                    "Off"
+                                }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleJumpApexHang),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(
+                                if settings.jump_apex_hang_factor >= 1.0 {
+                                    "Apex Hang-Time: Off".to_string()
+                                } else {
+                                    format!(
+                                        "Apex Hang-Time: {:.0}% gravity",
+                                        settings.jump_apex_hang_factor * 100.0
+                                    )
+                                },
+                            ));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleWallJump),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Wall Jump: {}",
+                                if settings.wall_jump_enabled {
+                                    "On"
+                                } else {
+                                    "Off"
+                                }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleAlwaysCaptureMouse),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Mouse Look: {}",
+                                if settings.always_capture_mouse {
+                                    "Always"
+                                } else {
+                                    "Hold Mouse Button"
+                                }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleScrollOrbit),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Horizontal Scroll Orbits: {}",
+                                if settings.horizontal_scroll_orbit {
+                                    "On"
+                                } else {
+                                    "Off"
+                                }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::BackMain),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(tr(language, "menu.back")));
+                    }
+                    MenuScreen::Debug => {
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::TogglePerformanceOverlay),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Performance Overlay: {}",
+                                if debug.show_performance_overlay {
+                                    "On"
+                                } else {
+                                    "Off"
+                                }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleFpsGraph),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "FPS Graph: {}",
+                                if debug.show_fps_graph { "On" } else { "Off" }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleInputPanel),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Input Panel: {}",
+                                if debug.show_input_panel { "On" } else { "Off" }
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleOverlayCorner),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Overlay Corner: {}",
+                                debug.overlay_corner.label()
+                            )));
+
+                        for metric in OverlayMetric::ALL {
+                            panel
+                                .spawn((
+                                    Button,
+                                    MenuButton(MenuButtonAction::ToggleOverlayMetric(metric)),
+                                    menu_button_node(),
+                                    menu_button_normal_color(),
+                                ))
+                                .with_child(Text::new(format!(
+                                    "Overlay {}: {}",
+                                    metric.label(),
+                                    if debug.overlay_metrics.shows(metric) {
+                                        "On"
+                                    } else {
+                                        "Off"
+                                    }
+                                )));
                         }
-                    }),
-                    Node {
-                        margin: UiRect::bottom(px(12)),
-                        ..default()
-                    },
-                ));
 
-                match menu.screen {
-                    MenuScreen::Main => {
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::Resume),
+                                MenuButton(MenuButtonAction::ToggleBakedShadows),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
-                            .with_child(Text::new("Resume"));
+                            .with_child(Text::new(format!(
+                                "World Baked Shadows: {}",
+                                if debug.show_baked_shadows {
+                                    "On"
+                                } else {
+                                    "Off"
+                                }
+                            )));
 
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::OpenSettings),
+                                MenuButton(MenuButtonAction::ToggleFog),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
-                            .with_child(Text::new("Settings"));
+                            .with_child(Text::new(format!(
+                                "Fog: {}",
+                                if debug.show_fog { "On" } else { "Off" }
+                            )));
 
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::OpenKeybinds),
+                                MenuButton(MenuButtonAction::ToggleCollisionShapes),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
-                            .with_child(Text::new("Keybinds"));
+                            .with_child(Text::new(format!(
+                                "Collision Shapes: {}",
+                                if debug.show_collision_shapes {
+                                    "On"
+                                } else {
+                                    "Off"
+                                }
+                            )));
 
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::OpenDebug),
+                                MenuButton(MenuButtonAction::ToggleContactPoints),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
-                            .with_child(Text::new("Debug"));
+                            .with_child(Text::new(format!(
+                                "Contact Points: {}",
+                                if debug.show_contact_points {
+                                    "On"
+                                } else {
+                                    "Off"
+                                }
+                            )));
 
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::OpenExitConfirm),
+                                MenuButton(MenuButtonAction::ToggleJumpReadout),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
-                            .with_child(Text::new(if flow.in_game {
-                                "Terug naar hoofdmenu"
-                            } else {
-                                "Exit"
-                            }));
-                    }
-                    MenuScreen::Settings => {
+                            .with_child(Text::new(format!(
+                                "Jump Readout: {}",
+                                if debug.show_jump_readout { "On" } else { "Off" }
+                            )));
+
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::CycleDisplayMode),
+                                MenuButton(MenuButtonAction::ToggleAnimationDebug),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
                             .with_child(Text::new(format!(
-                                "Display mode: {}",
-                                settings.display_mode.label()
+                                "Animation Rig: {}",
+                                if debug.show_animation_debug {
+                                    "On"
+                                } else {
+                                    "Off"
+                                }
                             )));
 
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::CycleResolution),
+                                MenuButton(MenuButtonAction::ToggleWireframe),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
                             .with_child(Text::new(format!(
-                                "Resolution: {}x{}",
-                                settings.resolution_width, settings.resolution_height
+                                "Model Lines (Wireframe): {}",
+                                if debug.show_wireframe { "On" } else { "Off" }
                             )));
 
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::ToggleMsaa),
+                                MenuButton(MenuButtonAction::ToggleWorldAxes),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
                             .with_child(Text::new(format!(
-                                "MSAA: {}",
-                                if settings.msaa_enabled { "On" } else { "Off" }
+                                "World Axes: {}",
+                                if debug.show_world_axes { "On" } else { "Off" }
                             )));
 
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::ToggleShadowMode),
+                                MenuButton(MenuButtonAction::CycleWorldAxesAnchor),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
                             .with_child(Text::new(format!(
-                                "Player Shadow: {}",
-                                settings.shadow_mode.label()
+                                "World Axes At: {}",
+                                debug.world_axes_anchor.label()
                             )));
 
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::BackMain),
+                                MenuButton(MenuButtonAction::ToggleGroundGrid),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
-                            .with_child(Text::new("Back"));
-                    }
-                    MenuScreen::Debug => {
+                            .with_child(Text::new(format!(
+                                "Ground Grid: {}",
+                                if debug.show_ground_grid { "On" } else { "Off" }
+                            )));
+
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::TogglePerformanceOverlay),
+                                MenuButton(MenuButtonAction::ToggleSunDirection),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
                             .with_child(Text::new(format!(
-                                "Performance Overlay: {}",
-                                if debug.show_performance_overlay {
-                                    "On"
-                                } else {
-                                    "Off"
-                                }
+                                "Sun Direction: {}",
+                                if debug.show_sun_direction { "On" } else { "Off" }
                             )));
 
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::ToggleBakedShadows),
+                                MenuButton(MenuButtonAction::ToggleLimbHitboxes),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
                             .with_child(Text::new(format!(
-                                "World Baked Shadows: {}",
-                                if debug.show_baked_shadows {
-                                    "On"
-                                } else {
-                                    "Off"
-                                }
+                                "Limb Hitboxes: {}",
+                                if debug.limb_hitboxes { "On" } else { "Off" }
                             )));
 
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::ToggleFog),
+                                MenuButton(MenuButtonAction::ToggleMeasurementTool),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
                             .with_child(Text::new(format!(
-                                "Fog: {}",
-                                if debug.show_fog { "On" } else { "Off" }
+                                "Measurement Tool ({}): {}",
+                                keybinds.display_keys(GameAction::Measure),
+                                if debug.measurement_tool { "On" } else { "Off" }
                             )));
 
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::ToggleCollisionShapes),
+                                MenuButton(MenuButtonAction::CycleTimeScale),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
                             .with_child(Text::new(format!(
-                                "Collision Shapes: {}",
-                                if debug.show_collision_shapes {
-                                    "On"
-                                } else {
-                                    "Off"
-                                }
+                                "Time Scale: {:.2}x",
+                                debug.time_scale
                             )));
 
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::ToggleAnimationDebug),
+                                MenuButton(MenuButtonAction::ResetTimeScale),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new("Reset Time Scale"));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleDeltaCap),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(if debug.cap_delta_time {
+                                format!("Cap Delta Time: On ({:.2}s)", debug.max_delta_secs)
+                            } else {
+                                "Cap Delta Time: Off".to_string()
+                            }));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ToggleDiagnosticsRecording),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
                             .with_child(Text::new(format!(
-                                "Animation Rig: {}",
-                                if debug.show_animation_debug {
-                                    "On"
+                                "Record Diagnostics CSV: {}",
+                                if debug.record_diagnostics {
+                                    "Recording"
                                 } else {
                                     "Off"
                                 }
@@ -1704,27 +3953,83 @@ pub(super) fn rebuild_menu_ui(
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::ToggleWireframe),
+                                MenuButton(MenuButtonAction::CycleDiagnosticsDuration),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
                             .with_child(Text::new(format!(
-                                "Model Lines (Wireframe): {}",
-                                if debug.show_wireframe { "On" } else { "Off" }
+                                "Recording Length: {:.0}s",
+                                debug.diagnostics_record_secs
                             )));
 
                         panel
                             .spawn((
                                 Button,
-                                MenuButton(MenuButtonAction::ToggleWorldAxes),
+                                MenuButton(MenuButtonAction::ToggleDiagnosticsEntityCount),
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
                             .with_child(Text::new(format!(
-                                "World Axes: {}",
-                                if debug.show_world_axes { "On" } else { "Off" }
+                                "Record Entity Count: {}",
+                                if debug.diagnostics_entity_count {
+                                    "On"
+                                } else {
+                                    "Off"
+                                }
                             )));
 
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::CycleHitchThreshold),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(if debug.hitch_threshold_ms > 0.0 {
+                                format!("Log Hitches Over: {:.0} ms", debug.hitch_threshold_ms)
+                            } else {
+                                "Log Hitches: Off".to_string()
+                            }));
+
+                        let hitch_lines = if hitches.recent.is_empty() {
+                            "No hitches recorded".to_string()
+                        } else {
+                            hitches
+                                .recent
+                                .iter()
+                                .rev()
+                                .map(|hitch| {
+                                    format!(
+                                        "{:>7.1}s  {:.0} ms  {}  {}",
+                                        hitch.at_secs,
+                                        hitch.frame_time_ms,
+                                        hitch.scenario.as_deref().unwrap_or("-"),
+                                        hitch.player_position.map_or("-".to_string(), |p| {
+                                            format!("({:.1}, {:.1}, {:.1})", p.x, p.y, p.z)
+                                        })
+                                    )
+                                })
+                                .collect::<Vec<_>>()
+                                .join("\n")
+                        };
+                        panel.spawn((
+                            Text::new(hitch_lines),
+                            TextFont::from_font_size(13.0),
+                            Node {
+                                margin: UiRect::vertical(px(4)),
+                                ..default()
+                            },
+                        ));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ClearHitchLog),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new("Clear Hitches"));
+
                         panel
                             .spawn((
                                 Button,
@@ -1732,7 +4037,7 @@ pub(super) fn rebuild_menu_ui(
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
-                            .with_child(Text::new("Back"));
+                            .with_child(Text::new(tr(language, "menu.back")));
                     }
                     MenuScreen::Keybinds => {
                         panel.spawn((
@@ -1759,10 +4064,41 @@ pub(super) fn rebuild_menu_ui(
                             ))
                             .with_child(Text::new("Clear filter"));
 
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ApplyClassicWasd),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new("Classic WASD (A/D strafe, Q/E turn)"));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ExportKeybinds),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Export to {SHARED_KEYBINDS_PATH}"
+                            )));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::ImportKeybinds),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(format!(
+                                "Import from {SHARED_KEYBINDS_PATH}"
+                            )));
+
                         if let Some(action) = menu.awaiting_rebind {
                             panel.spawn((
                                 Text::new(format!(
-                                    "Press a key for {} (toggle bind, ESC is reserved)",
+                                    "Press a key, mouse button or modifier+key for {} (toggle bind, ESC is reserved)",
                                     action.label()
                                 )),
                                 Node {
@@ -1793,13 +4129,44 @@ pub(super) fn rebuild_menu_ui(
                             };
 
                             panel
-                                .spawn((
-                                    Button,
-                                    MenuButton(MenuButtonAction::StartRebind(action)),
-                                    menu_button_node(),
-                                    menu_button_normal_color(),
-                                ))
-                                .with_child(Text::new(label));
+                                .spawn(Node {
+                                    width: percent(100),
+                                    column_gap: px(6),
+                                    ..default()
+                                })
+                                .with_children(|row| {
+                                    row.spawn((
+                                        Button,
+                                        MenuButton(MenuButtonAction::StartRebind(action)),
+                                        Node {
+                                            width: Val::Auto,
+                                            flex_grow: 1.0,
+                                            ..menu_button_node()
+                                        },
+                                        menu_button_normal_color(),
+                                    ))
+                                    .with_child(Text::new(label));
+                                    row.spawn((
+                                        Button,
+                                        MenuButton(MenuButtonAction::KeepPrimaryKey(action)),
+                                        Node {
+                                            width: px(90),
+                                            ..menu_button_node()
+                                        },
+                                        menu_button_normal_color(),
+                                    ))
+                                    .with_child(Text::new("Primary"));
+                                    row.spawn((
+                                        Button,
+                                        MenuButton(MenuButtonAction::RestoreDefaultKeys(action)),
+                                        Node {
+                                            width: px(90),
+                                            ..menu_button_node()
+                                        },
+                                        menu_button_normal_color(),
+                                    ))
+                                    .with_child(Text::new("Default"));
+                                });
                         }
 
                         panel
@@ -1809,15 +4176,18 @@ pub(super) fn rebuild_menu_ui(
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
-                            .with_child(Text::new("Back"));
+                            .with_child(Text::new(tr(language, "menu.back")));
                     }
                     MenuScreen::ExitConfirm => {
                         panel.spawn((
-                            Text::new(if flow.in_game {
-                                "Terug naar het hoofdmenu?"
-                            } else {
-                                "Weet je het zeker?"
-                            }),
+                            Text::new(tr(
+                                language,
+                                if flow.in_game {
+                                    "confirm.to_main_menu"
+                                } else {
+                                    "confirm.exit"
+                                },
+                            )),
                             Node {
                                 margin: UiRect::bottom(px(10)),
                                 ..default()
@@ -1831,11 +4201,14 @@ pub(super) fn rebuild_menu_ui(
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
-                            .with_child(Text::new(if flow.in_game {
-                                "Ja, hoofdmenu"
-                            } else {
-                                "Ja, Exit"
-                            }));
+                            .with_child(Text::new(tr(
+                                language,
+                                if flow.in_game {
+                                    "confirm.yes_main_menu"
+                                } else {
+                                    "confirm.yes_exit"
+                                },
+                            )));
 
                         panel
                             .spawn((
@@ -1844,7 +4217,34 @@ pub(super) fn rebuild_menu_ui(
                                 menu_button_node(),
                                 menu_button_normal_color(),
                             ))
-                            .with_child(Text::new("Nee, terug"));
+                            .with_child(Text::new(tr(language, "confirm.no_back")));
+                    }
+                    MenuScreen::QuitConfirm => {
+                        panel.spawn((
+                            Text::new(tr(language, "confirm.quit")),
+                            Node {
+                                margin: UiRect::bottom(px(10)),
+                                ..default()
+                            },
+                        ));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::QuitGame),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(tr(language, "confirm.yes_quit")));
+
+                        panel
+                            .spawn((
+                                Button,
+                                MenuButton(MenuButtonAction::Resume),
+                                menu_button_node(),
+                                menu_button_normal_color(),
+                            ))
+                            .with_child(Text::new(tr(language, "confirm.no_continue")));
                     }
                 }
             });
@@ -1853,11 +4253,15 @@ pub(super) fn rebuild_menu_ui(
     menu.dirty = false;
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub(super) fn apply_runtime_settings(
     settings: Res<GameSettings>,
     debug: Res<DebugSettings>,
     primary_window: Single<&mut Window, With<PrimaryWindow>>,
-    camera_entities: Query<(Entity, &Transform), With<Camera3d>>,
+    camera_entities: Query<
+        (Entity, &Transform, &Projection),
+        (With<Camera3d>, Without<ScenarioPreviewCamera>),
+    >,
     player_transforms: Query<&Transform, With<Player>>,
     player_entities: Query<(Entity, Has<NotShadowCaster>), With<Player>>,
     player_visual_entities: Query<(Entity, Has<NotShadowCaster>), With<PlayerVisualPart>>,
@@ -1905,11 +4309,33 @@ pub(super) fn apply_runtime_settings(
         );
     }
 
-    if let Ok((camera, camera_transform)) = camera_entities.single() {
-        if settings.msaa_enabled {
-            commands.entity(camera).insert(Msaa::Sample4);
+    if let Ok((camera, camera_transform, projection)) = camera_entities.single() {
+        let mut camera_commands = commands.entity(camera);
+        camera_commands.insert(settings.anti_aliasing.msaa());
+        if settings.is_changed()
+            && let Projection::Perspective(perspective) = projection
+        {
+            camera_commands.insert(Projection::Perspective(PerspectiveProjection {
+                fov: settings.fov_radians(),
+                ..perspective.clone()
+            }));
+        }
+        if settings.anti_aliasing == AntiAliasingSetting::Fxaa {
+            camera_commands.insert(Fxaa::default());
+        } else {
+            camera_commands.remove::<Fxaa>();
+        }
+        if settings.anti_aliasing == AntiAliasingSetting::Taa {
+            // Pulls in the depth/motion-vector prepasses and jitter it needs.
+            camera_commands.insert(TemporalAntiAliasing::default());
         } else {
-            commands.entity(camera).insert(Msaa::Off);
+            camera_commands.remove::<(
+                TemporalAntiAliasing,
+                TemporalJitter,
+                MipBias,
+                DepthPrepass,
+                MotionVectorPrepass,
+            )>();
         }
 
         let anchor_offset = if debug.fog_anchor == FogAnchorSetting::Character {
@@ -1996,6 +4422,7 @@ pub(super) fn apply_runtime_settings(
     }
 }
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub(super) fn apply_fog_alpha_materials(
     time: Res<Time>,
     debug: Res<DebugSettings>,
@@ -2017,6 +4444,7 @@ pub(super) fn apply_fog_alpha_materials(
         ),
     >,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    mut material_cache: ResMut<FogAlphaMaterialCache>,
     mut commands: Commands,
 ) {
     let Ok(camera_transform) = camera_query.single() else {
@@ -2024,6 +4452,18 @@ pub(super) fn apply_fog_alpha_materials(
     };
 
     let alpha_mode = debug.show_fog && debug.fog_hide_geometry;
+    if !alpha_mode {
+        for (entity, _, _, mut material_handle, state) in &mut mesh_materials {
+            let Some(state) = state else {
+                continue;
+            };
+            material_handle.0 = state.source.clone();
+            commands.entity(entity).remove::<FogAlphaMaterialState>();
+        }
+        material_cache.variants.clear();
+        return;
+    }
+
     let anchor_offset = if debug.fog_anchor == FogAnchorSetting::Character {
         player_transforms
             .single()
@@ -2040,42 +4480,15 @@ pub(super) fn apply_fog_alpha_materials(
     let smooth = 1.0 - (-time.delta_secs() * 10.0).exp();
 
     for (entity, transform, is_ground, mut material_handle, state) in &mut mesh_materials {
-        if !alpha_mode {
-            let Some(mut state) = state else {
-                continue;
-            };
-            let Some(material) = materials.get_mut(&material_handle.0) else {
-                continue;
-            };
-            let linear = material.base_color.to_linear();
-            material.base_color =
-                Color::linear_rgba(linear.red, linear.green, linear.blue, state.base_alpha);
-            material.alpha_mode = state.original_alpha_mode.clone();
-            material.fog_enabled = state.original_fog_enabled;
-            state.current_alpha_factor = 1.0;
-            continue;
-        }
-
-        if state.is_none() {
-            let Some(source_material) = materials.get(&material_handle.0).cloned() else {
+        let Some(mut state) = state else {
+            let Some(source_material) = materials.get(&material_handle.0) else {
                 continue;
             };
-            let linear = source_material.base_color.to_linear();
-            let state = FogAlphaMaterialState {
-                base_alpha: linear.alpha,
+            commands.entity(entity).insert(FogAlphaMaterialState {
+                source: material_handle.0.clone(),
+                base_alpha: source_material.base_color.to_linear().alpha,
                 current_alpha_factor: 1.0,
-                original_alpha_mode: source_material.alpha_mode.clone(),
-                original_fog_enabled: source_material.fog_enabled,
-            };
-            material_handle.0 = materials.add(source_material);
-            commands.entity(entity).insert(state);
-            continue;
-        }
-
-        let Some(mut state) = state else {
-            continue;
-        };
-        let Some(material) = materials.get_mut(&material_handle.0) else {
+            });
             continue;
         };
 
@@ -2086,16 +4499,42 @@ pub(super) fn apply_fog_alpha_materials(
         let fog_intensity = (1.0 - transmittance).clamp(0.0, 1.0);
         let target_alpha_factor = 1.0 - fog_intensity * debug.fog_opacity.clamp(0.0, 1.0);
         state.current_alpha_factor += (target_alpha_factor - state.current_alpha_factor) * smooth;
-        let target_alpha = (state.base_alpha * state.current_alpha_factor).clamp(0.0, 1.0);
 
-        let linear = material.base_color.to_linear();
-        material.base_color =
-            Color::linear_rgba(linear.red, linear.green, linear.blue, target_alpha);
-        material.alpha_mode = if is_ground {
-            AlphaMode::AlphaToCoverage
-        } else {
-            AlphaMode::Blend
+        // Quantize so entities at similar distances share one material variant.
+        let level =
+            (state.current_alpha_factor.clamp(0.0, 1.0) * FOG_ALPHA_LEVELS as f32).round() as u8;
+        let key = (state.source.id(), level, is_ground);
+        let variant = match material_cache.variants.get(&key) {
+            Some(handle) => handle.clone(),
+            None => {
+                let Some(source_material) = materials.get(&state.source).cloned() else {
+                    continue;
+                };
+                let target_alpha =
+                    (state.base_alpha * level as f32 / FOG_ALPHA_LEVELS as f32).clamp(0.0, 1.0);
+                let linear = source_material.base_color.to_linear();
+                let handle = materials.add(StandardMaterial {
+                    base_color: Color::linear_rgba(
+                        linear.red,
+                        linear.green,
+                        linear.blue,
+                        target_alpha,
+                    ),
+                    alpha_mode: if is_ground {
+                        AlphaMode::AlphaToCoverage
+                    } else {
+                        AlphaMode::Blend
+                    },
+                    fog_enabled: false,
+                    ..source_material
+                });
+                material_cache.variants.insert(key, handle.clone());
+                handle
+            }
         };
-        material.fog_enabled = false;
+
+        if material_handle.0 != variant {
+            material_handle.0 = variant;
+        }
     }
 }
@@ -1 +1,2 @@
 pub mod blender_model_editor;
+pub mod collision;